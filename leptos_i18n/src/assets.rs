@@ -0,0 +1,108 @@
+//! Locale variants of assets, for the images or documents with embedded text.
+//!
+//! The path of the asset is a template where `{locale}` is replaced by the locale, used for the locales
+//! having a variant of the asset, the others get the fallback path:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n();
+//! let hero = leptos_i18n::assets::localized_asset(i18n, "/img/hero.{locale}.png", &[LocaleEnum::en, LocaleEnum::fr], "/img/hero.png");
+//!
+//! view! { <img src=hero alt=t!(i18n, hero_alt) /> }
+//! ```
+//!
+//! When every locale has its own variant, with unrelated file names for example, `LocaleEnum::select` with a `PerLocale` is more direct.
+
+use leptos::Signal;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+/// The placeholder replaced by the locale in the asset templates.
+pub const LOCALE_PLACEHOLDER: &str = "{locale}";
+
+/// Return the path of the asset for `locale`: `template` with `{locale}` replaced if `locale` is in `available`, else `fallback`.
+pub fn resolve_asset<L: LocaleVariant>(
+    template: &str,
+    locale: L,
+    available: &[L],
+    fallback: &str,
+) -> String {
+    if available.iter().any(|&l| l.as_str() == locale.as_str()) {
+        template.replace(LOCALE_PLACEHOLDER, locale.as_str())
+    } else {
+        fallback.to_string()
+    }
+}
+
+/// Return a signal of the path of the asset for the current locale of the context, see [`resolve_asset`].
+pub fn localized_asset<T: Locales>(
+    i18n: I18nContext<T>,
+    template: &'static str,
+    available: &'static [T::Variants],
+    fallback: &'static str,
+) -> Signal<String> {
+    Signal::derive(move || resolve_asset(template, i18n.get_locale(), available, fallback))
+}
+
+impl<T: Locales> I18nContext<T> {
+    /// Return a signal of the path of the asset for the current locale, see [`localized_asset`].
+    #[inline]
+    pub fn asset(
+        self,
+        template: &'static str,
+        available: &'static [T::Variants],
+        fallback: &'static str,
+    ) -> Signal<String> {
+        localized_asset(self, template, available, fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+        De,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                "de" => Some(Locale::De),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+                Locale::De => "de",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr, Locale::De]
+        }
+    }
+
+    #[test]
+    fn test_resolve_asset() {
+        let available = [Locale::En, Locale::Fr];
+        let resolve = |locale| {
+            resolve_asset(
+                "/img/hero.{locale}.png",
+                locale,
+                &available,
+                "/img/hero.png",
+            )
+        };
+
+        assert_eq!(resolve(Locale::Fr), "/img/hero.fr.png");
+        assert_eq!(resolve(Locale::En), "/img/hero.en.png");
+        assert_eq!(resolve(Locale::De), "/img/hero.png");
+    }
+}
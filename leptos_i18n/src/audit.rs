@@ -0,0 +1,191 @@
+//! Development audit of the accessibility attributes of the rendered page, reporting the ones likely left untranslated.
+//!
+//! ```rust, ignore
+//! // in the app, in debug builds:
+//! let i18n = use_i18n();
+//! create_effect(move |_| {
+//!     i18n.get_locale();
+//!     leptos_i18n::audit::log_unlocalized_attributes(i18n, &Default::default());
+//! });
+//! ```
+//!
+//! The `alt`, `aria-label`, `aria-description`, `title` and `placeholder` attributes of the document are checked:
+//! - in the pseudo-locale mode of the `pseudo` module, with the `pseudo_locale` feature, a value with text outside of the markers doesn't come from `t_aria!`,
+//! - otherwise, when the current locale is not the default one, a value made mostly of common words of the default locale
+//!   is likely a hardcoded string. The words are given by [`AuditOptions::source_words`], common english words by default.
+//!
+//! The heuristics can report false positives, such as brand names, the report is meant to be read by a developer.
+
+use leptos::logging::warn;
+use wasm_bindgen::JsCast;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+/// The attributes read by screen readers or displayed by the browser that are checked.
+pub const AUDITED_ATTRIBUTES: &[&str] = &[
+    "alt",
+    "aria-label",
+    "aria-description",
+    "title",
+    "placeholder",
+];
+
+/// Common english words, the default [`AuditOptions::source_words`].
+pub const ENGLISH_WORDS: &[&str] = &[
+    "a", "an", "and", "back", "button", "cancel", "click", "close", "delete", "edit", "for",
+    "from", "hide", "home", "icon", "image", "in", "loading", "logo", "menu", "more", "next", "of",
+    "on", "open", "picture", "previous", "profile", "save", "search", "settings", "show", "submit",
+    "the", "to", "with", "your",
+];
+
+/// The options of the audit.
+#[derive(Debug, Clone, Copy)]
+pub struct AuditOptions<'a> {
+    /// Common words of the default locale, lowercased.
+    pub source_words: &'a [&'a str],
+}
+
+impl Default for AuditOptions<'_> {
+    fn default() -> Self {
+        AuditOptions {
+            source_words: ENGLISH_WORDS,
+        }
+    }
+}
+
+/// Why an attribute is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditReason {
+    /// The pseudo-locale mode is enabled and the value has text outside of the markers.
+    OutsidePseudoMarkers,
+    /// The value is made mostly of words of the default locale.
+    SourceLanguage,
+}
+
+/// An attribute likely left untranslated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlocalizedAttribute {
+    /// The element, as its tag name followed by its `id` if it has one, e.g. `img#avatar`.
+    pub element: String,
+    /// The name of the attribute.
+    pub attribute: &'static str,
+    /// The value of the attribute.
+    pub value: String,
+    /// Why the attribute is reported.
+    pub reason: AuditReason,
+}
+
+/// Return `true` if at least half of the words of `value` are in `source_words`.
+pub fn is_source_language(value: &str, source_words: &[&str]) -> bool {
+    let words = value
+        .split(|c: char| !c.is_alphabetic() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+    let known = words
+        .iter()
+        .filter(|word| source_words.contains(&word.as_str()))
+        .count();
+    !words.is_empty() && known * 2 >= words.len()
+}
+
+/// Return why the value of an attribute is likely untranslated, if it is.
+///
+/// `is_default_locale` tells if the page is in the default locale, whose words are expected.
+pub fn check_value(
+    value: &str,
+    is_default_locale: bool,
+    options: &AuditOptions,
+) -> Option<AuditReason> {
+    #[cfg(feature = "pseudo_locale")]
+    if crate::pseudo::is_enabled() {
+        let outside = !crate::pseudo::untranslated(value).is_empty();
+        return outside.then_some(AuditReason::OutsidePseudoMarkers);
+    }
+    let is_source = !is_default_locale && is_source_language(value, options.source_words);
+    is_source.then_some(AuditReason::SourceLanguage)
+}
+
+fn describe(element: &web_sys::Element) -> String {
+    let tag = element.tag_name().to_lowercase();
+    match element.id() {
+        id if id.is_empty() => tag,
+        id => format!("{}#{}", tag, id),
+    }
+}
+
+/// Return the attributes of the document likely left untranslated.
+///
+/// Must be called in the browser, for example in an effect.
+pub fn unlocalized_attributes<T: Locales>(
+    i18n: I18nContext<T>,
+    options: &AuditOptions,
+) -> Vec<UnlocalizedAttribute> {
+    let locale = i18n.get_locale_untracked();
+    let is_default_locale = locale.as_str() == T::Variants::default().as_str();
+
+    let selector = AUDITED_ATTRIBUTES
+        .iter()
+        .map(|attribute| format!("[{}]", attribute))
+        .collect::<Vec<_>>()
+        .join(",");
+    let Ok(nodes) = leptos::document().query_selector_all(&selector) else {
+        return vec![];
+    };
+
+    let mut found = vec![];
+    for i in 0..nodes.length() {
+        let Some(element) = nodes
+            .item(i)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+        for &attribute in AUDITED_ATTRIBUTES {
+            let Some(value) = element.get_attribute(attribute) else {
+                continue;
+            };
+            if let Some(reason) = check_value(&value, is_default_locale, options) {
+                found.push(UnlocalizedAttribute {
+                    element: describe(&element),
+                    attribute,
+                    value,
+                    reason,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Report the attributes of the document likely left untranslated to the console, see [`unlocalized_attributes`].
+pub fn log_unlocalized_attributes<T: Locales>(i18n: I18nContext<T>, options: &AuditOptions) {
+    for found in unlocalized_attributes(i18n, options) {
+        let reason = match found.reason {
+            AuditReason::OutsidePseudoMarkers => "not rendered by t_aria!",
+            AuditReason::SourceLanguage => "in the language of the default locale",
+        };
+        warn!(
+            "[i18n audit] {} {}={:?} looks untranslated: {}",
+            found.element, found.attribute, found.value, reason
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_value() {
+        let options = AuditOptions::default();
+        assert_eq!(
+            check_value("Close the menu", false, &options),
+            Some(AuditReason::SourceLanguage)
+        );
+        assert_eq!(check_value("Close the menu", true, &options), None);
+        assert_eq!(check_value("Fermer le menu", false, &options), None);
+        assert_eq!(check_value("Acme", false, &options), None);
+        assert_eq!(check_value("", false, &options), None);
+    }
+}
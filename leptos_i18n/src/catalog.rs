@@ -0,0 +1,265 @@
+//! Serve the translations as JSON, for clients loading them at runtime or for non-Rust consumers such as mobile apps.
+//!
+//! With the `catalog` feature, `load_locales!` embeds the translations of every locale file as minified JSON, with a hash of it used as an `ETag`.
+//!
+//! With `actix`:
+//!
+//! ```rust, ignore
+//! App::new()
+//!     .route("/i18n/{locale}", web::get().to(leptos_i18n::catalog::actix_handler::<i18n::LocaleEnum>))
+//!     .route("/i18n/{locale}/{namespace}", web::get().to(leptos_i18n::catalog::actix_handler::<i18n::LocaleEnum>))
+//!     .route("/i18n-schema", web::get().to(leptos_i18n::catalog::actix_schema_handler::<i18n::LocaleEnum>))
+//! ```
+//!
+//! With `axum`:
+//!
+//! ```rust, ignore
+//! Router::new()
+//!     .route("/i18n/:locale", get(leptos_i18n::catalog::axum_handler::<i18n::LocaleEnum>))
+//!     .route("/i18n/:locale/:namespace", get(leptos_i18n::catalog::axum_handler::<i18n::LocaleEnum>))
+//!     .route("/i18n-schema", get(leptos_i18n::catalog::axum_schema_handler::<i18n::LocaleEnum>))
+//! ```
+//!
+//! The schema handlers serve the [`CatalogSchema`], describing the variables, components and plurals of every key.
+//!
+//! Any other framework can use [`respond`] and [`respond_schema`] and convert the returned [`CatalogResponse`].
+//!
+//! On the client, `fetch_catalog` (with the `hydrate` feature) fetches a catalog sending its known `ETag` in `If-None-Match`,
+//! the hash embedded at compile time at first, so an unchanged catalog costs a `304` without any body.
+//!
+//! The catalogs of the locales the user is likely to switch to can be fetched in the background,
+//! so switching is instant: `prefetch` fetches every catalog of a locale, `prefetch_preferred_locales`
+//! the ones of the languages of the browser, and `cached_catalog` returns a catalog already fetched without any request:
+//!
+//! ```rust, ignore
+//! i18n.prefetch("/i18n", LocaleEnum::fr);
+//! leptos_i18n::catalog::prefetch_preferred_locales::<LocaleEnum>("/i18n");
+//!
+//! let json = match cached_catalog("/i18n", LocaleEnum::fr, None) {
+//!     Some(json) => json,
+//!     None => fetch_catalog("/i18n", LocaleEnum::fr, None).await?,
+//! };
+//! ```
+
+pub use leptos_i18n_core::catalog::*;
+
+// catalogs fetched since the start of the application, with their `ETag`.
+// shared by the threads of the module instance: with a shared-memory build (atomics), the workers spawned as wasm threads
+// see the catalogs fetched by the main thread, a plain web worker runs its own instance and has its own cache.
+#[cfg(feature = "hydrate")]
+static FETCHED_CATALOGS: std::sync::Mutex<
+    std::collections::BTreeMap<String, (String, std::sync::Arc<str>)>,
+> = std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// Fetch the catalog of `locale` (and `namespace`) from the catalogs served at `base_url`, and return its JSON content.
+///
+/// The `ETag` of the last known version of the catalog is sent in the `If-None-Match` header:
+/// the one fetched previously if any, else the hash embedded at compile time.
+/// If the server answers `304 Not Modified`, the known version is returned without downloading it again.
+///
+/// It can be called from a web worker, the worker global scope is used to fetch the catalog when there is no window.
+#[cfg(feature = "hydrate")]
+pub async fn fetch_catalog<L: LocaleCatalogs>(
+    base_url: &str,
+    locale: L,
+    namespace: Option<&str>,
+) -> Result<std::sync::Arc<str>, wasm_bindgen::JsValue> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let url = catalog_url(base_url, locale, namespace);
+    let known = fetched_catalogs().get(&url).cloned().or_else(|| {
+        locale
+            .get_catalog(namespace)
+            .map(|catalog| (catalog.etag(), std::sync::Arc::from(catalog.json)))
+    });
+
+    let init = web_sys::RequestInit::new();
+    init.set_method("GET");
+    let request_headers = web_sys::Headers::new()?;
+    if let Some((etag, _)) = &known {
+        request_headers.set(IF_NONE_MATCH, etag)?;
+    }
+    init.set_headers(&request_headers);
+    let request = web_sys::Request::new_with_str_and_init(&url, &init)?;
+
+    let global = js_sys::global();
+    let fetch = match global.dyn_ref::<web_sys::WorkerGlobalScope>() {
+        Some(worker) => worker.fetch_with_request(&request),
+        None => global
+            .unchecked_into::<web_sys::Window>()
+            .fetch_with_request(&request),
+    };
+    let response: web_sys::Response = JsFuture::from(fetch).await?.dyn_into()?;
+
+    if response.status() == 304 {
+        if let Some((_, json)) = known {
+            return Ok(json);
+        }
+    }
+    if !response.ok() {
+        return Err(wasm_bindgen::JsValue::from_str(&format!(
+            "fetching {} failed with status {}",
+            url,
+            response.status()
+        )));
+    }
+
+    let etag = response.headers().get(ETAG)?;
+    let json: std::sync::Arc<str> = JsFuture::from(response.text()?)
+        .await?
+        .as_string()
+        .unwrap_or_default()
+        .into();
+    if let Some(etag) = etag {
+        fetched_catalogs().insert(url, (etag, std::sync::Arc::clone(&json)));
+    }
+    Ok(json)
+}
+
+/// Return the catalog of `locale` (and `namespace`) fetched previously from `base_url`, without any request.
+#[cfg(feature = "hydrate")]
+pub fn cached_catalog<L: LocaleCatalogs>(
+    base_url: &str,
+    locale: L,
+    namespace: Option<&str>,
+) -> Option<std::sync::Arc<str>> {
+    let url = catalog_url(base_url, locale, namespace);
+    fetched_catalogs()
+        .get(&url)
+        .map(|(_, json)| std::sync::Arc::clone(json))
+}
+
+/// Fetch every catalog of `locale` from `base_url` in the background, so they are in the cache when the locale is selected.
+///
+/// Errors are ignored, the catalog is fetched again when it is needed.
+#[cfg(feature = "hydrate")]
+pub fn prefetch<L: LocaleCatalogs>(base_url: &str, locale: L) {
+    let namespaces = match L::NAMESPACES {
+        [] => vec![None],
+        namespaces => namespaces.iter().copied().map(Some).collect(),
+    };
+    for namespace in namespaces {
+        let base_url = base_url.to_string();
+        leptos::spawn_local(async move {
+            let _ = fetch_catalog(&base_url, locale, namespace).await;
+        });
+    }
+}
+
+/// Prefetch the catalogs of the locales matching the languages of the browser (`navigator.languages`), see [`prefetch`].
+///
+/// The current locale is usually one of them, its catalogs are only checked with their `ETag`.
+#[cfg(feature = "hydrate")]
+pub fn prefetch_preferred_locales<L: LocaleCatalogs>(base_url: &str) {
+    let languages = leptos::window().navigator().languages();
+    let mut locales: Vec<L> = vec![];
+    for language in languages.iter().filter_map(|language| language.as_string()) {
+        // `find_locale` falls back to the default locale, which is only prefetched if it matches.
+        let locale = L::find_locale(&[&language]);
+        if leptos_i18n_core::__private::language(locale.as_str())
+            != leptos_i18n_core::__private::language(&language)
+        {
+            continue;
+        }
+        if locales
+            .iter()
+            .all(|known| known.as_str() != locale.as_str())
+        {
+            locales.push(locale);
+            prefetch(base_url, locale);
+        }
+    }
+}
+
+// a panic while the lock is held can't leave the map in an invalid state, so a poisoned lock is still used.
+#[cfg(feature = "hydrate")]
+fn fetched_catalogs(
+) -> std::sync::MutexGuard<'static, std::collections::BTreeMap<String, (String, std::sync::Arc<str>)>>
+{
+    FETCHED_CATALOGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Actix handler serving the catalogs, the route must have a `locale` segment and a `namespace` one if namespaces are used.
+#[cfg(feature = "actix")]
+pub async fn actix_handler<L: LocaleCatalogs>(
+    req: actix_web::HttpRequest,
+) -> actix_web::HttpResponse {
+    let locale = req.match_info().get("locale").unwrap_or_default();
+    let namespace = req.match_info().get("namespace");
+    let response = respond::<L>(locale, namespace, actix_if_none_match(&req));
+    into_actix_response(response)
+}
+
+/// Actix handler serving the [`CatalogSchema`].
+#[cfg(feature = "actix")]
+pub async fn actix_schema_handler<L: LocaleCatalogs>(
+    req: actix_web::HttpRequest,
+) -> actix_web::HttpResponse {
+    into_actix_response(respond_schema::<L>(actix_if_none_match(&req)))
+}
+
+#[cfg(feature = "actix")]
+fn actix_if_none_match(req: &actix_web::HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(actix_web::http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+}
+
+#[cfg(feature = "actix")]
+fn into_actix_response(response: CatalogResponse) -> actix_web::HttpResponse {
+    let status = actix_web::http::StatusCode::from_u16(response.status)
+        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
+    let mut builder = actix_web::HttpResponse::build(status);
+    for header in response.headers {
+        builder.insert_header(header);
+    }
+    builder.body(response.body)
+}
+
+/// Axum handler serving the catalogs, the route must have a `locale` segment and a `namespace` one if namespaces are used.
+#[cfg(feature = "axum")]
+pub async fn axum_handler<L: LocaleCatalogs>(
+    axum::extract::Path(params): axum::extract::Path<std::collections::HashMap<String, String>>,
+    request_headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    let locale = params.get("locale").map(String::as_str).unwrap_or_default();
+    let namespace = params.get("namespace").map(String::as_str);
+    let response = respond::<L>(locale, namespace, axum_if_none_match(&request_headers));
+    into_axum_response(response)
+}
+
+/// Axum handler serving the [`CatalogSchema`].
+#[cfg(feature = "axum")]
+pub async fn axum_schema_handler<L: LocaleCatalogs>(
+    request_headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    into_axum_response(respond_schema::<L>(axum_if_none_match(&request_headers)))
+}
+
+#[cfg(feature = "axum")]
+fn axum_if_none_match(request_headers: &axum::http::HeaderMap) -> Option<&str> {
+    request_headers
+        .get(axum::http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+}
+
+#[cfg(feature = "axum")]
+fn into_axum_response(response: CatalogResponse) -> axum::response::Response {
+    use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+    use axum::response::IntoResponse;
+
+    let status = StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let mut response_headers = HeaderMap::new();
+    for (name, value) in response.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            response_headers.insert(name, value);
+        }
+    }
+    (status, response_headers, response.body).into_response()
+}
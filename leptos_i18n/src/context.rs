@@ -1,53 +1,184 @@
 use leptos::*;
 use leptos_meta::*;
 
-use crate::{fetch_locale, locale_traits::*};
+use crate::{fetch_locale, standalone::Translator, LocaleKeys, LocaleVariant, Locales};
 
 /// This context is the heart of the i18n system:
 ///
 /// It servers as a signal to the the current locale and enable reactivity to locale change.
 ///
 /// You access the translations and read/update the current locale through it.
+///
+/// It also holds the tenant whose catalog backs the translations, if tenants are declared in the configuration.
 #[derive(Debug, Clone, Copy)]
-pub struct I18nContext<T: Locales>(RwSignal<T::Variants>);
+pub struct I18nContext<T: Locales> {
+    locale: RwSignal<T::Variants>,
+    tenant: RwSignal<Option<&'static str>>,
+}
 
 impl<T: Locales> I18nContext<T> {
     /// Return the current locale subscribing to any changes.
     #[inline]
     pub fn get_locale(self) -> T::Variants {
-        self.0.get()
+        self.locale.get()
     }
 
     /// Return the current locale but does not subscribe to changes
     #[inline]
     pub fn get_locale_untracked(self) -> T::Variants {
-        self.0.get_untracked()
+        self.locale.get_untracked()
+    }
+
+    /// Return the styling hints of the current locale subscribing to any changes, see the `style` module.
+    #[inline]
+    pub fn get_style(self) -> crate::style::LocaleStyle {
+        self.get_locale().style()
+    }
+
+    /// Return the share of the keys translated in the current locale subscribing to any changes, from `0.0` to `1.0`.
+    #[inline]
+    pub fn get_completeness(self) -> f32 {
+        self.get_locale().completeness()
+    }
+
+    /// Return `true` if every key is translated in the current locale, subscribing to any changes.
+    #[inline]
+    pub fn is_complete(self) -> bool {
+        self.get_locale().is_complete()
     }
 
-    /// Return the keys for the current locale subscribing to any changes
+    /// Return the keys for the current locale and tenant subscribing to any changes
     #[inline]
     pub fn get_keys(self) -> &'static T::LocaleKeys {
         let variant = self.get_locale();
-        LocaleKeys::from_variant(variant)
+        match self.tenant.get() {
+            Some(tenant) => T::get_tenant_keys(variant, tenant),
+            None => LocaleKeys::from_variant(variant),
+        }
     }
 
-    /// Return the keys for the current locale but does not subscribe to changes
+    /// Return the keys for the current locale and tenant but does not subscribe to changes
     #[inline]
     pub fn get_keys_untracked(self) -> &'static T::LocaleKeys {
-        let variant = self.get_locale();
-        LocaleKeys::from_variant(variant)
+        let variant = self.get_locale_untracked();
+        match self.tenant.get_untracked() {
+            Some(tenant) => T::get_tenant_keys(variant, tenant),
+            None => LocaleKeys::from_variant(variant),
+        }
+    }
+
+    /// Return the current tenant subscribing to any changes, `None` when the shared catalog is used.
+    #[inline]
+    pub fn get_tenant(self) -> Option<&'static str> {
+        self.tenant.get()
+    }
+
+    /// Select the catalog of `tenant` and notify all subscribers, `None` or an unknown tenant select the shared catalog.
+    ///
+    /// On the server it is usually set right after providing the context, from the host or the session of the request.
+    pub fn set_tenant(self, tenant: Option<&str>) {
+        let tenant = tenant.and_then(|tenant| T::TENANTS.iter().copied().find(|t| *t == tenant));
+        self.tenant.set(tenant)
     }
 
     /// Set the locale and notify all subscribers
     #[inline]
     pub fn set_locale(self, lang: T::Variants) {
-        self.0.set(lang)
+        self.locale.set(lang)
     }
 
     /// Set the locale but does not notify the subscribers
     #[inline]
     pub fn set_locale_untracked(self, lang: T::Variants) {
-        self.0.set_untracked(lang)
+        self.locale.set_untracked(lang)
+    }
+
+    /// Call `f` with the previous and the new locale every time the locale change, to refetch the data depending on it
+    /// or update third-party widgets:
+    ///
+    /// ```rust, ignore
+    /// i18n.on_locale_change(move |old, new| analytics::track("locale_change", old.as_str(), new.as_str()));
+    /// ```
+    ///
+    /// `f` is not called for the initial locale nor when the locale is set to its current value, and only runs in the browser.
+    /// The subscription lasts as long as the current reactive owner, such as the component calling it.
+    pub fn on_locale_change<F>(self, f: F)
+    where
+        F: Fn(T::Variants, T::Variants) + 'static,
+    {
+        let locale = self.locale;
+        create_effect(move |previous: Option<T::Variants>| {
+            let new_locale = locale.get();
+            if let Some(old_locale) = previous {
+                if old_locale.as_str() != new_locale.as_str() {
+                    untrack(|| f(old_locale, new_locale));
+                }
+            }
+            new_locale
+        });
+    }
+
+    /// Return the translation of `key` registered at runtime by the plugin `namespace` in the current locale, subscribing to any changes.
+    ///
+    /// See the `plugins` module, the key itself is returned if no plugin registered it.
+    pub fn get_plugin_translation(self, namespace: &str, key: &str) -> String {
+        match crate::plugins::use_plugin_translations() {
+            Some(plugins) => plugins.translate(self, namespace, key),
+            None => key.to_string(),
+        }
+    }
+
+    /// Evaluate `f` with a context whose locale is `locale`, without changing the current locale of the application.
+    ///
+    /// The context given to `f` shares the tenant of this one, translating with it renders a preview in another locale:
+    ///
+    /// ```rust, ignore
+    /// view! {
+    ///     <h2>{t!(i18n, invoice_preview)}</h2>
+    ///     {i18n.with_locale(LocaleEnum::de, |i18n| view! { <p>{t!(i18n, invoice_title)}</p> })}
+    /// }
+    /// ```
+    ///
+    /// Components calling `use_i18n()` still get the context of the application, give them the preview context instead.
+    pub fn with_locale<R>(self, locale: T::Variants, f: impl FnOnce(Self) -> R) -> R {
+        f(I18nContext {
+            locale: create_rw_signal(locale),
+            tenant: self.tenant,
+        })
+    }
+
+    /// Return a [`Translator`] for the current locale and tenant, subscribing to any changes.
+    ///
+    /// The context is tied to the reactive runtime of its thread, the translator is `Send + Sync` and can be moved
+    /// to a wasm thread or a web worker to translate the strings of the work offloaded there.
+    #[inline]
+    pub fn get_translator(self) -> Translator<T::Variants> {
+        Translator::from_parts(self.get_locale(), self.get_tenant())
+    }
+
+    /// Return a [`Translator`] for the current locale and tenant but does not subscribe to changes
+    #[inline]
+    pub fn get_translator_untracked(self) -> Translator<T::Variants> {
+        Translator::from_parts(self.get_locale_untracked(), self.tenant.get_untracked())
+    }
+
+    fn new(locale: RwSignal<T::Variants>) -> Self {
+        I18nContext {
+            locale,
+            tenant: create_rw_signal(None),
+        }
+    }
+}
+
+#[cfg(all(feature = "hydrate", feature = "catalog"))]
+impl<T: Locales> I18nContext<T>
+where
+    T::Variants: crate::catalog::LocaleCatalogs,
+{
+    /// Fetch every catalog of `locale` served at `base_url` in the background, see `leptos_i18n::catalog::prefetch`.
+    #[inline]
+    pub fn prefetch(self, base_url: &str, locale: T::Variants) {
+        crate::catalog::prefetch(base_url, locale)
     }
 }
 
@@ -57,7 +188,7 @@ fn set_html_lang_attr(lang: &'static str) {
         lang: Some(lang.into()),
         dir: None,
         class: None,
-        attributes: None,
+        attributes: Default::default(),
     });
 }
 
@@ -75,13 +206,20 @@ fn init_context<T: Locales>() -> I18nContext<T> {
         set_lang_cookie::<T>(new_lang);
     });
 
-    let context = I18nContext::<T>(locale);
+    let context = I18nContext::<T>::new(locale);
 
     provide_context(context);
 
     context
 }
 
+// provide a context with a fixed initial locale, without detection nor side effects on the document.
+pub(crate) fn provide_context_with_locale<T: Locales>(locale: T::Variants) -> I18nContext<T> {
+    let context = I18nContext::<T>::new(create_rw_signal(locale));
+    provide_context(context);
+    context
+}
+
 /// Provide the `I18nContext` for the application.
 ///
 /// This function must be called at the highest possible level of the application.
@@ -105,14 +243,9 @@ pub fn use_i18n_context<T: Locales>() -> I18nContext<T> {
 
 #[cfg(all(feature = "hydrate", feature = "cookie"))]
 fn set_lang_cookie<T: Locales>(lang: T::Variants) -> Option<()> {
-    use crate::COOKIE_PREFERED_LANG;
     use wasm_bindgen::JsCast;
     let document = document().dyn_into::<web_sys::HtmlDocument>().ok()?;
-    let cookie = format!(
-        "{}={}; SameSite=Lax; Secure; Path=/; Max-Age=31536000",
-        COOKIE_PREFERED_LANG,
-        lang.as_str()
-    );
+    let cookie = crate::integration::lang_cookie(lang.as_str());
     document.set_cookie(&cookie).ok()
 }
 
@@ -16,7 +16,13 @@ pub fn fetch_locale<T: Locales>() -> T::Variants {
         .unwrap_or_default()
 }
 
-#[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+#[cfg(all(feature = "os_locale", not(any(feature = "ssr", feature = "hydrate"))))]
+#[inline]
+pub fn fetch_locale<T: Locales>() -> T::Variants {
+    crate::os_locale::detect_locale()
+}
+
+#[cfg(not(any(feature = "ssr", feature = "hydrate", feature = "os_locale")))]
 #[inline]
 pub fn fetch_locale<T: Locales>() -> T::Variants {
     Default::default()
@@ -0,0 +1,278 @@
+//! Traits to plug any server framework into the locale negotiation.
+//!
+//! The `actix` and `axum` features provide the integration for those frameworks,
+//! for any other one enable the `ssr` feature, implement [`RequestLocaleSource`] for the request
+//! and [`ResponseLocaleSink`] for the response, and provide them in the leptos context before rendering the app:
+//!
+//! ```rust, ignore
+//! use leptos_i18n::integration::{provide_locale_sink, provide_locale_source, RequestLocaleSource, ResponseLocaleSink};
+//!
+//! struct MyRequest(my_framework::Request);
+//!
+//! impl RequestLocaleSource for MyRequest {
+//!     fn header(&self, name: &str) -> Option<String> {
+//!         self.0.header(name).map(String::from)
+//!     }
+//! }
+//!
+//! // in the handler rendering the app:
+//! provide_locale_source(MyRequest(req));
+//! provide_locale_sink(MyResponseHeaders::new());
+//! ```
+//!
+//! `provide_i18n_context` will then pick the locale from the cookie or the `Accept-Language` header of the request,
+//! and add the `Content-Language` and `Vary` headers to the response. The locale cookie is only set for a locale
+//! chosen explicitly, with [`pin_locale`] or [`send_locale_cookie`], a negotiated locale is not remembered.
+//!
+//! With streaming, the suspended parts of a page are rendered after the response started, possibly in a context created
+//! once the request is gone. [`pin_request_locale`] resolves the locale once, when the rendering starts, and every
+//! `I18nContext` created for the rest of the response uses it:
+//!
+//! ```rust, ignore
+//! let app = Router::new().leptos_routes_with_context(
+//!     &leptos_options,
+//!     routes,
+//!     || leptos_i18n::integration::pin_request_locale::<Locales>(),
+//!     App,
+//! );
+//! ```
+
+#[cfg(feature = "ssr")]
+use std::rc::Rc;
+
+#[cfg(feature = "ssr")]
+use leptos::{provide_context, use_context};
+
+use crate::{
+    headers::{self, Negotiation},
+    LocaleVariant,
+};
+
+/// Gives access to the parts of a request used to find the locale.
+pub trait RequestLocaleSource {
+    /// Return the value of the header with the given name, the name must be matched case insensitively.
+    fn header(&self, name: &str) -> Option<String>;
+
+    /// Return the value of the cookie with the given name.
+    ///
+    /// The default implementation parse the `Cookie` header.
+    fn cookie(&self, name: &str) -> Option<String> {
+        self.header("Cookie")?
+            .split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(cookie_name, _)| *cookie_name == name)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+/// Receive the headers the response should contain given the locale of the request.
+pub trait ResponseLocaleSink {
+    /// Add a header to the response.
+    fn insert_header(&self, name: &'static str, value: String);
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Clone)]
+struct LocaleSource(Rc<dyn RequestLocaleSource>);
+
+#[cfg(feature = "ssr")]
+#[derive(Clone)]
+struct LocaleSink(Rc<dyn ResponseLocaleSink>);
+
+#[cfg(feature = "ssr")]
+/// Provide the request used by `provide_i18n_context` to find the locale.
+pub fn provide_locale_source<S: RequestLocaleSource + 'static>(source: S) {
+    provide_context(LocaleSource(Rc::new(source)))
+}
+
+#[cfg(feature = "ssr")]
+/// Provide the response `provide_i18n_context` will add the locale headers to.
+pub fn provide_locale_sink<S: ResponseLocaleSink + 'static>(sink: S) {
+    provide_context(LocaleSink(Rc::new(sink)))
+}
+
+/// Find the locale of a request: the locale cookie is used first if the `cookie` feature is enabled,
+/// then the `Accept-Language` header, and finally the default locale.
+pub fn resolve_locale<L: LocaleVariant, S: RequestLocaleSource + ?Sized>(source: &S) -> L {
+    #[cfg(feature = "cookie")]
+    if let Some(locale) = source
+        .cookie(crate::COOKIE_PREFERED_LANG)
+        .and_then(|value| L::from_str(&value))
+    {
+        return locale;
+    }
+
+    source
+        .header(headers::ACCEPT_LANGUAGE)
+        .map(|header| headers::from_accept_language(&header))
+        .unwrap_or_default()
+}
+
+/// How [`resolve_locale`] finds the locale: the cookie is read first when the `cookie` feature is enabled.
+#[cfg(feature = "cookie")]
+pub const REQUEST_NEGOTIATION: Negotiation = Negotiation::CookieThenAcceptLanguage;
+
+/// How [`resolve_locale`] finds the locale: the cookie is read first when the `cookie` feature is enabled.
+#[cfg(not(feature = "cookie"))]
+pub const REQUEST_NEGOTIATION: Negotiation = Negotiation::AcceptLanguage;
+
+/// Send the headers describing the locale of the response to the given sink, for a locale found with [`resolve_locale`].
+#[inline]
+pub fn send_locale_headers<L: LocaleVariant, S: ResponseLocaleSink + ?Sized>(sink: &S, locale: L) {
+    send_negotiated_locale_headers(sink, locale, REQUEST_NEGOTIATION)
+}
+
+/// Send the headers describing the locale of the response to the given sink, for a locale found as described by `negotiation`.
+///
+/// The `Vary` header matches the negotiation, so caches store one response per language.
+/// No cookie is set, a negotiated locale is not a choice of the user and the response stays cacheable.
+pub fn send_negotiated_locale_headers<L: LocaleVariant, S: ResponseLocaleSink + ?Sized>(
+    sink: &S,
+    locale: L,
+    negotiation: Negotiation,
+) {
+    for (name, value) in headers::negotiated_response_headers(locale, negotiation) {
+        sink.insert_header(name, value.to_string());
+    }
+}
+
+/// Send the `Set-Cookie` header remembering `locale` for the next requests to the given sink,
+/// for a locale chosen explicitly by the user, such as with a language selection form.
+#[cfg(feature = "cookie")]
+pub fn send_locale_cookie<L: LocaleVariant, S: ResponseLocaleSink + ?Sized>(sink: &S, locale: L) {
+    sink.insert_header("Set-Cookie", lang_cookie(locale.as_str()));
+}
+
+#[cfg(feature = "cookie")]
+pub(crate) fn lang_cookie(lang: &str) -> String {
+    format!(
+        "{}={}; SameSite=Lax; Secure; Path=/; Max-Age=31536000",
+        crate::COOKIE_PREFERED_LANG,
+        lang
+    )
+}
+
+// the locale used for the whole response once pinned.
+#[cfg(feature = "ssr")]
+#[derive(Clone, Copy)]
+struct PinnedLocale<L>(L);
+
+#[cfg(feature = "ssr")]
+/// Use `locale` for the whole response being rendered, including the parts streamed after the response started.
+///
+/// It must be called before rendering the app, in the context closure of the server integration,
+/// the locale headers are sent for it and the request is no longer used to find the locale.
+/// The locale is an explicit choice, so the locale cookie is set too when the `cookie` feature is enabled.
+pub fn pin_locale<L: LocaleVariant>(locale: L) {
+    pin(locale);
+    #[cfg(feature = "cookie")]
+    if let Some(sink) = use_context::<LocaleSink>() {
+        send_locale_cookie(&*sink.0, locale);
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn pin<L: LocaleVariant>(locale: L) {
+    send_locale_to_context(locale);
+    provide_context(PinnedLocale(locale))
+}
+
+#[cfg(feature = "ssr")]
+/// Resolve the locale of the request being rendered and use it for the whole response, see [`pin_locale`].
+///
+/// Returns the pinned locale. It is negotiated, so unlike [`pin_locale`] the locale cookie is not set.
+pub fn pin_request_locale<T: crate::Locales>() -> T::Variants {
+    if let Some(locale) = pinned_locale::<T::Variants>() {
+        return locale;
+    }
+    let locale = crate::server::resolve_locale_server_side::<T>();
+    pin(locale);
+    locale
+}
+
+#[cfg(feature = "ssr")]
+/// Return the locale pinned for the response being rendered, if any.
+pub fn pinned_locale<L: LocaleVariant>() -> Option<L> {
+    use_context::<PinnedLocale<L>>().map(|PinnedLocale(locale)| locale)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn locale_from_context<L: LocaleVariant>() -> Option<L> {
+    use_context::<LocaleSource>().map(|source| resolve_locale(&*source.0))
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn send_locale_to_context<L: LocaleVariant>(locale: L) {
+    if let Some(sink) = use_context::<LocaleSink>() {
+        send_locale_headers(&*sink.0, locale);
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use leptos::create_runtime;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    struct Headers(Rc<RefCell<Vec<&'static str>>>);
+
+    impl ResponseLocaleSink for Headers {
+        fn insert_header(&self, name: &'static str, _value: String) {
+            self.0.borrow_mut().push(name);
+        }
+    }
+
+    #[test]
+    fn test_pin_locale() {
+        let runtime = create_runtime();
+
+        let headers = Rc::new(RefCell::new(vec![]));
+        provide_locale_sink(Headers(Rc::clone(&headers)));
+        assert_eq!(pinned_locale::<Locale>(), None);
+
+        pin_locale(Locale::Fr);
+        assert_eq!(pinned_locale::<Locale>(), Some(Locale::Fr));
+        assert!(headers.borrow().contains(&headers::CONTENT_LANGUAGE));
+        #[cfg(feature = "cookie")]
+        assert!(headers.borrow().contains(&"Set-Cookie"));
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_negotiated_headers() {
+        let headers = Headers(Rc::new(RefCell::new(vec![])));
+        send_locale_headers(&headers, Locale::Fr);
+
+        assert!(headers.0.borrow().contains(&headers::CONTENT_LANGUAGE));
+        assert!(!headers.0.borrow().contains(&"Set-Cookie"));
+    }
+}
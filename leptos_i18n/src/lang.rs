@@ -0,0 +1,30 @@
+//! Mark content written in another language than the page, so screen readers pronounce it correctly.
+//!
+//! ```rust, ignore
+//! use leptos_i18n::lang::{in_lang, Lang};
+//!
+//! view! {
+//!     <p>"The motto is " <Lang lang="la">"Fluctuat nec mergitur"</Lang></p>
+//!     // "quote": "As Goethe wrote: <original>Mehr Licht!</original>"
+//!     <p>{t!(i18n, quote, <original> = in_lang("de"))}</p>
+//! }
+//! ```
+
+use leptos::*;
+
+/// Wrap the children in a `<span>` with the given `lang` attribute.
+#[component]
+pub fn Lang(
+    /// The language of the children, a locale tag such as `"de"` or `"pt-BR"`.
+    #[prop(into)]
+    lang: String,
+    /// The content in that language.
+    children: Children,
+) -> impl IntoView {
+    view! { <span lang=lang>{children()}</span> }
+}
+
+/// A component value for the translation macros wrapping the children in a `<span>` with the given `lang` attribute.
+pub fn in_lang(lang: &'static str) -> impl Fn(ChildrenFn) -> View + Clone + 'static {
+    move |children| view! { <span lang=lang>{children()}</span> }.into_view()
+}
@@ -17,15 +17,15 @@
 //! If you want to see what Leptos i18n is capable of, check out
 //! the [examples](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples):
 //! - [`hello_world_actix`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_actix) is a simple example
-//!     to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
+//!   to showcase the syntax and file structure to easily incorporate translations in you application using the actix backend
 //! - [`hello_world_axum`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/hello_world_axum) is like the actix hello world exemple
-//!     but use axum as the backend, it showcase that the code you will write with this library will be the same using actix or axum as a backend.
+//!   but use axum as the backend, it showcase that the code you will write with this library will be the same using actix or axum as a backend.
 //! - [`counter`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter) is the classic
-//!     counter example, showing how you can interpolate values in the translations and switch locale without full reload.
+//!   counter example, showing how you can interpolate values in the translations and switch locale without full reload.
 //! - [`counter_plurals`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/counter_plurals) is like the `counter` example
-//!     but show how you can use plurals to display different texts based on a count.
+//!   but show how you can use plurals to display different texts based on a count.
 //! - [`namespaces`](https://github.com/Baptistemontan/leptos_i18n/tree/master/examples/namespaces) show how to break down your translations
-//!     in multiple namespaces to avoid big files
+//!   in multiple namespaces to avoid big files
 //!
 //!
 //! Details on how to run each example can be found in its README.
@@ -33,11 +33,25 @@
 //! # Feature Flags
 //! - `nightly`: On `nightly` Rust, enables the function-call syntax the i18n  context to get/set the locale.
 //! - `hydrate`: Enable this feature when building for the client.
+//! - `ssr`: Enable this feature when building for the server with a custom integration, see the `integration` module.
 //! - `actix`: Enable this feature when building for the server with actix as the backend (can't be enabled with the `axum` feature).
 //! - `axum`: Enable this feature when building for the server with axum as the backend (can't be enabled with the `axum` feature).
 //! - `debug_interpolations`: Enable the macros to generate code to emit a warning if a key is supplied twice in interpolations and a better compilation error when a key is missing.
 //! - `cookie` (*Default*): Enable this feature to set a cookie on the client to remember the last locale set.
 //! - `supress_key_warnings`: Disable the warning emission of the `load_locales!()` macro when some keys are missing or ignored.
+//! - `serde`: Implement `serde::Serialize` and `serde::Deserialize` for the locale enum and the validation errors.
+//! - `os_locale`: Use the locale of the operating system as the initial locale when neither `ssr` nor `hydrate` are enabled, for desktop applications.
+//! - `catalog`: Embed the translations as JSON and enable the `catalog` module to serve them over HTTP.
+//! - `segmentation`: Enable the `segmentation` module with grapheme and word segmentation helpers.
+//! - `snapshot`: Generate a `snapshot()` function in the `i18n` module rendering every key of every locale, for golden tests.
+//! - `typed_keys`: Generate the `KeyEnum` and `KeyArgs` enums and a `translate` function in the `i18n` module, to select translations from data.
+//! - `panic_on_fallback`: Panic when the keys of a locale missing some keys are accessed, under `cfg(test)` or when the `LEPTOS_I18N_PANIC_ON_FALLBACK` environment variable is set.
+//! - `pseudo_locale`: Enable the `pseudo` module, wrapping the translations rendered by `t!` in markers for end-to-end tests when turned on at runtime.
+//! - `overrides`: Enable the `overrides` module, letting custom strings set at runtime shadow the translations rendered by `t!`.
+//! - `provenance`: Generate `LocaleEnum::provenance` and `LocaleEnum::key_source`, telling which locale supplies the value of every key of a locale.
+//! - `usage_stats`: Enable the `usage` module and count the uses of every key at runtime, reported by a generated `usage_report()` function in the `i18n` module. Meant for debug builds.
+//! - `a11y_audit`: Enable the `audit` module, reporting to the console the `alt`, `aria-label` or `title` attributes of the page likely left untranslated. Meant for development builds.
+//! - `router`: Enable the `router` module, with the `<I18nRoute>` component taking the locale from the first segment of the path with `leptos_router`.
 //!
 //! # A Simple Counter
 //!
@@ -116,22 +130,66 @@
 //! }
 //! ```
 
+pub mod assets;
+#[cfg(feature = "a11y_audit")]
+pub mod audit;
+#[cfg(feature = "catalog")]
+pub mod catalog;
 mod context;
 mod fetch_locale;
-mod locale_traits;
+pub mod integration;
+pub mod lang;
+pub mod meta;
+#[cfg(feature = "overrides")]
+pub mod overrides;
+pub mod plugins;
+#[cfg(feature = "pseudo_locale")]
+pub mod pseudo;
+pub mod render;
+#[cfg(feature = "router")]
+pub mod router;
+pub mod scope;
 #[cfg(feature = "ssr")]
 mod server;
+pub mod signal;
+#[cfg(feature = "ssr")]
+pub mod sitemap;
+#[cfg(feature = "ssr")]
+pub mod ssg;
+pub mod standalone;
+pub mod testing;
+#[cfg(feature = "usage_stats")]
+pub mod usage;
+pub mod vars;
+
+#[cfg(feature = "os_locale")]
+pub use leptos_i18n_core::os_locale;
+#[cfg(feature = "segmentation")]
+pub use leptos_i18n_core::segmentation;
+pub use leptos_i18n_core::{
+    casing, currency, format, gender, headers, measurement, number, plurals, slug, style, template,
+    validation,
+};
 
-#[cfg(all(any(feature = "ssr", feature = "hydrate"), feature = "cookie"))]
+#[cfg(feature = "cookie")]
 pub(crate) const COOKIE_PREFERED_LANG: &str = "i18n_pref_locale";
 
-pub use locale_traits::*;
+pub use leptos_i18n_core::{
+    LocaleKeys, LocaleVariant, Locales, UnknownLocaleError, VariantLocales,
+};
 
 pub use context::{provide_i18n_context, use_i18n_context, I18nContext};
 
-pub use leptos_i18n_macro::{load_locales, t};
+pub use leptos_i18n_macro::{
+    assert_key, derive_translation, load_locales, t, t_aria, td, td_string, use_i18n_scoped,
+    I18nDisplay, LocalizedError,
+};
 
 #[doc(hidden)]
 pub mod __private {
-    pub use super::locale_traits::BuildStr;
+    pub use crate::scope::TranslationContext;
+    pub use leptos_i18n_core;
+    pub use leptos_i18n_core::BuildStr;
+    #[cfg(feature = "serde")]
+    pub use serde;
 }
@@ -0,0 +1,126 @@
+//! Components setting the document metadata from the translations, updated when the locale change.
+//!
+//! They wrap the `leptos_meta` components, so `leptos_meta::provide_meta_context` must have been called
+//! (`provide_i18n_context` already does it):
+//!
+//! ```rust, ignore
+//! use leptos_i18n::meta::{I18nDescription, I18nTitle, LocaleAlternates};
+//!
+//! let i18n = use_i18n();
+//!
+//! view! {
+//!     <I18nTitle i18n key=|keys| keys.home_title />
+//!     <I18nDescription i18n key=|keys| keys.home_description />
+//!     <LocaleAlternates i18n href=|locale| format!("https://example.com/{}/", locale) />
+//! }
+//! ```
+//!
+//! [`use_translated_title`] does the same as `I18nTitle` from the code of a component, for titles with interpolations:
+//!
+//! ```rust, ignore
+//! use_translated_title(derive_translation!(i18n, profile_title, name = move || user.get().name));
+//! ```
+
+use leptos::*;
+use leptos_meta::{Link, Meta, Title};
+
+use leptos_i18n_core::__private as locale_data;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+/// Return the Open Graph representation of a locale, e.g. `"fr_FR"` for `"fr"`.
+pub fn og_locale<L: LocaleVariant>(locale: L) -> String {
+    let tag = locale.as_str();
+    let language = locale_data::language(tag);
+    match locale_data::region(tag) {
+        Some(region) => format!("{}_{}", language, region),
+        None => language,
+    }
+}
+
+/// Set the document title to the translation returned by `key` in the current locale.
+#[component]
+pub fn I18nTitle<T, F>(
+    /// The i18n context.
+    i18n: I18nContext<T>,
+    /// Select the translation used as the title.
+    key: F,
+) -> impl IntoView
+where
+    T: Locales,
+    F: Fn(&'static T::LocaleKeys) -> &'static str + 'static,
+{
+    let text = move || key(i18n.get_keys()).to_string();
+    view! { <Title text=text /> }
+}
+
+/// Keep the document title in sync with `title`, such as the `Signal` of a translation returned by `derive_translation!`,
+/// so it follows the current locale and the values of the interpolations.
+///
+/// The title is set for as long as the current reactive owner is alive, like with a `<Title>` component.
+pub fn use_translated_title(title: Signal<String>) {
+    // the `Title` component registers the title when it is created, its view is empty.
+    let _ = view! { <Title text=move || title.get() /> };
+}
+
+/// Set the description of the document to the translation returned by `key` in the current locale.
+///
+/// Both the `description` and the Open Graph `og:description` meta tags are set, for social previews.
+#[component]
+pub fn I18nDescription<T, F>(
+    /// The i18n context.
+    i18n: I18nContext<T>,
+    /// Select the translation used as the description.
+    key: F,
+) -> impl IntoView
+where
+    T: Locales,
+    F: Fn(&'static T::LocaleKeys) -> &'static str + Clone + 'static,
+{
+    let og_key = key.clone();
+    let description = move || key(i18n.get_keys()).to_string();
+    let og_description = move || og_key(i18n.get_keys()).to_string();
+    view! {
+        <Meta name="description" content=description />
+        <Meta property="og:description" content=og_description />
+    }
+}
+
+/// Add the links to the page in every locale, and the Open Graph locale tags.
+///
+/// `href` returns the URL of the page in the given locale. A `<link rel="alternate" hreflang=...>` is added for each locale,
+/// plus one with `hreflang="x-default"` pointing to the default locale.
+///
+/// The `og:locale` meta tag follows the current locale, the `og:locale:alternate` tags list the other locales.
+#[component]
+pub fn LocaleAlternates<T, F>(
+    /// The i18n context.
+    i18n: I18nContext<T>,
+    /// Return the URL of the page in the given locale.
+    href: F,
+) -> impl IntoView
+where
+    T: Locales,
+    F: Fn(T::Variants) -> String + 'static,
+{
+    let current = i18n.get_locale_untracked();
+    let links = T::Variants::get_all()
+        .iter()
+        .map(|&locale| {
+            view! { <Link rel="alternate" hreflang=locale.as_str() href=href(locale) /> }
+        })
+        .collect_view();
+    let alternates = T::Variants::get_all()
+        .iter()
+        .filter(|locale| locale.as_str() != current.as_str())
+        .map(|&locale| view! { <Meta property="og:locale:alternate" content=og_locale(locale) /> })
+        .collect_view();
+    let og = move || og_locale(i18n.get_locale());
+
+    view! {
+        {links}
+        <Link rel="alternate" hreflang="x-default" href=href(T::Variants::default()) />
+        <Meta property="og:locale" content=og />
+        {alternates}
+    }
+}
@@ -0,0 +1,156 @@
+//! Runtime overrides of the compiled translations, for white-label deployments.
+//!
+//! Custom strings of a tenant or a user, fetched from a database for example, shadow the translations of the same key path
+//! in the translations rendered by the `t!`, `t_aria!` and `derive_translation!` macros:
+//!
+//! ```rust, ignore
+//! let overrides = leptos_i18n::overrides::provide_overrides();
+//!
+//! // the path is written as in the macros, `namespace::key.subkey` with namespaces.
+//! overrides.set(LocaleEnum::en, "app_name", "Acme Portal");
+//! overrides.extend(LocaleEnum::fr, tenant_strings);
+//! ```
+//!
+//! The overrides are reactive, the views using an overridden key are updated when it is set or removed.
+//! An override is plain text, the interpolations of the key are not applied to it.
+//!
+//! The lookup code is only generated when the `overrides` feature is enabled, so other builds don't pay for it.
+
+use std::{borrow::Cow, collections::HashMap};
+
+use leptos::*;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+/// The store of the overrides, by locale and key path.
+#[derive(Debug, Clone, Copy)]
+pub struct Overrides(RwSignal<HashMap<(&'static str, String), String>>);
+
+impl Overrides {
+    /// Override the translation of the key at `path` for `locale`.
+    pub fn set<L: LocaleVariant>(self, locale: L, path: &str, value: impl Into<String>) {
+        self.0.update(|overrides| {
+            overrides.insert((locale.as_str(), path.to_string()), value.into());
+        })
+    }
+
+    /// Override the translations of multiple keys for `locale`, given as `(path, value)` pairs.
+    pub fn extend<L, I, P, V>(self, locale: L, values: I)
+    where
+        L: LocaleVariant,
+        I: IntoIterator<Item = (P, V)>,
+        P: Into<String>,
+        V: Into<String>,
+    {
+        let locale = locale.as_str();
+        self.0.update(|overrides| {
+            overrides.extend(
+                values
+                    .into_iter()
+                    .map(|(path, value)| ((locale, path.into()), value.into())),
+            )
+        })
+    }
+
+    /// Remove the override of the key at `path` for `locale`, the compiled translation is used again.
+    pub fn remove<L: LocaleVariant>(self, locale: L, path: &str) {
+        self.0.update(|overrides| {
+            overrides.remove(&(locale.as_str(), path.to_string()));
+        })
+    }
+
+    /// Remove every override, for example when the tenant changes.
+    pub fn clear(self) {
+        self.0.update(HashMap::clear)
+    }
+
+    /// Return the override of the key at `path` for `locale`, subscribing to any changes.
+    pub fn get<L: LocaleVariant>(self, locale: L, path: &str) -> Option<String> {
+        self.0
+            .with(|overrides| overrides.get(&(locale.as_str(), path.to_string())).cloned())
+    }
+}
+
+/// Provide the overrides store, or return the current one if it is already provided.
+pub fn provide_overrides() -> Overrides {
+    use_context().unwrap_or_else(|| {
+        let overrides = Overrides(create_rw_signal(HashMap::new()));
+        provide_context(overrides);
+        overrides
+    })
+}
+
+/// Return the overrides store previously provided, if any.
+#[inline]
+pub fn use_overrides() -> Option<Overrides> {
+    use_context()
+}
+
+/// Render the override of the key at `path` in the current locale if there is one, else the translation, used by the macros.
+#[doc(hidden)]
+pub fn wrap<T: Locales, V: IntoView>(
+    i18n: I18nContext<T>,
+    path: Cow<'static, str>,
+    translation: impl Fn() -> V + 'static,
+) -> impl Fn() -> View + 'static {
+    let overrides = use_overrides();
+    move || match overrides.and_then(|overrides| overrides.get(i18n.get_locale(), &path)) {
+        Some(value) => value.into_view(),
+        None => translation().into_view(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let runtime = create_runtime();
+
+        let overrides = provide_overrides();
+        overrides.set(Locale::En, "app_name", "Acme");
+        overrides.extend(Locale::Fr, [("app_name", "Acme FR"), ("title", "Titre")]);
+
+        assert_eq!(
+            overrides.get(Locale::En, "app_name").as_deref(),
+            Some("Acme")
+        );
+        assert_eq!(overrides.get(Locale::Fr, "title").as_deref(), Some("Titre"));
+        assert_eq!(overrides.get(Locale::En, "title"), None);
+
+        overrides.remove(Locale::En, "app_name");
+        assert_eq!(overrides.get(Locale::En, "app_name"), None);
+
+        overrides.clear();
+        assert_eq!(overrides.get(Locale::Fr, "app_name"), None);
+
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,156 @@
+//! Translations contributed at runtime by dynamically loaded plugins or feature modules.
+//!
+//! Each plugin registers its own namespace of string-keyed translations, kept apart from the compiled catalog,
+//! and read through the `I18nContext` so they follow the current locale:
+//!
+//! ```rust, ignore
+//! let plugins = leptos_i18n::plugins::provide_plugin_translations();
+//!
+//! plugins.register("billing", LocaleEnum::en, [("invoice.title", "Invoice"), ("invoice.pay", "Pay now")]);
+//! plugins.register("billing", LocaleEnum::fr, [("invoice.title", "Facture")]);
+//!
+//! let i18n = use_i18n();
+//! view! { <h1>{move || i18n.get_plugin_translation("billing", "invoice.title")}</h1> }
+//! ```
+//!
+//! A key missing in the current locale falls back to the default locale, then to the key itself, like the compiled keys.
+//! Plugin translations are plain text: they are not checked at compile time and have no interpolations.
+
+use std::collections::HashMap;
+
+use leptos::*;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+type Namespace = HashMap<(&'static str, String), String>;
+
+/// The store of the translations registered by plugins, by namespace, locale and key.
+#[derive(Debug, Clone, Copy)]
+pub struct PluginTranslations(RwSignal<HashMap<String, Namespace>>);
+
+impl PluginTranslations {
+    /// Register the translations of `namespace` for `locale`, given as `(key, value)` pairs.
+    ///
+    /// The keys already registered for this namespace and locale are replaced.
+    pub fn register<L, I, K, V>(self, namespace: &str, locale: L, translations: I)
+    where
+        L: LocaleVariant,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let locale = locale.as_str();
+        self.0.update(|namespaces| {
+            namespaces.entry(namespace.to_string()).or_default().extend(
+                translations
+                    .into_iter()
+                    .map(|(key, value)| ((locale, key.into()), value.into())),
+            )
+        })
+    }
+
+    /// Remove every translation of `namespace`, for example when its plugin is unloaded.
+    pub fn unregister(self, namespace: &str) {
+        self.0.update(|namespaces| {
+            namespaces.remove(namespace);
+        })
+    }
+
+    /// Return `true` if translations are registered for `namespace`, subscribing to any changes.
+    pub fn is_registered(self, namespace: &str) -> bool {
+        self.0.with(|namespaces| namespaces.contains_key(namespace))
+    }
+
+    /// Return the translation of `key` in `namespace` for `locale` without any fallback, subscribing to any changes.
+    pub fn get<L: LocaleVariant>(self, namespace: &str, locale: L, key: &str) -> Option<String> {
+        self.0.with(|namespaces| {
+            namespaces
+                .get(namespace)?
+                .get(&(locale.as_str(), key.to_string()))
+                .cloned()
+        })
+    }
+
+    /// Return the translation of `key` in `namespace` for the current locale of the context, subscribing to any changes.
+    ///
+    /// The value of the default locale is used if the key is missing in the current locale, and the key itself if it is missing in both.
+    pub fn translate<T: Locales>(self, i18n: I18nContext<T>, namespace: &str, key: &str) -> String {
+        let locale = i18n.get_locale();
+        self.get(namespace, locale, key)
+            .or_else(|| self.get(namespace, T::Variants::default(), key))
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+/// Provide the plugin translations store, or return the current one if it is already provided.
+pub fn provide_plugin_translations() -> PluginTranslations {
+    use_context().unwrap_or_else(|| {
+        let plugins = PluginTranslations(create_rw_signal(HashMap::new()));
+        provide_context(plugins);
+        plugins
+    })
+}
+
+/// Return the plugin translations store previously provided, if any.
+#[inline]
+pub fn use_plugin_translations() -> Option<PluginTranslations> {
+    use_context()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[test]
+    fn test_plugin_translations() {
+        let runtime = create_runtime();
+
+        let plugins = provide_plugin_translations();
+        plugins.register(
+            "billing",
+            Locale::En,
+            [("title", "Invoice"), ("pay", "Pay now")],
+        );
+        plugins.register("billing", Locale::Fr, [("title", "Facture")]);
+
+        assert!(plugins.is_registered("billing"));
+        assert_eq!(
+            plugins.get("billing", Locale::Fr, "title").as_deref(),
+            Some("Facture")
+        );
+        assert_eq!(plugins.get("billing", Locale::Fr, "pay"), None);
+        assert_eq!(plugins.get("shipping", Locale::En, "title"), None);
+
+        plugins.unregister("billing");
+        assert!(!plugins.is_registered("billing"));
+        assert_eq!(plugins.get("billing", Locale::En, "title"), None);
+
+        runtime.dispose();
+    }
+}
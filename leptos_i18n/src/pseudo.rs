@@ -0,0 +1,99 @@
+//! Pseudo-locale mode for end-to-end tests.
+//!
+//! When the mode is enabled, every translation rendered by the `t!`, `t_aria!` and `derive_translation!` macros is wrapped between [`START`] and [`END`],
+//! so a test can check that every text of the page comes from the translations:
+//!
+//! ```rust, ignore
+//! // in the app, for example when a `?pseudo` query parameter is present:
+//! leptos_i18n::pseudo::set_enabled(true);
+//!
+//! // in the E2E test, on the text content of the page:
+//! let leaked = leptos_i18n::pseudo::untranslated(&page_text);
+//! assert!(leaked.is_empty(), "hardcoded strings: {:?}", leaked);
+//! ```
+//!
+//! The wrapping code is only generated when the `pseudo_locale` feature is enabled, so production builds don't pay for it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use leptos::{Fragment, IntoView, View};
+
+/// The marker put before every translation.
+pub const START: char = '⟦';
+
+/// The marker put after every translation.
+pub const END: char = '⟧';
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable the pseudo-locale mode, translations rendered after the call are affected.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed)
+}
+
+/// Return `true` if the pseudo-locale mode is enabled.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Wrap a rendered translation between the markers if the pseudo-locale mode is enabled, used by the macros.
+#[doc(hidden)]
+pub fn wrap<V: IntoView>(view: V) -> View {
+    if !is_enabled() {
+        return view.into_view();
+    }
+    Fragment::new(vec![
+        START.to_string().into_view(),
+        view.into_view(),
+        END.to_string().into_view(),
+    ])
+    .into_view()
+}
+
+/// Return the parts of `text` outside of the markers, that is the text not coming from a translation.
+///
+/// Whitespace only parts are ignored, translations nested in the components of another translation are supported.
+pub fn untranslated(text: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            START => {
+                if depth == 0 {
+                    parts.push(&text[start..i]);
+                }
+                depth += 1;
+            }
+            END => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    start = i + c.len_utf8();
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth == 0 {
+        parts.push(&text[start..]);
+    }
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untranslated() {
+        assert!(untranslated("⟦Hello⟧ ⟦Click <b>⟦here⟧</b>⟧\n").is_empty());
+        assert_eq!(
+            untranslated("Home ⟦Bonjour⟧ Sign in ⟦Au revoir⟧!"),
+            vec!["Home", "Sign in", "!"]
+        );
+    }
+}
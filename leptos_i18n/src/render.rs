@@ -0,0 +1,117 @@
+//! Rendering of translations outside of an application, for emails or notifications generated on the server.
+//!
+//! ```rust, ignore
+//! let body: String = leptos_i18n::render::render_to_html(td!(LocaleEnum::fr, welcome_body, name = "Alice"));
+//! ```
+//!
+//! The `td_string!` macro does the same in one step.
+//!
+//! [`render_to_text`] render to plain text instead, it is used by the `derive_translation!` macro.
+
+use leptos::IntoView;
+
+/// Render a view to an HTML string, without the hydration markers added by leptos.
+///
+/// The text is escaped, so the output can be inserted in an HTML document as is.
+pub fn render_to_html<V: IntoView>(view: V) -> String {
+    let html = view.into_view().render_to_string();
+    strip_hydration_markers(&html)
+}
+
+/// Render a view to plain text: the tags are removed and the HTML entities decoded.
+///
+/// Use it when the translation ends up in a `String` displayed as text, such as a validation message held in a signal.
+pub fn render_to_text<V: IntoView>(view: V) -> String {
+    let html = view.into_view().render_to_string();
+    html_to_text(&strip_hydration_markers(&html))
+}
+
+/// Remove the tags of rendered HTML and decode the entities escaped by leptos.
+pub fn html_to_text(html: &str) -> String {
+    const ENTITIES: [(&str, char); 7] = [
+        ("&amp;", '&'),
+        ("&lt;", '<'),
+        ("&gt;", '>'),
+        ("&quot;", '"'),
+        ("&#x27;", '\''),
+        ("&#39;", '\''),
+        ("&#x2F;", '/'),
+    ];
+
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(c) = rest.chars().next() {
+        if c == '<' {
+            // text is escaped, so every '<' starts a tag.
+            rest = rest
+                .find('>')
+                .map(|end| &rest[end + 1..])
+                .unwrap_or_default();
+        } else if let Some((entity, decoded)) =
+            ENTITIES.iter().find(|(entity, _)| rest.starts_with(entity))
+        {
+            output.push(*decoded);
+            rest = &rest[entity.len()..];
+        } else {
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    output
+}
+
+/// Remove the comments and the `data-hk` attributes leptos add to the rendered HTML to hydrate it.
+///
+/// Comments can't be produced by a translation, as text is escaped, so every comment is removed.
+pub fn strip_hydration_markers(html: &str) -> String {
+    const HK_ATTR: &str = " data-hk=\"";
+
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    loop {
+        let comment = rest.find("<!--");
+        let attr = rest.find(HK_ATTR);
+        let (start, end_pattern, skip) = match (comment, attr) {
+            (Some(c), Some(a)) if a < c => (a, "\"", HK_ATTR.len()),
+            (Some(c), _) => (c, "-->", 4),
+            (None, Some(a)) => (a, "\"", HK_ATTR.len()),
+            (None, None) => break,
+        };
+        output.push_str(&rest[..start]);
+        let after = &rest[start + skip..];
+        match after.find(end_pattern) {
+            Some(end) => rest = &after[end + end_pattern.len()..],
+            None => {
+                rest = "";
+                break;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_hydration_markers() {
+        let html = "<!--hk=0-0-0-5o|leptos-<>-start-->You clicked <!--hk=0-0-0-4o|leptos-dyn-child-start-->5<!--hk=0-0-0-4c|leptos-dyn-child-end--> times<!--hk=0-0-0-5c|leptos-<>-end-->";
+        assert_eq!(strip_hydration_markers(html), "You clicked 5 times");
+
+        let html = "<strong data-hk=\"0-0-0-1\"><!--hk=0-0-0-3o|leptos-<>-start-->subkey_2<!--hk=0-0-0-3c|leptos-<>-end--></strong>";
+        assert_eq!(strip_hydration_markers(html), "<strong>subkey_2</strong>");
+
+        assert_eq!(strip_hydration_markers("a &lt;b&gt; c"), "a &lt;b&gt; c");
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<strong>Tom &amp; Jerry</strong> &lt;3 l&#x27;été &#x2F;"),
+            "Tom & Jerry <3 l'été /"
+        );
+        assert_eq!(html_to_text("<a href=\"x\">link</a>"), "link");
+    }
+}
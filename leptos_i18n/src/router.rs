@@ -0,0 +1,160 @@
+//! Routing with the locale as the first segment of the path, such as `/en/about` and `/fr/about`, with `leptos_router`.
+//!
+//! ```rust, ignore
+//! use leptos_i18n::router::{localized_href, use_locale_switch_href, I18nRoute};
+//! use leptos_router::*;
+//!
+//! #[component]
+//! pub fn App() -> impl IntoView {
+//!     let i18n = provide_i18n_context();
+//!
+//!     view! {
+//!         <Router>
+//!             <Routes>
+//!                 <I18nRoute i18n>
+//!                     <Route path="" view=Home/>
+//!                     <Route path="about" view=About/>
+//!                 </I18nRoute>
+//!             </Routes>
+//!         </Router>
+//!     }
+//! }
+//!
+//! #[component]
+//! fn Nav() -> impl IntoView {
+//!     let i18n = use_i18n();
+//!     view! {
+//!         // `/en/about` or `/fr/about`, following the current locale
+//!         <A href=localized_href(i18n, "/about")>{t!(i18n, about)}</A>
+//!         // the current page in French
+//!         <A href=use_locale_switch_href(LocaleEnum::fr)>"Français"</A>
+//!     }
+//! }
+//! ```
+//!
+//! `<I18nRoute>` matches the `/:locale` segment and sets the locale of the context from it, the nested routes are relative to it.
+//! A segment which is not a supported locale leaves the locale untouched, the routes declared next to `<I18nRoute>`
+//! with a static first segment, such as `/api`, take precedence over it.
+
+use leptos::*;
+use leptos_router::*;
+
+use crate::{I18nContext, LocaleVariant, Locales};
+
+/// A route matching the locale as the first segment of the path and setting the locale of the context from it.
+///
+/// The children are the routes of the pages, relative to the locale segment.
+#[component(transparent)]
+pub fn I18nRoute<T: Locales>(
+    /// The context whose locale follows the path.
+    i18n: I18nContext<T>,
+    /// The routes of the pages.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <Route
+            path=":locale"
+            view=move || {
+                view! {
+                    <LocaleFromPath i18n/>
+                    <Outlet/>
+                }
+            }
+        >
+            {children()}
+        </Route>
+    }
+}
+
+#[component]
+fn LocaleFromPath<T: Locales>(i18n: I18nContext<T>) -> impl IntoView {
+    let params = use_params_map();
+    create_isomorphic_effect(move |_| {
+        let locale = params.with(|params| {
+            params
+                .get("locale")
+                .and_then(|locale| T::Variants::from_str(locale))
+        });
+        if let Some(locale) = locale {
+            if locale.as_str() != i18n.get_locale_untracked().as_str() {
+                i18n.set_locale(locale);
+            }
+        }
+    });
+}
+
+/// Return the path of `path` in `locale`, e.g. `"/fr/about"` for `"/about"` or `"/en/about"`.
+///
+/// A first segment naming a supported locale is replaced, so the path of the current page can be given.
+pub fn localized_path<L: LocaleVariant>(locale: L, path: &str) -> String {
+    let path = path.trim_matches('/');
+    let path = match path.split_once('/') {
+        Some((first, rest)) if L::from_str(first).is_some() => rest,
+        None if L::from_str(path).is_some() => "",
+        _ => path,
+    };
+    if path.is_empty() {
+        format!("/{}/", locale.as_str())
+    } else {
+        format!("/{}/{}", locale.as_str(), path)
+    }
+}
+
+/// Return the `href` of `path` in the current locale, to be given to `<A>` and updated when the locale changes.
+pub fn localized_href<T: Locales>(
+    i18n: I18nContext<T>,
+    path: impl Into<String>,
+) -> impl Fn() -> String + Clone + 'static {
+    let path: String = path.into();
+    move || localized_path(i18n.get_locale(), &path)
+}
+
+/// Return the `href` of the current page in `locale`, for the links of a locale switcher.
+///
+/// Must be called under the `<Router>`.
+pub fn use_locale_switch_href<L: LocaleVariant>(
+    locale: L,
+) -> impl Fn() -> String + Clone + 'static {
+    let pathname = use_location().pathname;
+    move || pathname.with(|pathname| localized_path(locale, pathname))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[test]
+    fn test_localized_path() {
+        assert_eq!(localized_path(Locale::Fr, "/"), "/fr/");
+        assert_eq!(localized_path(Locale::Fr, "/about/team"), "/fr/about/team");
+        assert_eq!(localized_path(Locale::Fr, "/en/about"), "/fr/about");
+        assert_eq!(localized_path(Locale::En, "/fr"), "/en/");
+        assert_eq!(localized_path(Locale::En, "/english/"), "/en/english");
+    }
+}
@@ -0,0 +1,138 @@
+//! Scoped access to a subtree of the keys, for the components of a large area of the application.
+//!
+//! `use_i18n_scoped!` returns a scope whose keys resolve relative to the given subkeys, or namespace:
+//!
+//! ```rust, ignore
+//! let i18n = use_i18n_scoped!(dashboard.widgets);
+//!
+//! view! {
+//!     // `dashboard.widgets.title`
+//!     <h2>{t!(i18n, title)}</h2>
+//!     <p>{t!(i18n, empty.description)}</p>
+//! }
+//! ```
+//!
+//! A scope is used like the `I18nContext` in the `t!`, `t_aria!` and `derive_translation!` macros, the overrides and the usage
+//! statistics still use the whole path of the key. The context itself is returned by `I18nScope::context`, to read or change the locale.
+
+use std::borrow::Cow;
+
+use crate::{I18nContext, Locales};
+
+/// The `I18nContext` restricted to a subtree of the keys, created by `use_i18n_scoped!`.
+pub struct I18nScope<T: Locales, S: 'static> {
+    i18n: I18nContext<T>,
+    select: fn(&'static T::LocaleKeys) -> &'static S,
+    prefix: &'static str,
+}
+
+impl<T: Locales, S: 'static> Clone for I18nScope<T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Locales, S: 'static> Copy for I18nScope<T, S> {}
+
+impl<T: Locales, S: 'static> core::fmt::Debug for I18nScope<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("I18nScope")
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Locales, S: 'static> I18nScope<T, S> {
+    /// Create a scope selecting a subtree of the keys, used by `use_i18n_scoped!`.
+    ///
+    /// `prefix` is the path of the subtree with its separator, e.g. `"dashboard.widgets."` or `"namespace::"`.
+    #[doc(hidden)]
+    pub const fn new(
+        i18n: I18nContext<T>,
+        select: fn(&'static T::LocaleKeys) -> &'static S,
+        prefix: &'static str,
+    ) -> Self {
+        I18nScope {
+            i18n,
+            select,
+            prefix,
+        }
+    }
+
+    /// Return the context the scope is created from.
+    #[inline]
+    pub const fn context(self) -> I18nContext<T> {
+        self.i18n
+    }
+
+    /// Return the keys of the subtree in the current locale subscribing to any changes.
+    #[inline]
+    pub fn get_keys(self) -> &'static S {
+        (self.select)(self.i18n.get_keys())
+    }
+
+    /// Return the keys of the subtree in the current locale but does not subscribe to changes.
+    #[inline]
+    pub fn get_keys_untracked(self) -> &'static S {
+        (self.select)(self.i18n.get_keys_untracked())
+    }
+
+    /// Return the path of the subtree with its separator, e.g. `"dashboard.widgets."`.
+    #[inline]
+    pub const fn prefix(self) -> &'static str {
+        self.prefix
+    }
+}
+
+/// What the reactive translation macros accept as a context, the `I18nContext` or a scope of it.
+#[doc(hidden)]
+pub trait TranslationContext: Copy + 'static {
+    type Locales: Locales;
+    type Keys: 'static;
+
+    fn context(self) -> I18nContext<Self::Locales>;
+
+    fn get_keys(self) -> &'static Self::Keys;
+
+    // the whole path of a key written relative to the context.
+    fn key_path(self, path: &'static str) -> Cow<'static, str>;
+}
+
+impl<T: Locales> TranslationContext for I18nContext<T> {
+    type Locales = T;
+    type Keys = T::LocaleKeys;
+
+    #[inline]
+    fn context(self) -> I18nContext<T> {
+        self
+    }
+
+    #[inline]
+    fn get_keys(self) -> &'static T::LocaleKeys {
+        I18nContext::get_keys(self)
+    }
+
+    #[inline]
+    fn key_path(self, path: &'static str) -> Cow<'static, str> {
+        Cow::Borrowed(path)
+    }
+}
+
+impl<T: Locales, S: 'static> TranslationContext for I18nScope<T, S> {
+    type Locales = T;
+    type Keys = S;
+
+    #[inline]
+    fn context(self) -> I18nContext<T> {
+        self.i18n
+    }
+
+    #[inline]
+    fn get_keys(self) -> &'static S {
+        I18nScope::get_keys(self)
+    }
+
+    fn key_path(self, path: &'static str) -> Cow<'static, str> {
+        Cow::Owned(format!("{}{}", self.prefix, path))
+    }
+}
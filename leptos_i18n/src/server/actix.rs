@@ -1,4 +1,4 @@
-use crate::locale_traits::*;
+use crate::{LocaleVariant, Locales};
 use actix_web::http::header;
 
 pub fn fetch_locale_server<T: Locales>() -> T::Variants {
@@ -26,7 +26,7 @@ fn from_req<T: LocaleVariant>(req: &actix_web::HttpRequest) -> T {
         return Default::default();
     };
 
-    let langs = super::parse_header(header);
+    let langs = crate::headers::parse_accept_language(header);
 
     LocaleVariant::find_locale(&langs)
 }
@@ -1,4 +1,4 @@
-use crate::locale_traits::*;
+use crate::{LocaleVariant, Locales};
 use axum::http::header;
 
 pub fn fetch_locale_server<T: Locales>() -> T::Variants {
@@ -23,7 +23,7 @@ fn from_req<T: LocaleVariant>(req: &leptos_axum::RequestParts) -> T {
         return Default::default();
     };
 
-    let langs = super::parse_header(header);
+    let langs = crate::headers::parse_accept_language(header);
 
     T::find_locale(&langs)
 }
@@ -0,0 +1,67 @@
+//! Keep translations held in state up to date with the locale.
+//!
+//! The `derive_translation!` macro return a `Signal<String>` following the current locale,
+//! [`sync_translation`] write such a signal to any other signal, for example a field of a form state:
+//!
+//! ```rust, ignore
+//! let error = create_rw_signal(String::new());
+//!
+//! sync_translation(error, derive_translation!(i18n, required_field));
+//! ```
+//!
+//! [`InterpolationFn`] let the reactive interpolations, the count of plurals and the gender, be given a signal directly:
+//!
+//! ```rust, ignore
+//! let (count, set_count) = create_signal(0);
+//!
+//! t!(i18n, click_count, count) // instead of `count = move || count.get()`
+//! ```
+
+use leptos::{
+    create_isomorphic_effect, MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet, SignalSet,
+};
+
+/// Write the value of `translation` to `target` now and every time it change, such as when the locale change.
+///
+/// The synchronisation stops when the current reactive owner is disposed.
+pub fn sync_translation<S>(target: S, translation: Signal<String>)
+where
+    S: SignalSet<Value = String> + 'static,
+{
+    create_isomorphic_effect(move |_| target.set(translation.get()));
+}
+
+/// Values accepted for the interpolations read reactively by the translations, the count of plurals and the gender:
+/// closures such as `move || count.get()`, and signals directly.
+///
+/// With the `nightly` feature the signals are closures already, so only the closures implementation is needed.
+pub trait InterpolationFn<T>: Clone + 'static {
+    /// Return the current value, subscribing to any changes.
+    fn get_value(&self) -> T;
+}
+
+impl<T, F> InterpolationFn<T> for F
+where
+    F: Fn() -> T + Clone + 'static,
+{
+    #[inline]
+    fn get_value(&self) -> T {
+        self()
+    }
+}
+
+macro_rules! impl_interpolation_fn_for_signals {
+    ($($signal:ident),*) => {
+        $(
+            #[cfg(not(feature = "nightly"))]
+            impl<T: Clone + 'static> InterpolationFn<T> for $signal<T> {
+                #[inline]
+                fn get_value(&self) -> T {
+                    self.get()
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolation_fn_for_signals!(ReadSignal, RwSignal, Memo, Signal, MaybeSignal);
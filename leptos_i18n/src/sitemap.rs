@@ -0,0 +1,172 @@
+//! The localized URLs of the routes, to generate a `sitemap.xml` listing every page in every locale.
+//!
+//! ```rust, ignore
+//! let sitemap = leptos_i18n::sitemap::sitemap::<LocaleEnum, _>("https://example.com", &["/", "/about"], |locale, segment| {
+//!     match (locale, segment) {
+//!         (LocaleEnum::fr, "about") => Some("a-propos".to_string()),
+//!         _ => None,
+//!     }
+//! });
+//! ```
+//!
+//! Each route is prefixed with the locale like [`ssg::localized_route`](crate::ssg::localized_route), and each segment
+//! can be translated by the given function, returning `None` to keep the segment as is. Every `<url>` entry of the sitemap
+//! lists the other locales of the page with `<xhtml:link rel="alternate" hreflang=...>`, plus the `x-default` pointing to the default locale,
+//! as expected by the search engines.
+
+use crate::{ssg::localized_route, LocaleVariant};
+
+/// Return the path of `route` in every supported locale, with its segments translated by `translate_segment`.
+///
+/// The locales are in the order of `LocaleEnum::ALL`, the default one first.
+pub fn localized_routes<L, F>(route: &str, translate_segment: F) -> Vec<(L, String)>
+where
+    L: LocaleVariant,
+    F: Fn(L, &str) -> Option<String>,
+{
+    L::get_all()
+        .iter()
+        .map(|&locale| {
+            let translated = route
+                .split('/')
+                .map(|segment| match segment {
+                    "" => String::new(),
+                    segment => {
+                        translate_segment(locale, segment).unwrap_or_else(|| segment.to_string())
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+            (locale, localized_route(locale, &translated))
+        })
+        .collect()
+}
+
+/// Return the `<url>` entries of `route` in every supported locale, with the alternates of each page.
+///
+/// `base_url` is the origin the paths are appended to, such as `"https://example.com"`.
+pub fn sitemap_entries<L, F>(base_url: &str, route: &str, translate_segment: F) -> String
+where
+    L: LocaleVariant,
+    F: Fn(L, &str) -> Option<String>,
+{
+    let base_url = base_url.trim_end_matches('/');
+    let urls = localized_routes(route, translate_segment)
+        .into_iter()
+        .map(|(locale, path)| (locale, escape(&format!("{}{}", base_url, path))))
+        .collect::<Vec<_>>();
+
+    let default_url = urls
+        .iter()
+        .find(|(locale, _)| locale.as_str() == L::default().as_str())
+        .map(|(_, url)| url.as_str());
+    let mut alternates = String::new();
+    for (locale, url) in &urls {
+        alternates.push_str(&alternate_link(locale.as_str(), url));
+    }
+    if let Some(default_url) = default_url {
+        alternates.push_str(&alternate_link("x-default", default_url));
+    }
+
+    let mut entries = String::new();
+    for (_, url) in &urls {
+        entries.push_str(&format!("<url><loc>{}</loc>{}</url>", url, alternates));
+    }
+    entries
+}
+
+/// Return a whole `sitemap.xml` document listing every route in every supported locale, see [`sitemap_entries`].
+pub fn sitemap<L, F>(base_url: &str, routes: &[&str], translate_segment: F) -> String
+where
+    L: LocaleVariant,
+    F: Fn(L, &str) -> Option<String>,
+{
+    let entries = routes
+        .iter()
+        .map(|route| sitemap_entries(base_url, route, &translate_segment))
+        .collect::<String>();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?><urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">{}</urlset>",
+        entries
+    )
+}
+
+fn alternate_link(hreflang: &str, url: &str) -> String {
+    format!(
+        "<xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+        hreflang, url
+    )
+}
+
+// the URLs are in XML text and attributes, `&` is common in query strings.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    fn translate(locale: Locale, segment: &str) -> Option<String> {
+        match (locale, segment) {
+            (Locale::Fr, "about") => Some("a-propos".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_sitemap() {
+        assert_eq!(
+            localized_routes("/about/team", translate),
+            [
+                (Locale::En, "/en/about/team".to_string()),
+                (Locale::Fr, "/fr/a-propos/team".to_string())
+            ]
+        );
+        assert_eq!(
+            sitemap_entries("https://example.com/", "/about?a=1&b=2", |_: Locale, _: &str| None),
+            concat!(
+                "<url><loc>https://example.com/en/about?a=1&amp;b=2</loc>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/about?a=1&amp;b=2\"/>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"fr\" href=\"https://example.com/fr/about?a=1&amp;b=2\"/>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/en/about?a=1&amp;b=2\"/>",
+                "</url>",
+                "<url><loc>https://example.com/fr/about?a=1&amp;b=2</loc>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/about?a=1&amp;b=2\"/>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"fr\" href=\"https://example.com/fr/about?a=1&amp;b=2\"/>",
+                "<xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/en/about?a=1&amp;b=2\"/>",
+                "</url>",
+            )
+        );
+    }
+}
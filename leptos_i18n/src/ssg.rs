@@ -0,0 +1,133 @@
+//! Static site generation: prerender every route once per locale into a directory per language.
+//!
+//! ```rust, ignore
+//! let written = leptos_i18n::ssg::render_static_site::<LocaleEnum, _, _>("target/site", &["/", "/about"], |_locale, route| {
+//!     view! { <App route=route.to_string() /> }
+//! })?;
+//! ```
+//!
+//! The pages of a locale are written under its own directory, `/about` in `fr` becoming `target/site/fr/about/index.html`,
+//! so a static host serves them at `/fr/about`. The `I18nContext` is provided with the locale of the page,
+//! the `<html>` element of the document gets its `lang` attribute and the head contains the metadata set with `leptos_meta`.
+//!
+//! Leptos has no route listing this module can rely on, the routes to render and the way to render one are given by the caller,
+//! who also chooses what is served at `/`, for example a copy of the default locale or a redirection.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use leptos::IntoView;
+
+use crate::{render::render_to_html, testing::with_i18n, LocaleVariant, VariantLocales};
+
+/// Return the path of `route` prefixed with the locale, e.g. `"/fr/about"` for `"/about"`, for the links between the static pages.
+pub fn localized_route<L: LocaleVariant>(locale: L, route: &str) -> String {
+    let route = route.trim_matches('/');
+    if route.is_empty() {
+        format!("/{}/", locale.as_str())
+    } else {
+        format!("/{}/{}", locale.as_str(), route)
+    }
+}
+
+/// Return the file where the page of `route` in `locale` is written, e.g. `out_dir/fr/about/index.html`.
+pub fn output_file<L: LocaleVariant>(out_dir: impl AsRef<Path>, locale: L, route: &str) -> PathBuf {
+    let mut file = out_dir.as_ref().join(locale.as_str());
+    file.extend(route.split('/').filter(|segment| !segment.is_empty()));
+    file.push("index.html");
+    file
+}
+
+/// Render a whole HTML document for the view returned by `view`, with the context provided for `locale`.
+pub fn render_page<L, V>(locale: L, view: impl FnOnce() -> V) -> String
+where
+    L: VariantLocales,
+    V: IntoView,
+{
+    with_i18n(locale, || {
+        let body = render_to_html(view());
+        let head = leptos_meta::use_head().dehydrate();
+        format!(
+            "<!DOCTYPE html><html lang=\"{}\"><head>{}</head><body>{}</body></html>",
+            locale.as_str(),
+            head,
+            body
+        )
+    })
+}
+
+/// Render every route once for each supported locale and write the documents under `out_dir`, see [`output_file`].
+///
+/// `view` is called with the locale and the route of the page. The locales are in the order of `LocaleEnum::ALL`.
+/// Returns the files written.
+pub fn render_static_site<L, F, V>(
+    out_dir: impl AsRef<Path>,
+    routes: &[&str],
+    view: F,
+) -> io::Result<Vec<PathBuf>>
+where
+    L: VariantLocales,
+    F: Fn(L, &str) -> V,
+    V: IntoView,
+{
+    let mut written = vec![];
+    for &locale in L::get_all() {
+        for route in routes {
+            let html = render_page(locale, || view(locale, route));
+            let file = output_file(out_dir.as_ref(), locale, route);
+            if let Some(dir) = file.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&file, html)?;
+            written.push(file);
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[test]
+    fn test_static_paths() {
+        assert_eq!(localized_route(Locale::Fr, "/"), "/fr/");
+        assert_eq!(localized_route(Locale::Fr, "/blog/post/"), "/fr/blog/post");
+        assert_eq!(
+            output_file("site", Locale::En, "/"),
+            Path::new("site/en/index.html")
+        );
+        assert_eq!(
+            output_file("site", Locale::Fr, "/blog/post"),
+            Path::new("site/fr/blog/post/index.html")
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Translations resolved outside of a leptos application, in tokio tasks, background jobs, cron-style code or web workers.
+//!
+//! There is no request nor reactive runtime there, a [`Translator`] holds the locale, and the tenant if any, in place of the context.
+//! It is `Send + Sync`, so it can be moved into a task or a wasm thread, and is given to `td!` and `td_string!` like a locale:
+//!
+//! ```rust, ignore
+//! let translator = Translator::new(user.locale).with_tenant(&user.tenant);
+//!
+//! tokio::spawn(async move {
+//!     let (subject, body) = translator.run(|tr| {
+//!         (td!(tr, welcome_subject), td_string!(tr, welcome_body, name = user.name.clone()))
+//!     });
+//!     send_email(&user.email, subject, &body).await;
+//! });
+//! ```
+//!
+//! In an application, `I18nContext::get_translator` returns the translator of the current locale and tenant,
+//! to hand it to the work offloaded to a web worker.
+//!
+//! Rendering a translation with interpolations needs a reactive runtime, [`Translator::run`] and [`with_runtime`] create one for the
+//! duration of the closure. They must not be used where a runtime already exists, like in a component or a request handled by leptos,
+//! where `td_string!` can be used directly.
+
+use leptos::create_runtime;
+
+use crate::{Locales, VariantLocales};
+
+/// Run `f` in a new reactive runtime, disposed once `f` returns.
+pub fn with_runtime<R>(f: impl FnOnce() -> R) -> R {
+    let runtime = create_runtime();
+    let result = f();
+    runtime.dispose();
+    result
+}
+
+/// The locale and tenant of translations resolved without the `I18nContext`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Translator<L> {
+    locale: L,
+    tenant: Option<&'static str>,
+}
+
+impl<L> Translator<L> {
+    pub(crate) const fn from_parts(locale: L, tenant: Option<&'static str>) -> Self {
+        Translator { locale, tenant }
+    }
+}
+
+impl<L: VariantLocales> Translator<L> {
+    /// Create a translator for `locale`, using the shared catalog.
+    pub const fn new(locale: L) -> Self {
+        Translator {
+            locale,
+            tenant: None,
+        }
+    }
+
+    /// Use the catalog of `tenant`, an unknown tenant uses the shared catalog.
+    pub fn with_tenant(mut self, tenant: &str) -> Self {
+        self.tenant = <L::Locales as Locales>::TENANTS
+            .iter()
+            .copied()
+            .find(|t| *t == tenant);
+        self
+    }
+
+    /// Return the locale of the translator.
+    #[inline]
+    pub fn get_locale(self) -> L {
+        self.locale
+    }
+
+    /// Return the tenant of the translator, `None` when the shared catalog is used.
+    #[inline]
+    pub fn get_tenant(self) -> Option<&'static str> {
+        self.tenant
+    }
+
+    /// Return the keys for the locale and tenant, used by `td!` and `td_string!`.
+    pub fn get_keys(self) -> &'static <L::Locales as Locales>::LocaleKeys {
+        match self.tenant {
+            Some(tenant) => <L::Locales as Locales>::get_tenant_keys(self.locale, tenant),
+            None => <L::Locales as Locales>::get_keys(self.locale),
+        }
+    }
+
+    /// Run `f` with the translator in a new reactive runtime, disposed once `f` returns.
+    #[inline]
+    pub fn run<R>(self, f: impl FnOnce(Self) -> R) -> R {
+        with_runtime(|| f(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{render::render_to_text, LocaleKeys, LocaleVariant};
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Keys {
+        hello: &'static str,
+    }
+
+    impl LocaleKeys for Keys {
+        type Locales = TestLocales;
+        fn from_variant(variant: Locale) -> &'static Self {
+            match variant {
+                Locale::En => &Keys { hello: "Hello" },
+                Locale::Fr => &Keys { hello: "Bonjour" },
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct TestLocales;
+
+    impl Locales for TestLocales {
+        type Variants = Locale;
+        type LocaleKeys = Keys;
+
+        const TENANTS: &'static [&'static str] = &["acme"];
+
+        fn get_tenant_keys(locale: Locale, tenant: &str) -> &'static Keys {
+            match (tenant, locale) {
+                ("acme", Locale::Fr) => &Keys { hello: "Salut" },
+                _ => Keys::from_variant(locale),
+            }
+        }
+    }
+
+    impl VariantLocales for Locale {
+        type Locales = TestLocales;
+    }
+
+    #[test]
+    fn test_translator() {
+        let translator = Translator::new(Locale::Fr);
+        assert_eq!(translator.get_keys().hello, "Bonjour");
+        assert_eq!(translator.with_tenant("acme").get_keys().hello, "Salut");
+        assert_eq!(translator.with_tenant("unknown").get_tenant(), None);
+
+        let hello = std::thread::spawn(move || {
+            translator.run(|tr| render_to_text(tr.with_tenant("acme").get_keys().hello))
+        })
+        .join()
+        .unwrap();
+        assert_eq!(hello, "Salut");
+    }
+
+    #[test]
+    fn test_translator_from_context() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        with_runtime(|| {
+            let i18n = crate::context::provide_context_with_locale::<TestLocales>(Locale::Fr);
+            i18n.set_tenant(Some("acme"));
+            let translator = i18n.get_translator_untracked();
+            assert_send_sync(&translator);
+            assert_send_sync(translator.get_keys());
+
+            let hello = std::thread::spawn(move || translator.get_keys().hello)
+                .join()
+                .unwrap();
+            assert_eq!(hello, "Salut");
+        })
+    }
+}
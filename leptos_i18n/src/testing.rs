@@ -0,0 +1,249 @@
+//! Helpers for unit tests of components using the i18n context, without SSR or a browser.
+//!
+//! ```rust, ignore
+//! #[test]
+//! fn counter_is_translated() {
+//!     leptos_i18n::testing::with_i18n(LocaleEnum::fr, || {
+//!         let i18n = use_i18n();
+//!         assert_eq!(i18n.get_keys().click_to_inc, "Cliquez pour incrémenter le compteur");
+//!     });
+//! }
+//! ```
+//!
+//! [`td_test!`] resolve a translation to plain text for a given locale, rendering the components given without a value as markers:
+//!
+//! ```rust, ignore
+//! assert_eq!(td_test!(LocaleEnum::fr, welcome_body, name = "Alice", <b>), "Bienvenue <b>Alice</b> !");
+//! ```
+//!
+//! [`render_all_locales`] render a view under each supported locale, for visual or snapshot tests sweeping every language.
+//!
+//! With the `panic_on_fallback` feature, accessing the keys of a locale missing some keys panics under `cfg(test)`
+//! or when the `LEPTOS_I18N_PANIC_ON_FALLBACK` environment variable is set, instead of silently using the default locale.
+//!
+//! With the `snapshot` feature, `load_locales!` generate a `snapshot()` function rendering every key of every locale,
+//! [`format_snapshot`] turn it into a stable text for golden tests:
+//!
+//! ```rust, ignore
+//! #[test]
+//! fn translations_snapshot() {
+//!     insta::assert_snapshot!(leptos_i18n::testing::format_snapshot(&i18n::snapshot()));
+//! }
+//! ```
+
+use leptos::{create_runtime, ChildrenFn, Fragment, IntoView, View};
+use leptos_meta::provide_meta_context;
+
+use crate::{context::provide_context_with_locale, LocaleVariant, VariantLocales};
+
+pub use leptos_i18n_macro::td_test;
+
+/// Run `f` in a new reactive runtime where the `I18nContext` is provided with `locale` as the current locale.
+///
+/// The locale is not detected from the request, the cookie or the system, and the `lang` attribute of `<html>` is not set.
+/// The runtime is disposed once `f` returns.
+pub fn with_i18n<L, R>(locale: L, f: impl FnOnce() -> R) -> R
+where
+    L: VariantLocales,
+{
+    let runtime = create_runtime();
+    provide_meta_context();
+    provide_context_with_locale::<L::Locales>(locale);
+    let result = f();
+    runtime.dispose();
+    result
+}
+
+/// Render the view returned by `view` once for each supported locale, with the context provided for that locale,
+/// and return the HTML of each rendering, without the hydration markers.
+///
+/// The locales are in the order of `LocaleEnum::ALL`, the default one first, so the output is stable for snapshots:
+///
+/// ```rust, ignore
+/// for (locale, html) in leptos_i18n::testing::render_all_locales::<LocaleEnum, _, _>(|| view! { <Counter /> }) {
+///     insta::assert_snapshot!(format!("counter_{}", locale), html);
+/// }
+/// ```
+pub fn render_all_locales<L, F, V>(view: F) -> Vec<(L, String)>
+where
+    L: VariantLocales,
+    F: Fn() -> V,
+    V: IntoView,
+{
+    L::get_all()
+        .iter()
+        .map(|&locale| {
+            let html = with_i18n(locale, || crate::render::render_to_html(view()));
+            (locale, html)
+        })
+        .collect()
+}
+
+/// A component rendering its children between `<name>` and `</name>` as text, used by [`td_test!`] for the components given without a value.
+pub fn marker(name: &'static str) -> impl Fn(ChildrenFn) -> View + Clone + 'static {
+    move |children| {
+        Fragment::new(vec![
+            format!("<{}>", name).into_view(),
+            children().into_view(),
+            format!("</{}>", name).into_view(),
+        ])
+        .into_view()
+    }
+}
+
+/// The environment variable enabling the panics of the `panic_on_fallback` feature outside of `cfg(test)`, such as in integration tests.
+pub const PANIC_ON_FALLBACK_VAR: &str = "LEPTOS_I18N_PANIC_ON_FALLBACK";
+
+/// Panic if some keys of the locale fallback to the default locale, called when the keys are accessed with the `panic_on_fallback` feature.
+///
+/// It only panics when `is_test` is `true`, that is when the code using the keys is compiled with `cfg(test)`,
+/// or when the `LEPTOS_I18N_PANIC_ON_FALLBACK` environment variable is set.
+#[doc(hidden)]
+pub fn check_fallback(is_test: bool, locale: &str, fallback_keys: &[&str]) {
+    if fallback_keys.is_empty() {
+        return;
+    }
+    if is_test || std::env::var_os(PANIC_ON_FALLBACK_VAR).is_some() {
+        panic!(
+            "locale {:?} is missing the keys {:?}, they fallback to the default locale",
+            locale, fallback_keys
+        );
+    }
+}
+
+/// The rendering of a key for a locale, generated by the `snapshot()` function of `load_locales!` with the `snapshot` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotEntry<L> {
+    /// The locale the key is rendered for.
+    pub locale: L,
+    /// The path of the key, with the namespace and the sample count of plurals, e.g. `"ns::a.b (count = 2)"`.
+    pub key: &'static str,
+    /// The translation rendered to text.
+    pub value: String,
+}
+
+/// Format a snapshot with one `locale key = value` line per entry, line breaks in values are escaped so each entry stays on one line.
+pub fn format_snapshot<L: LocaleVariant>(entries: &[SnapshotEntry<L>]) -> String {
+    let mut output = String::new();
+    for entry in entries {
+        output.push_str(entry.locale.as_str());
+        output.push(' ');
+        output.push_str(entry.key);
+        output.push_str(" = ");
+        output.push_str(&entry.value.replace('\\', "\\\\").replace('\n', "\\n"));
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{use_i18n_context, LocaleKeys, Locales};
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        En,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            match s {
+                "en" => Some(Locale::En),
+                "fr" => Some(Locale::Fr),
+                _ => None,
+            }
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::En => "en",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::En, Locale::Fr]
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Keys {
+        hello: &'static str,
+    }
+
+    impl LocaleKeys for Keys {
+        type Locales = TestLocales;
+        fn from_variant(variant: Locale) -> &'static Self {
+            match variant {
+                Locale::En => &Keys { hello: "Hello" },
+                Locale::Fr => &Keys { hello: "Bonjour" },
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct TestLocales;
+
+    impl Locales for TestLocales {
+        type Variants = Locale;
+        type LocaleKeys = Keys;
+    }
+
+    impl VariantLocales for Locale {
+        type Locales = TestLocales;
+    }
+
+    #[test]
+    fn test_with_i18n() {
+        let hello = with_i18n(Locale::Fr, || {
+            let i18n = use_i18n_context::<TestLocales>();
+            assert_eq!(i18n.get_locale(), Locale::Fr);
+            i18n.set_locale(Locale::En);
+            i18n.get_keys().hello
+        });
+        assert_eq!(hello, "Hello");
+    }
+
+    #[test]
+    fn test_render_all_locales() {
+        let rendered = render_all_locales::<Locale, _, _>(|| {
+            use_i18n_context::<TestLocales>().get_keys().hello
+        });
+        assert_eq!(
+            rendered,
+            vec![
+                (Locale::En, "Hello".to_string()),
+                (Locale::Fr, "Bonjour".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_snapshot() {
+        let entries = [
+            SnapshotEntry {
+                locale: Locale::En,
+                key: "hello",
+                value: "Hello".to_string(),
+            },
+            SnapshotEntry {
+                locale: Locale::Fr,
+                key: "ns::lines (count = 2)",
+                value: "2 lignes\nici".to_string(),
+            },
+        ];
+        assert_eq!(
+            format_snapshot(&entries),
+            "en hello = Hello\nfr ns::lines (count = 2) = 2 lignes\\nici\n"
+        );
+    }
+
+    #[test]
+    fn test_check_fallback() {
+        check_fallback(true, "fr", &[]);
+        check_fallback(false, "fr", &["a.b"]);
+        let result = std::panic::catch_unwind(|| check_fallback(true, "fr", &["a.b"]));
+        assert!(result.is_err());
+    }
+}
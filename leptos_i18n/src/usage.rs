@@ -0,0 +1,116 @@
+//! Usage statistics of the keys, to find the hot strings worth optimizing and the dead keys worth removing.
+//!
+//! With the `usage_stats` feature, every evaluation of a translation by the macros is counted by key,
+//! and `load_locales!` generate a `usage_report()` function listing every key of the catalog with its count:
+//!
+//! ```rust, ignore
+//! // after running the app or the end-to-end tests:
+//! let report = i18n::usage_report();
+//! println!("{}", report);
+//!
+//! for key in report.unused() {
+//!     println!("never used: {}", key);
+//! }
+//! ```
+//!
+//! The counting code is only generated when the feature is enabled, it is meant for debug builds.
+//! Keys read directly from `get_keys()` are not counted.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+static COUNTS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+fn counts() -> std::sync::MutexGuard<'static, BTreeMap<String, u64>> {
+    COUNTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Count a use of the key at `path`, used by the macros.
+#[doc(hidden)]
+pub fn record(path: &str) {
+    let mut counts = counts();
+    match counts.get_mut(path) {
+        Some(count) => *count += 1,
+        None => {
+            counts.insert(path.to_string(), 1);
+        }
+    }
+}
+
+/// Return the number of uses of the key at `path`, written as in the macros.
+pub fn count(path: &str) -> u64 {
+    counts().get(path).copied().unwrap_or_default()
+}
+
+/// Reset every count, for example between two test scenarios.
+pub fn reset() {
+    counts().clear()
+}
+
+/// Build the report of the keys at `paths`, used by the generated `usage_report` function.
+#[doc(hidden)]
+pub fn report(paths: &[&'static str]) -> UsageReport {
+    let counts = counts();
+    let mut keys = paths
+        .iter()
+        .map(|path| (*path, counts.get(*path).copied().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    keys.sort_by(|(a_path, a_count), (b_path, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_path.cmp(b_path))
+    });
+    UsageReport { keys }
+}
+
+/// The number of uses of every key of the catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageReport {
+    /// The keys with their number of uses, the most used first.
+    pub keys: Vec<(&'static str, u64)>,
+}
+
+impl UsageReport {
+    /// Return the `n` most used keys.
+    pub fn hottest(&self, n: usize) -> &[(&'static str, u64)] {
+        &self.keys[..n.min(self.keys.len())]
+    }
+
+    /// Return the keys never used.
+    pub fn unused(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.keys
+            .iter()
+            .filter(|(_, count)| *count == 0)
+            .map(|(path, _)| *path)
+    }
+}
+
+impl std::fmt::Display for UsageReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (path, count) in &self.keys {
+            writeln!(f, "{:>8} {}", count, path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_report() {
+        record("usage_test::hello");
+        record("usage_test::hello");
+        record("usage_test::bye");
+
+        let report = report(&["usage_test::bye", "usage_test::hello", "usage_test::unused"]);
+
+        assert_eq!(count("usage_test::hello"), 2);
+        assert_eq!(report.hottest(1), [("usage_test::hello", 2)]);
+        assert_eq!(report.unused().collect::<Vec<_>>(), ["usage_test::unused"]);
+        assert_eq!(
+            report.to_string(),
+            "       2 usage_test::hello\n       1 usage_test::bye\n       0 usage_test::unused\n"
+        );
+    }
+}
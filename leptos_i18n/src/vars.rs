@@ -0,0 +1,50 @@
+//! Helpers for the values of the interpolation variables.
+//!
+//! A variable takes any `IntoView + Clone + 'static`, so a view can be inserted in a sentence,
+//! such as the avatar and the name of a user in `"{{ user }} commented on your post"`:
+//!
+//! ```rust, ignore
+//! t!(i18n, commented, user = move || view! { <UserChip user=user.clone() /> })
+//! ```
+//!
+//! The value is rendered every time the translation is, a closure creating the view is preferred over a view created once.
+//! [`view_var`] turns a closure that is not `Clone`, for example one capturing `Children`, into a value accepted by the macros.
+//!
+//! Strings given as variables are text: they are escaped and never parsed as HTML or as components, `"<b>"` is displayed as is.
+//! Views are inserted as nodes, and `td_string!` renders them to their HTML.
+
+use std::rc::Rc;
+
+use leptos::{IntoView, View};
+
+/// Make a view variable from a closure creating the view, the closure is shared instead of cloned.
+pub fn view_var<F, V>(f: F) -> impl Fn() -> View + Clone + 'static
+where
+    F: Fn() -> V + 'static,
+    V: IntoView,
+{
+    let f = Rc::new(f);
+    move || f().into_view()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::render_to_html;
+    use leptos::{create_runtime, view};
+
+    #[test]
+    fn test_view_var() {
+        let runtime = create_runtime();
+
+        let name = String::from("Alice");
+        let chip = view_var(move || view! { <b>{name.clone()}</b> });
+        let cloned = chip.clone();
+
+        assert_eq!(render_to_html(chip), "<b>Alice</b>");
+        assert_eq!(render_to_html(cloned), "<b>Alice</b>");
+        assert_eq!(render_to_html("<b>"), "&lt;b&gt;");
+
+        runtime.dispose();
+    }
+}
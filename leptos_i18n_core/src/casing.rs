@@ -0,0 +1,192 @@
+//! Locale-aware case transforms, for the languages where `str::to_uppercase` and `str::to_lowercase` get it wrong.
+//!
+//! ```rust
+//! use leptos_i18n_core::casing::Casing;
+//!
+//! assert_eq!(Casing::Upper.apply_for_tag("istanbul", "tr"), "İSTANBUL");
+//! assert_eq!(Casing::Lower.apply_for_tag("DIŞ", "tr"), "dış");
+//! assert_eq!(Casing::Upper.apply_for_tag("straße", "de"), "STRASSE");
+//! assert_eq!(Casing::Title.apply_for_tag("ijsselmeer", "nl"), "IJsselmeer");
+//! ```
+//!
+//! The same transforms are available in the locale files with `{{ name, upper }}`, `{{ name, lower }}` and `{{ name, title }}`.
+
+use alloc::string::String;
+
+use crate::{locale_data, LocaleVariant};
+
+/// A case transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Casing {
+    /// `upper`, every letter uppercased.
+    Upper,
+    /// `lower`, every letter lowercased.
+    Lower,
+    /// `title`, the first letter of each word uppercased and the others lowercased.
+    Title,
+}
+
+impl Casing {
+    /// All the transforms.
+    pub const ALL: &'static [Casing] = &[Casing::Upper, Casing::Lower, Casing::Title];
+
+    /// Return the name of the transform as written in the locale files, e.g. `"upper"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Casing::Upper => "upper",
+            Casing::Lower => "lower",
+            Casing::Title => "title",
+        }
+    }
+
+    /// Parse the name of a transform as written in the locale files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|casing| casing.as_str() == name.trim())
+    }
+
+    /// Apply the transform with the rules of the given locale.
+    #[inline]
+    pub fn apply<L: LocaleVariant>(self, s: &str, locale: L) -> String {
+        self.apply_for_tag(s, locale.as_str())
+    }
+
+    /// Apply the transform with the rules of the language of the given locale tag.
+    pub fn apply_for_tag(self, s: &str, tag: &str) -> String {
+        let language = locale_data::language(tag);
+        match self {
+            Casing::Upper => upper(s, &language),
+            Casing::Lower => lower(s, &language),
+            Casing::Title => title(s, &language),
+        }
+    }
+}
+
+impl core::fmt::Display for Casing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Uppercase a string with the rules of the given locale.
+#[inline]
+pub fn to_upper<L: LocaleVariant>(s: &str, locale: L) -> String {
+    Casing::Upper.apply(s, locale)
+}
+
+/// Lowercase a string with the rules of the given locale.
+#[inline]
+pub fn to_lower<L: LocaleVariant>(s: &str, locale: L) -> String {
+    Casing::Lower.apply(s, locale)
+}
+
+/// Title case a string with the rules of the given locale.
+#[inline]
+pub fn to_title<L: LocaleVariant>(s: &str, locale: L) -> String {
+    Casing::Title.apply(s, locale)
+}
+
+// turkish and azerbaijani have a dotted and a dotless i in both cases.
+fn has_dotless_i(language: &str) -> bool {
+    matches!(language, "tr" | "az")
+}
+
+fn push_upper(c: char, language: &str, output: &mut String) {
+    match c {
+        'i' if has_dotless_i(language) => output.push('İ'),
+        // greek drops the accents in uppercase.
+        'ά' if language == "el" => output.push('Α'),
+        'έ' if language == "el" => output.push('Ε'),
+        'ή' if language == "el" => output.push('Η'),
+        'ί' if language == "el" => output.push('Ι'),
+        'ό' if language == "el" => output.push('Ο'),
+        'ύ' if language == "el" => output.push('Υ'),
+        'ώ' if language == "el" => output.push('Ω'),
+        'ΐ' if language == "el" => output.push('Ϊ'),
+        'ΰ' if language == "el" => output.push('Ϋ'),
+        // the full mapping, so 'ß' becomes "SS".
+        c => output.extend(c.to_uppercase()),
+    }
+}
+
+fn upper(s: &str, language: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    for c in s.chars() {
+        push_upper(c, language, &mut output);
+    }
+    output
+}
+
+fn lower(s: &str, language: &str) -> String {
+    if !has_dotless_i(language) {
+        // handles the final sigma.
+        return s.to_lowercase();
+    }
+    let mut output = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            'I' => output.push('ı'),
+            'İ' => output.push('i'),
+            c => output.extend(c.to_lowercase()),
+        }
+    }
+    output
+}
+
+fn title(s: &str, language: &str) -> String {
+    let flush = |word: &mut String, output: &mut String| {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            let rest = chars.as_str();
+            // the dutch digraph "ij" is capitalized as a whole.
+            if language == "nl" && matches!(first, 'i' | 'I') && rest.starts_with(['j', 'J']) {
+                output.push_str("IJ");
+                output.push_str(&lower(&rest[1..], language));
+            } else {
+                push_upper(first, language, output);
+                output.push_str(&lower(rest, language));
+            }
+        }
+        word.clear();
+    };
+
+    let mut output = String::with_capacity(s.len());
+    let mut word = String::new();
+    for c in s.chars() {
+        if c.is_whitespace() {
+            flush(&mut word, &mut output);
+            output.push(c);
+        } else {
+            word.push(c);
+        }
+    }
+    flush(&mut word, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_casing() {
+        assert_eq!(Casing::Upper.apply_for_tag("istanbul", "en"), "ISTANBUL");
+        assert_eq!(Casing::Upper.apply_for_tag("istanbul", "tr-TR"), "İSTANBUL");
+        assert_eq!(
+            Casing::Lower.apply_for_tag("DİYARBAKIR", "tr"),
+            "diyarbakır"
+        );
+        assert_eq!(Casing::Lower.apply_for_tag("ΟΔΟΣ", "el"), "οδο\u{3C2}");
+        assert_eq!(Casing::Upper.apply_for_tag("Straße", "de"), "STRASSE");
+        assert_eq!(Casing::Upper.apply_for_tag("Αθήνα", "el"), "ΑΘΗΝΑ");
+        assert_eq!(Casing::Title.apply_for_tag("iLK  adım", "tr"), "İlk  Adım");
+        assert_eq!(
+            Casing::Title.apply_for_tag("ijsselmeer ij", "nl"),
+            "IJsselmeer IJ"
+        );
+        assert_eq!(Casing::from_name(" title "), Some(Casing::Title));
+        assert_eq!(Casing::from_name("snake"), None);
+    }
+}
@@ -0,0 +1,440 @@
+//! The translations as JSON, for clients loading them at runtime or for non-Rust consumers such as mobile apps.
+//!
+//! With the `catalog` feature of `leptos_i18n`, `load_locales!` embeds the translations of every locale file as minified JSON,
+//! with a hash of it used as an `ETag`, and implement [`LocaleCatalogs`] for the locale enum.
+//!
+//! [`respond`] build the response to a catalog request for any framework.
+//!
+//! The [`CatalogSchema`] describe every key, with its interpolation variables, components, plural count type
+//! and the description given to translators,
+//! [`respond_schema`] serve it as JSON so a client can check it handles the same keys:
+//!
+//! ```json
+//! {
+//!   "default_locale": "en",
+//!   "locales": ["en", "fr"],
+//!   "namespaces": [],
+//!   "keys": [
+//!     { "namespace": null, "key": "click_count", "plural": "i64", "variables": ["count"], "components": [], "description": null },
+//!     { "namespace": null, "key": "subkeys.welcome", "plural": null, "variables": ["name"], "components": ["b"], "description": "Shown after login" }
+//!   ]
+//! }
+//! ```
+//!
+//! [`CatalogSchema::to_typescript`] turn it into TypeScript definitions, for the JS/TS islands or companion SPAs
+//! consuming the served catalogs to get the same compile-time checks of the keys and their arguments.
+
+use core::fmt::Write;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{headers, LocaleVariant};
+
+/// The translations of a locale, or of a namespace of a locale, as embedded by `load_locales!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Catalog {
+    /// The name of the locale.
+    pub locale: &'static str,
+    /// The name of the namespace, if namespaces are used.
+    pub namespace: Option<&'static str>,
+    /// The translations of the locale file as minified JSON, without the metadata and with the missing keys filled.
+    pub json: &'static str,
+    /// A hash of `json`, computed at compile time.
+    pub hash: u64,
+}
+
+fn etag(hash: u64) -> String {
+    format!("\"{:016x}\"", hash)
+}
+
+fn etag_matches(hash: u64, if_none_match: &str) -> bool {
+    let etag = etag(hash);
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+}
+
+impl Catalog {
+    /// Return the `ETag` header value for this catalog.
+    pub fn etag(&self) -> String {
+        etag(self.hash)
+    }
+
+    /// Return `true` if the given `If-None-Match` header value match this catalog.
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        etag_matches(self.hash, if_none_match)
+    }
+}
+
+/// The description of a key of the translations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeySchema {
+    /// The namespace of the key, if namespaces are used.
+    pub namespace: Option<&'static str>,
+    /// The path of the key, with subkeys separated by dots, e.g. `"subkeys.subkey_1"`.
+    pub path: &'static str,
+    /// The type of the count if the key is a plural in at least one locale, e.g. `"u32"`.
+    pub plural: Option<&'static str>,
+    /// The names of the interpolation variables, sorted, including `"count"` for plurals.
+    pub variables: &'static [&'static str],
+    /// The names of the interpolation components, sorted.
+    pub components: &'static [&'static str],
+    /// The description given to translators with an `"@key"` entry, if any.
+    pub description: Option<&'static str>,
+}
+
+/// The description of all the keys of the translations, generated by `load_locales!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CatalogSchema {
+    /// The name of the default locale.
+    pub default_locale: &'static str,
+    /// The names of the locales.
+    pub locales: &'static [&'static str],
+    /// The names of the namespaces, empty if namespaces are not used.
+    pub namespaces: &'static [&'static str],
+    /// The keys, sorted by namespace then path.
+    pub keys: &'static [KeySchema],
+    /// The schema as JSON, see the [module documentation](self) for its format.
+    pub json: &'static str,
+    /// A hash of `json`, computed at compile time.
+    pub hash: u64,
+}
+
+impl CatalogSchema {
+    /// Return the `ETag` header value for this schema.
+    pub fn etag(&self) -> String {
+        etag(self.hash)
+    }
+
+    /// Return `true` if the given `If-None-Match` header value match this schema.
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        etag_matches(self.hash, if_none_match)
+    }
+
+    /// Return the description of the key at `path` in `namespace`.
+    pub fn get_key(&self, namespace: Option<&str>, path: &str) -> Option<&'static KeySchema> {
+        self.keys
+            .iter()
+            .find(|key| key.namespace == namespace && key.path == path)
+    }
+
+    /// Return TypeScript definitions of the catalog, to write in a `.d.ts` file:
+    ///
+    /// ```ts
+    /// export type Locale = "en" | "fr";
+    /// export type Namespace = never;
+    /// export interface TranslationArgs {
+    ///   "click_count": { "count": number };
+    ///   /** Shown after login */
+    ///   "subkeys.welcome": { "name": string | number; "b": (children: string) => string };
+    /// }
+    /// export type TranslationKey = keyof TranslationArgs;
+    /// ```
+    ///
+    /// The keys are written `namespace::path` with namespaces, like in the macros.
+    pub fn to_typescript(&self) -> String {
+        fn union(names: &[&str]) -> String {
+            if names.is_empty() {
+                return "never".to_string();
+            }
+            let names = names.iter().map(|name| ts_string(name)).collect::<Vec<_>>();
+            names.join(" | ")
+        }
+
+        let mut output = String::new();
+        output.push_str("// Generated by leptos_i18n from the catalog schema, do not edit.\n\n");
+        let _ = writeln!(output, "export type Locale = {};", union(self.locales));
+        let _ = writeln!(
+            output,
+            "export type Namespace = {};",
+            union(self.namespaces)
+        );
+        output.push_str("\nexport interface TranslationArgs {\n");
+        for key in self.keys {
+            if let Some(description) = key.description {
+                let _ = writeln!(output, "  /** {} */", description.replace("*/", "*\\/"));
+            }
+            let path = match key.namespace {
+                Some(namespace) => format!("{}::{}", namespace, key.path),
+                None => key.path.to_string(),
+            };
+            let args = key
+                .variables
+                .iter()
+                .map(|variable| {
+                    let ty = match *variable {
+                        "count" if key.plural.is_some() => "number",
+                        "gender" => "\"masculine\" | \"feminine\" | \"neuter\" | \"other\"",
+                        _ => "string | number",
+                    };
+                    format!("{}: {}", ts_string(variable), ty)
+                })
+                .chain(key.components.iter().map(|component| {
+                    format!("{}: (children: string) => string", ts_string(component))
+                }))
+                .collect::<Vec<_>>();
+            let args = if args.is_empty() {
+                "Record<string, never>".to_string()
+            } else {
+                format!("{{ {} }}", args.join("; "))
+            };
+            let _ = writeln!(output, "  {}: {};", ts_string(&path), args);
+        }
+        output.push_str("}\n\nexport type TranslationKey = keyof TranslationArgs;\n");
+        output
+    }
+}
+
+// a double quoted string literal, valid in both JSON and TypeScript.
+fn ts_string(s: &str) -> String {
+    let mut output = String::with_capacity(s.len() + 2);
+    output.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+/// Trait implemented by the locale enum when the `catalog` feature is enabled.
+pub trait LocaleCatalogs: LocaleVariant {
+    /// The names of the namespaces, empty if namespaces are not used.
+    const NAMESPACES: &'static [&'static str];
+
+    /// The description of all the keys.
+    const SCHEMA: &'static CatalogSchema;
+
+    /// Return the catalog of this locale for the given namespace.
+    ///
+    /// `namespace` must be `None` if namespaces are not used, and `Some` if they are.
+    fn get_catalog(self, namespace: Option<&str>) -> Option<&'static Catalog>;
+}
+
+/// The name of the `ETag` header.
+pub const ETAG: &str = "ETag";
+
+/// The name of the `If-None-Match` header.
+pub const IF_NONE_MATCH: &str = "If-None-Match";
+
+/// The `Cache-Control` header value of the catalogs: caches must check the `ETag` before reusing them,
+/// so new translations are picked up as soon as they are deployed.
+pub const CACHE_CONTROL_VALUE: &str = "no-cache";
+
+/// A framework agnostic response to a catalog request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogResponse {
+    /// The status code: `200`, `304` when the client already has the catalog, or `404` if the locale or the namespace does not exist.
+    pub status: u16,
+    /// The headers of the response.
+    pub headers: Vec<(&'static str, String)>,
+    /// The body of the response, empty for `304` and `404`.
+    pub body: &'static str,
+}
+
+/// Build the response to a request for the catalog of `locale` (and `namespace`).
+///
+/// `if_none_match` is the value of the `If-None-Match` header of the request, if any.
+pub fn respond<L: LocaleCatalogs>(
+    locale: &str,
+    namespace: Option<&str>,
+    if_none_match: Option<&str>,
+) -> CatalogResponse {
+    let Some(catalog) = L::from_str(locale).and_then(|locale| locale.get_catalog(namespace)) else {
+        return CatalogResponse {
+            status: 404,
+            headers: vec![],
+            body: "",
+        };
+    };
+
+    json_response(
+        catalog.json,
+        catalog.hash,
+        Some(catalog.locale),
+        if_none_match,
+    )
+}
+
+/// Build the response to a request for the schema of the catalogs.
+///
+/// `if_none_match` is the value of the `If-None-Match` header of the request, if any.
+pub fn respond_schema<L: LocaleCatalogs>(if_none_match: Option<&str>) -> CatalogResponse {
+    json_response(L::SCHEMA.json, L::SCHEMA.hash, None, if_none_match)
+}
+
+fn json_response(
+    json: &'static str,
+    hash: u64,
+    locale: Option<&'static str>,
+    if_none_match: Option<&str>,
+) -> CatalogResponse {
+    let mut response_headers = vec![
+        (ETAG, etag(hash)),
+        ("Cache-Control", CACHE_CONTROL_VALUE.to_string()),
+    ];
+    if let Some(locale) = locale {
+        response_headers.push((headers::CONTENT_LANGUAGE, locale.to_string()));
+    }
+
+    if if_none_match.is_some_and(|value| etag_matches(hash, value)) {
+        return CatalogResponse {
+            status: 304,
+            headers: response_headers,
+            body: "",
+        };
+    }
+
+    response_headers.push((
+        "Content-Type",
+        "application/json; charset=utf-8".to_string(),
+    ));
+    CatalogResponse {
+        status: 200,
+        headers: response_headers,
+        body: json,
+    }
+}
+
+/// Return the URL of a catalog given the base URL the catalogs are served at,
+/// e.g. `"/i18n/fr/common"` for `"/i18n"`, the `fr` locale and the `common` namespace.
+pub fn catalog_url<L: LocaleVariant>(base_url: &str, locale: L, namespace: Option<&str>) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    match namespace {
+        Some(namespace) => format!("{}/{}/{}", base_url, locale.as_str(), namespace),
+        None => format!("{}/{}", base_url, locale.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    struct Fr;
+
+    const FR: Catalog = Catalog {
+        locale: "fr",
+        namespace: None,
+        json: "{\"hello\":\"Bonjour\"}",
+        hash: 0x1234,
+    };
+
+    impl LocaleVariant for Fr {
+        fn from_str(s: &str) -> Option<Self> {
+            (s == "fr").then_some(Fr)
+        }
+        fn as_str(self) -> &'static str {
+            "fr"
+        }
+        fn get_all() -> &'static [Self] {
+            &[Fr]
+        }
+    }
+
+    const SCHEMA: CatalogSchema = CatalogSchema {
+        default_locale: "fr",
+        locales: &["fr"],
+        namespaces: &[],
+        keys: &[KeySchema {
+            namespace: None,
+            path: "hello",
+            plural: None,
+            variables: &[],
+            components: &[],
+            description: None,
+        }],
+        json: "{}",
+        hash: 0x5678,
+    };
+
+    impl LocaleCatalogs for Fr {
+        const NAMESPACES: &'static [&'static str] = &[];
+
+        const SCHEMA: &'static CatalogSchema = &SCHEMA;
+
+        fn get_catalog(self, namespace: Option<&str>) -> Option<&'static Catalog> {
+            namespace.is_none().then_some(&FR)
+        }
+    }
+
+    #[test]
+    fn test_catalog_url() {
+        assert_eq!(catalog_url("/i18n/", Fr, None), "/i18n/fr");
+        assert_eq!(catalog_url("/i18n", Fr, Some("common")), "/i18n/fr/common");
+    }
+
+    #[test]
+    fn test_respond() {
+        let response = respond::<Fr>("fr", None, None);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, FR.json);
+        assert!(response
+            .headers
+            .contains(&(ETAG, "\"0000000000001234\"".to_string())));
+
+        let response = respond::<Fr>("fr", None, Some("W/\"0000000000001234\""));
+        assert_eq!(response.status, 304);
+        assert_eq!(response.body, "");
+
+        assert_eq!(respond::<Fr>("fr", None, Some("\"abcd\"")).status, 200);
+        assert_eq!(respond::<Fr>("en", None, None).status, 404);
+        assert_eq!(respond::<Fr>("fr", Some("common"), None).status, 404);
+    }
+
+    #[test]
+    fn test_respond_schema() {
+        let response = respond_schema::<Fr>(None);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "{}");
+        assert_eq!(
+            respond_schema::<Fr>(Some("\"0000000000005678\"")).status,
+            304
+        );
+        assert_eq!(SCHEMA.get_key(None, "hello"), Some(&SCHEMA.keys[0]));
+        assert_eq!(SCHEMA.get_key(Some("common"), "hello"), None);
+    }
+
+    #[test]
+    fn test_to_typescript() {
+        const SCHEMA_NS: CatalogSchema = CatalogSchema {
+            namespaces: &["common"],
+            keys: &[
+                KeySchema {
+                    namespace: Some("common"),
+                    path: "cart.items",
+                    plural: Some("u32"),
+                    variables: &["count", "gender"],
+                    components: &["b"],
+                    description: Some("Items in the \"cart\" */"),
+                },
+                SCHEMA.keys[0],
+            ],
+            ..SCHEMA
+        };
+        let expected = r#"// Generated by leptos_i18n from the catalog schema, do not edit.
+
+export type Locale = "fr";
+export type Namespace = "common";
+
+export interface TranslationArgs {
+  /** Items in the "cart" *\/ */
+  "common::cart.items": { "count": number; "gender": "masculine" | "feminine" | "neuter" | "other"; "b": (children: string) => string };
+  "hello": Record<string, never>;
+}
+
+export type TranslationKey = keyof TranslationArgs;
+"#;
+        assert_eq!(SCHEMA_NS.to_typescript(), expected);
+    }
+}
@@ -0,0 +1,304 @@
+//! Currency metadata from ISO 4217 and locale aware formatting of monetary amounts.
+//!
+//! ```rust
+//! use leptos_i18n_core::currency::{Currency, Money};
+//!
+//! let eur = Currency::from_code("EUR").unwrap();
+//!
+//! assert_eq!(Money::new(-1234.5, eur).format_for_tag("fr"), "-1\u{202F}234,50\u{A0}€");
+//! assert_eq!(Money::new(-1234.5, eur).accounting().format_for_tag("en"), "(€1,234.50)");
+//! ```
+
+use alloc::string::String;
+
+use crate::{format::NumberFormat, locale_data, LocaleVariant};
+
+/// Metadata of a currency as defined by ISO 4217.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    /// The alphabetic code of the currency, e.g. `"EUR"`.
+    pub code: &'static str,
+    /// The numeric code of the currency, e.g. `978`.
+    pub numeric: u16,
+    /// The number of digits after the decimal separator, e.g. 2 for cents.
+    pub fraction_digits: u8,
+    /// A symbol that can't be confused with the one of another currency, e.g. `"US$"`.
+    pub symbol: &'static str,
+    /// The short symbol used in the regions using the currency, e.g. `"$"`.
+    pub narrow_symbol: &'static str,
+}
+
+macro_rules! currencies {
+    ($(($code:literal, $numeric:literal, $digits:literal, $symbol:literal, $narrow:literal),)*) => {
+        const CURRENCIES: &[Currency] = &[
+            $(
+                Currency {
+                    code: $code,
+                    numeric: $numeric,
+                    fraction_digits: $digits,
+                    symbol: $symbol,
+                    narrow_symbol: $narrow,
+                },
+            )*
+        ];
+    };
+}
+
+currencies! {
+    ("AED", 784, 2, "AED", "AED"),
+    ("ARS", 32, 2, "ARS", "$"),
+    ("AUD", 36, 2, "A$", "$"),
+    ("BGN", 975, 2, "BGN", "лв."),
+    ("BHD", 48, 3, "BHD", "BHD"),
+    ("BRL", 986, 2, "R$", "R$"),
+    ("CAD", 124, 2, "CA$", "$"),
+    ("CHF", 756, 2, "CHF", "CHF"),
+    ("CLP", 152, 0, "CLP", "$"),
+    ("CNY", 156, 2, "CN¥", "¥"),
+    ("COP", 170, 2, "COP", "$"),
+    ("CZK", 203, 2, "CZK", "Kč"),
+    ("DKK", 208, 2, "DKK", "kr."),
+    ("EGP", 818, 2, "EGP", "E£"),
+    ("EUR", 978, 2, "€", "€"),
+    ("GBP", 826, 2, "£", "£"),
+    ("HKD", 344, 2, "HK$", "$"),
+    ("HUF", 348, 2, "HUF", "Ft"),
+    ("IDR", 360, 2, "IDR", "Rp"),
+    ("ILS", 376, 2, "₪", "₪"),
+    ("INR", 356, 2, "₹", "₹"),
+    ("ISK", 352, 0, "ISK", "kr"),
+    ("JOD", 400, 3, "JOD", "JOD"),
+    ("JPY", 392, 0, "JP¥", "¥"),
+    ("KRW", 410, 0, "₩", "₩"),
+    ("KWD", 414, 3, "KWD", "KWD"),
+    ("MAD", 504, 2, "MAD", "MAD"),
+    ("MXN", 484, 2, "MX$", "$"),
+    ("MYR", 458, 2, "MYR", "RM"),
+    ("NGN", 566, 2, "NGN", "₦"),
+    ("NOK", 578, 2, "NOK", "kr"),
+    ("NZD", 554, 2, "NZ$", "$"),
+    ("OMR", 512, 3, "OMR", "OMR"),
+    ("PHP", 608, 2, "₱", "₱"),
+    ("PKR", 586, 2, "PKR", "Rs"),
+    ("PLN", 985, 2, "PLN", "zł"),
+    ("QAR", 634, 2, "QAR", "QAR"),
+    ("RON", 946, 2, "RON", "lei"),
+    ("RUB", 643, 2, "RUB", "₽"),
+    ("SAR", 682, 2, "SAR", "SAR"),
+    ("SEK", 752, 2, "SEK", "kr"),
+    ("SGD", 702, 2, "SGD", "$"),
+    ("THB", 764, 2, "THB", "฿"),
+    ("TND", 788, 3, "TND", "TND"),
+    ("TRY", 949, 2, "TRY", "₺"),
+    ("TWD", 901, 2, "NT$", "$"),
+    ("UAH", 980, 2, "UAH", "₴"),
+    ("USD", 840, 2, "US$", "$"),
+    ("VND", 704, 0, "₫", "₫"),
+    ("XAF", 950, 0, "FCFA", "FCFA"),
+    ("XOF", 952, 0, "F\u{202F}CFA", "F\u{202F}CFA"),
+    ("ZAR", 710, 2, "ZAR", "R"),
+}
+
+fn region_currency(region: &str) -> Option<&'static str> {
+    let code = match region {
+        "AE" => "AED",
+        "AR" => "ARS",
+        "AU" => "AUD",
+        "BG" => "BGN",
+        "BR" => "BRL",
+        "CA" => "CAD",
+        "CH" | "LI" => "CHF",
+        "CL" => "CLP",
+        "CN" => "CNY",
+        "CO" => "COP",
+        "CZ" => "CZK",
+        "DK" => "DKK",
+        "EG" => "EGP",
+        "AT" | "BE" | "CY" | "DE" | "EE" | "ES" | "FI" | "FR" | "GR" | "HR" | "IE" | "IT"
+        | "LT" | "LU" | "LV" | "MT" | "NL" | "PT" | "SI" | "SK" => "EUR",
+        "GB" => "GBP",
+        "HK" => "HKD",
+        "HU" => "HUF",
+        "ID" => "IDR",
+        "IL" => "ILS",
+        "IN" => "INR",
+        "IS" => "ISK",
+        "JP" => "JPY",
+        "KR" => "KRW",
+        "MA" => "MAD",
+        "MX" => "MXN",
+        "MY" => "MYR",
+        "NG" => "NGN",
+        "NO" => "NOK",
+        "NZ" => "NZD",
+        "PH" => "PHP",
+        "PK" => "PKR",
+        "PL" => "PLN",
+        "RO" => "RON",
+        "RU" => "RUB",
+        "SA" => "SAR",
+        "SE" => "SEK",
+        "SG" => "SGD",
+        "TH" => "THB",
+        "TR" => "TRY",
+        "TW" => "TWD",
+        "UA" => "UAH",
+        "US" => "USD",
+        "VN" => "VND",
+        "ZA" => "ZAR",
+        _ => return None,
+    };
+    Some(code)
+}
+
+impl Currency {
+    /// Return all the known currencies.
+    pub const fn all() -> &'static [Currency] {
+        CURRENCIES
+    }
+
+    /// Find a currency by its alphabetic code, case insensitive.
+    pub fn from_code(code: &str) -> Option<&'static Currency> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Find a currency by its numeric code.
+    pub fn from_numeric(numeric: u16) -> Option<&'static Currency> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.numeric == numeric)
+    }
+
+    /// Return the currency used in the region of the given locale tag.
+    ///
+    /// A unicode `cu` extension in the tag (e.g. `"fr-CH-u-cu-eur"`) overrides the regional currency.
+    pub fn from_tag(tag: &str) -> Option<&'static Currency> {
+        if let Some(code) = locale_data::unicode_extension(tag, "cu") {
+            return Self::from_code(code);
+        }
+        let region = locale_data::region(tag)?;
+        region_currency(&region).and_then(Self::from_code)
+    }
+
+    /// Return the currency used in the region of the given locale.
+    #[inline]
+    pub fn for_locale<L: LocaleVariant>(locale: L) -> Option<&'static Currency> {
+        Self::from_tag(locale.as_str())
+    }
+
+    /// Return the symbol to use for this currency in the given locale tag.
+    ///
+    /// The narrow symbol is used when the currency is the one of the locale region, the unambiguous one otherwise.
+    pub fn symbol_for_tag(&self, tag: &str) -> &'static str {
+        let is_local = locale_data::region(tag)
+            .and_then(|region| region_currency(&region))
+            .is_some_and(|code| code == self.code);
+        if is_local {
+            self.narrow_symbol
+        } else {
+            self.symbol
+        }
+    }
+}
+
+/// How negative amounts are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CurrencyStyle {
+    /// Negative amounts are prefixed with a minus sign: `-$1.00`.
+    #[default]
+    Standard,
+    /// Negative amounts are wrapped in parentheses: `($1.00)`.
+    Accounting,
+}
+
+/// A monetary amount in a given currency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Money {
+    amount: f64,
+    currency: &'static Currency,
+    style: CurrencyStyle,
+}
+
+impl Money {
+    /// Create a new amount of the given currency.
+    pub const fn new(amount: f64, currency: &'static Currency) -> Self {
+        Money {
+            amount,
+            currency,
+            style: CurrencyStyle::Standard,
+        }
+    }
+
+    /// Set the style used for negative amounts.
+    pub const fn with_style(self, style: CurrencyStyle) -> Self {
+        Money { style, ..self }
+    }
+
+    /// Display negative amounts in parentheses.
+    pub const fn accounting(self) -> Self {
+        self.with_style(CurrencyStyle::Accounting)
+    }
+
+    /// Format the amount for the given locale tag.
+    ///
+    /// The amount is formatted as the `{{ price, currency(CODE) }}` variables of the translations, see [`NumberFormat::Currency`].
+    #[inline]
+    pub fn format_for_tag(self, tag: &str) -> String {
+        NumberFormat::Currency(self.currency.code).format_styled_for_tag(
+            self.amount,
+            self.style,
+            tag,
+        )
+    }
+
+    /// Format the amount for the given locale.
+    #[inline]
+    pub fn format<L: LocaleVariant>(self, locale: L) -> String {
+        self.format_for_tag(locale.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let usd = Currency::from_code("usd").unwrap();
+        assert_eq!(usd.numeric, 840);
+        assert_eq!(Currency::from_numeric(392).unwrap().code, "JPY");
+        assert_eq!(Currency::from_tag("en").unwrap().code, "USD");
+        assert_eq!(Currency::from_tag("fr-CH").unwrap().code, "CHF");
+        assert_eq!(Currency::from_tag("fr-CH-u-cu-eur").unwrap().code, "EUR");
+        assert_eq!(Currency::from_tag("eo"), None);
+    }
+
+    #[test]
+    fn test_format() {
+        let usd = Currency::from_code("USD").unwrap();
+        let jpy = Currency::from_code("JPY").unwrap();
+        assert_eq!(Money::new(1234.5, usd).format_for_tag("en-US"), "$1,234.50");
+        assert_eq!(
+            Money::new(1234.5, usd).format_for_tag("en-CA"),
+            "US$1,234.50"
+        );
+        assert_eq!(Money::new(1234.6, jpy).format_for_tag("ja"), "￥1,235");
+        assert_eq!(
+            Money::new(-3.0, usd).accounting().format_for_tag("en"),
+            "($3.00)"
+        );
+        // german has no accounting pattern.
+        assert_eq!(
+            Money::new(-3.0, usd).accounting().format_for_tag("de-DE"),
+            "-3,00\u{A0}$"
+        );
+        // the same text as the `currency(CODE)` variables.
+        assert_eq!(
+            Money::new(9.5, usd).format_for_tag("pt-BR"),
+            NumberFormat::Currency("USD").format_for_tag(9.5, "pt-BR")
+        );
+        assert_eq!(Money::new(-0.001, usd).format_for_tag("en"), "$0.00");
+    }
+}
@@ -0,0 +1,624 @@
+//! Locale-aware formatting of the numbers and dates interpolated in the translations.
+//!
+//! ```rust
+//! use leptos_i18n_core::format::{DateLength, DateTime, DateTimeFormat, NumberFormat};
+//!
+//! assert_eq!(NumberFormat::Number.format_for_tag(1234.5, "fr"), "1\u{202F}234,5");
+//! assert_eq!(NumberFormat::Percent.format_for_tag(0.25, "de"), "25\u{A0}%");
+//! assert_eq!(NumberFormat::Currency("EUR").format_for_tag(9.5, "en"), "€9.50");
+//!
+//! let date = DateTime::new(2024, 3, 5, 14, 30, 0);
+//! assert_eq!(DateTimeFormat::Date(DateLength::Short).format_for_tag(date, "en-US"), "3/5/24");
+//! assert_eq!(DateTimeFormat::Date(DateLength::Long).format_for_tag(date, "fr"), "5 mars 2024");
+//! assert_eq!(DateTimeFormat::Time(DateLength::Short).format_for_tag(date, "de"), "14:30");
+//! ```
+//!
+//! The numbers and the dates are formatted by ICU4X with its compiled CLDR data, the numbers from exact decimals of `fixed_decimal`.
+//!
+//! The same formats are available in the locale files with `{{ count, number }}`, `{{ ratio, percent }}`, `{{ price, currency(USD) }}`,
+//! `{{ day, date(long) }}`, `{{ start, time(short) }}` and `{{ sent_at, datetime(medium) }}`.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use fixed_decimal::{FloatPrecision, Sign};
+use icu_datetime::{
+    fieldsets::{T, YMD},
+    input,
+    options::TimePrecision,
+    DateTimeFormatter, DateTimeFormatterPreferences, NoCalendarFormatter,
+};
+use icu_decimal::DecimalFormatter;
+use icu_experimental::dimension::{
+    currency::{formatter::CurrencyFormatter, options::CurrencyUsage, CurrencyType},
+    percent::formatter::PercentFormatter,
+};
+use icu_locale_core::Locale;
+use writeable::Writeable;
+
+use crate::{
+    currency::{Currency, CurrencyStyle},
+    LocaleVariant,
+};
+
+/// The exact decimal numbers of `fixed_decimal`, formatted by [`NumberFormat::format_decimal_for_tag`].
+pub use fixed_decimal::Decimal;
+
+/// A number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberFormat {
+    /// `number`, the number with the separators of the locale and at most 3 fractional digits.
+    Number,
+    /// `percent`, the number multiplied by 100 and rounded, with the percent sign of the locale.
+    Percent,
+    /// `currency(CODE)`, an amount in the currency of the given ISO 4217 code, with the digits of the currency.
+    Currency(&'static str),
+}
+
+// the argument of `name(argument)`.
+fn argument<'a>(formatter: &'a str, name: &str) -> Option<&'a str> {
+    let argument = formatter
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    Some(argument.trim())
+}
+
+// the ICU4X locale of a tag, the root locale if the tag is not a valid BCP 47 tag.
+fn icu_locale(tag: &str) -> Locale {
+    Locale::try_from_str(tag).unwrap_or(Locale::UNKNOWN)
+}
+
+// a value rounded to zero is displayed without its sign.
+fn without_zero_sign(value: &mut Decimal) {
+    if value.absolute.is_zero() {
+        value.sign = Sign::None;
+    }
+}
+
+// `value` rounded to `max_fraction_digits` fractional digits, without trailing zeros,
+// with the separators and the digits of the locale.
+fn format_number(mut value: Decimal, max_fraction_digits: u8, tag: &str) -> String {
+    value.round(-i16::from(max_fraction_digits));
+    value.absolute.trim_end();
+    without_zero_sign(&mut value);
+    let locale = icu_locale(tag);
+    // the compiled data always has the root locale to fall back to, the formatter can't fail.
+    DecimalFormatter::try_new((&locale).into(), Default::default())
+        .map(|formatter| formatter.format(&value).to_string())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+/// Format `value` with the separators of the given locale tag and at most `max_fraction_digits` fractional digits,
+/// as `NumberFormat::Number` with its 3 digits. Used by the measurements.
+pub(crate) fn format_number_for_tag(value: f64, max_fraction_digits: u8, tag: &str) -> String {
+    match Decimal::try_from_f64(value, FloatPrecision::RoundTrip) {
+        Ok(value) => format_number(value, max_fraction_digits, tag),
+        Err(_) => value.to_string(),
+    }
+}
+
+impl NumberFormat {
+    /// Parse a format as written in the locale files, e.g. `"percent"` or `"currency(USD)"`.
+    ///
+    /// The code of the currency must be a known ISO 4217 code.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "number" => Some(NumberFormat::Number),
+            "percent" => Some(NumberFormat::Percent),
+            name => {
+                let currency = Currency::from_code(argument(name, "currency")?)?;
+                Some(NumberFormat::Currency(currency.code))
+            }
+        }
+    }
+
+    /// Format `value` with the conventions of the given locale.
+    #[inline]
+    pub fn format<L: LocaleVariant>(self, value: f64, locale: L) -> String {
+        self.format_for_tag(value, locale.as_str())
+    }
+
+    /// Format `value` with the conventions of the given locale tag.
+    ///
+    /// `NaN` and the infinities are displayed as by `f64::to_string`.
+    #[inline]
+    pub fn format_for_tag(self, value: f64, tag: &str) -> String {
+        self.format_styled_for_tag(value, CurrencyStyle::Standard, tag)
+    }
+
+    /// Format the exact decimal `value` with the conventions of the given locale tag.
+    #[inline]
+    pub fn format_decimal_for_tag(self, value: Decimal, tag: &str) -> String {
+        self.format_decimal_styled_for_tag(value, CurrencyStyle::Standard, tag)
+    }
+
+    // `style` only applies to the currencies, `Money` formats its accounting style with it.
+    pub(crate) fn format_styled_for_tag(
+        self,
+        value: f64,
+        style: CurrencyStyle,
+        tag: &str,
+    ) -> String {
+        match Decimal::try_from_f64(value, FloatPrecision::RoundTrip) {
+            Ok(value) => self.format_decimal_styled_for_tag(value, style, tag),
+            Err(_) => value.to_string(),
+        }
+    }
+
+    fn format_decimal_styled_for_tag(
+        self,
+        mut value: Decimal,
+        style: CurrencyStyle,
+        tag: &str,
+    ) -> String {
+        let locale = icu_locale(tag);
+        // the compiled data always has the root locale to fall back to, the formatters can't fail.
+        match self {
+            NumberFormat::Number => format_number(value, 3, tag),
+            NumberFormat::Percent => {
+                value.absolute.multiply_pow10(2);
+                value.absolute.trim_start();
+                value.round(0);
+                without_zero_sign(&mut value);
+                PercentFormatter::try_new((&locale).into(), Default::default())
+                    .map(|formatter| formatter.format(&value).to_string())
+                    .unwrap_or_else(|_| value.to_string())
+            }
+            NumberFormat::Currency(code) => {
+                let digits =
+                    Currency::from_code(code).map_or(2, |currency| currency.fraction_digits);
+                let position = -i16::from(digits);
+                value.round(position);
+                value.absolute.pad_end(position);
+                without_zero_sign(&mut value);
+                let Ok(currency) = CurrencyType::try_from_str(&code.to_ascii_lowercase()) else {
+                    return format!("{}\u{A0}{}", value, code);
+                };
+                let usage = match style {
+                    CurrencyStyle::Standard => CurrencyUsage::Standard,
+                    CurrencyStyle::Accounting => CurrencyUsage::Accounting,
+                };
+                CurrencyFormatter::try_new_symbol((&locale).into(), currency, usage.into())
+                    .map(|formatter| {
+                        formatter
+                            .format_fixed_decimal(&value)
+                            .write_to_string()
+                            .into_owned()
+                    })
+                    .unwrap_or_else(|_| value.to_string())
+            }
+        }
+    }
+
+    /// Format the number written in `s`, as rendered by `f64::to_string` or an integer, with the conventions of the given locale.
+    #[inline]
+    pub fn apply<L: LocaleVariant>(self, s: &str, locale: L) -> String {
+        self.apply_for_tag(s, locale.as_str())
+    }
+
+    /// Format the number written in `s` with the conventions of the given locale tag, `s` is returned as is if it is not a number.
+    ///
+    /// The digits are read as written, without the rounding of a conversion to `f64`.
+    pub fn apply_for_tag(self, s: &str, tag: &str) -> String {
+        match Decimal::try_from_str(s.trim()) {
+            Ok(value) => self.format_decimal_for_tag(value, tag),
+            Err(_) => s.into(),
+        }
+    }
+}
+
+/// The length of a date or time format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateLength {
+    /// `short`, only digits: `3/5/24`, `10:05 AM`.
+    Short,
+    /// `medium`, the abbreviated month and the seconds: `Mar 5, 2024`, `10:05:30 AM`.
+    Medium,
+    /// `long`, the whole name of the month: `March 5, 2024`.
+    Long,
+}
+
+impl DateLength {
+    /// All the lengths.
+    pub const ALL: &'static [DateLength] =
+        &[DateLength::Short, DateLength::Medium, DateLength::Long];
+
+    /// Return the name of the length as written in the locale files, e.g. `"short"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            DateLength::Short => "short",
+            DateLength::Medium => "medium",
+            DateLength::Long => "long",
+        }
+    }
+
+    /// Parse the name of a length as written in the locale files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|length| length.as_str() == name.trim())
+    }
+}
+
+/// A date and time format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeFormat {
+    /// `date(length)`, the date only.
+    Date(DateLength),
+    /// `time(length)`, the time of day only.
+    Time(DateLength),
+    /// `datetime(length)`, the date and the time of day.
+    DateTime(DateLength),
+}
+
+/// A date and a time of day, in the time zone of the value it is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DateTime {
+    /// The year.
+    pub year: i32,
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The day of the month, from 1 to 31.
+    pub day: u8,
+    /// The hour, from 0 to 23.
+    pub hour: u8,
+    /// The minute, from 0 to 59.
+    pub minute: u8,
+    /// The second, from 0 to 60 for leap seconds.
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Create a date and time from its components.
+    pub const fn new(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Parse a date in the ISO 8601 format, as displayed by `chrono` or `time`: `2024-03-05`, `2024-03-05T10:05:30Z`
+    /// or `2024-03-05 10:05:30 UTC`.
+    ///
+    /// The time of day is midnight if missing, the fraction of seconds and the time zone are ignored.
+    pub fn parse(s: &str) -> Option<Self> {
+        match parse_date_time(s)? {
+            (Some((year, month, day)), time) => {
+                let (hour, minute, second) = time.unwrap_or_default();
+                Some(DateTime::new(year, month, day, hour, minute, second))
+            }
+            (None, _) => None,
+        }
+    }
+}
+
+// the ISO 8601 format read back by `DateTime::parse`, so the value can be given as is to the translations.
+impl core::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+type Date = (i32, u8, u8);
+type Time = (u8, u8, u8);
+
+fn parse_number<T: core::str::FromStr>(s: &str, len: usize) -> Option<(T, &str)> {
+    let digits = s.get(..len)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, &s[len..]))
+}
+
+fn parse_date(s: &str) -> Option<(Date, &str)> {
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let year_len = s.find('-')?;
+    let (year, s) = parse_number::<i32>(s, year_len.max(4))?;
+    let (month, s) = parse_number::<u8>(s.strip_prefix('-')?, 2)?;
+    let (day, s) = parse_number::<u8>(s.strip_prefix('-')?, 2)?;
+    let is_valid = (1..=12).contains(&month) && (1..=31).contains(&day);
+    is_valid.then_some(((sign * year, month, day), s))
+}
+
+fn parse_time(s: &str) -> Option<(Time, &str)> {
+    let (hour, s) = parse_number::<u8>(s, 2)?;
+    let (minute, s) = parse_number::<u8>(s.strip_prefix(':')?, 2)?;
+    let (second, s) = match s.strip_prefix(':') {
+        Some(s) => parse_number::<u8>(s, 2)?,
+        None => (0, s),
+    };
+    let is_valid = hour < 24 && minute < 60 && second <= 60;
+    is_valid.then_some(((hour, minute, second), s))
+}
+
+// a date, a time or both separated by `T` or a space, followed by anything such as the fraction of seconds or the zone.
+fn parse_date_time(s: &str) -> Option<(Option<Date>, Option<Time>)> {
+    let s = s.trim();
+    let Some((date, rest)) = parse_date(s) else {
+        return parse_time(s).map(|(time, _)| (None, Some(time)));
+    };
+    let time = rest
+        .strip_prefix(['T', ' '])
+        .and_then(parse_time)
+        .map(|(time, _)| time);
+    Some((Some(date), time))
+}
+
+fn date_fields(length: DateLength) -> YMD {
+    match length {
+        DateLength::Short => YMD::short(),
+        DateLength::Medium => YMD::medium(),
+        DateLength::Long => YMD::long(),
+    }
+}
+
+// the short lengths leave out the seconds.
+fn time_precision(length: DateLength) -> TimePrecision {
+    match length {
+        DateLength::Short => TimePrecision::Minute,
+        DateLength::Medium | DateLength::Long => TimePrecision::Second,
+    }
+}
+
+impl DateTimeFormat {
+    /// Parse a format as written in the locale files, e.g. `"date(short)"` or `"datetime(long)"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        // "datetime" first, "date" is a prefix of it.
+        if let Some(length) = argument(name, "datetime") {
+            return DateLength::from_name(length).map(DateTimeFormat::DateTime);
+        }
+        if let Some(length) = argument(name, "date") {
+            return DateLength::from_name(length).map(DateTimeFormat::Date);
+        }
+        DateLength::from_name(argument(name, "time")?).map(DateTimeFormat::Time)
+    }
+
+    /// Format `value` with the conventions of the given locale.
+    #[inline]
+    pub fn format<L: LocaleVariant>(self, value: DateTime, locale: L) -> String {
+        self.format_for_tag(value, locale.as_str())
+    }
+
+    /// Format `value` with the conventions of the given locale tag.
+    ///
+    /// An invalid date, such as February 30, is displayed in the ISO 8601 format.
+    pub fn format_for_tag(self, value: DateTime, tag: &str) -> String {
+        let date = (value.year, value.month, value.day);
+        let time = (value.hour, value.minute, value.second);
+        self.format_parts(Some(date), Some(time), tag)
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    // `None` if a part needed by the format is missing or is not a valid date or time.
+    fn format_parts(self, date: Option<Date>, time: Option<Time>, tag: &str) -> Option<String> {
+        let date = match date {
+            Some((year, month, day)) => Some(input::Date::try_new_iso(year, month, day).ok()?),
+            None => None,
+        };
+        let time = match time {
+            Some((hour, minute, second)) => {
+                Some(input::Time::try_new(hour, minute, second, 0).ok()?)
+            }
+            None => None,
+        };
+        let prefs = DateTimeFormatterPreferences::from(icu_locale(tag));
+        // the dates are converted to the calendar of the locale, e.g. the buddhist calendar in `th`.
+        let formatted = match self {
+            DateTimeFormat::Date(length) => DateTimeFormatter::try_new(prefs, date_fields(length))
+                .ok()?
+                .format(&date?)
+                .to_string(),
+            DateTimeFormat::Time(length) => NoCalendarFormatter::try_new(
+                prefs,
+                T::short().with_time_precision(time_precision(length)),
+            )
+            .ok()?
+            .format(&time?)
+            .to_string(),
+            DateTimeFormat::DateTime(length) => {
+                let fields = date_fields(length).with_time(time_precision(length));
+                let value = input::DateTime {
+                    date: date?,
+                    time: time?,
+                };
+                DateTimeFormatter::try_new(prefs, fields)
+                    .ok()?
+                    .format(&value)
+                    .to_string()
+            }
+        };
+        Some(formatted)
+    }
+
+    /// Format the date written in `s`, see [`DateTime::parse`], with the conventions of the given locale.
+    #[inline]
+    pub fn apply<L: LocaleVariant>(self, s: &str, locale: L) -> String {
+        self.apply_for_tag(s, locale.as_str())
+    }
+
+    /// Format the date written in `s` with the conventions of the given locale tag, `s` is returned as is if it is not a date,
+    /// or a time of day for `time(length)`.
+    pub fn apply_for_tag(self, s: &str, tag: &str) -> String {
+        parse_date_time(s)
+            .and_then(|(date, time)| self.format_parts(date, time, tag))
+            .unwrap_or_else(|| s.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            NumberFormat::Number.format_for_tag(1234.56789, "en"),
+            "1,234.568"
+        );
+        assert_eq!(NumberFormat::Number.format_for_tag(-3.0, "de"), "-3");
+        assert_eq!(NumberFormat::Percent.format_for_tag(0.5, "en"), "50%");
+        assert_eq!(
+            NumberFormat::Percent.format_for_tag(0.126, "fr"),
+            "13\u{A0}%"
+        );
+        assert_eq!(NumberFormat::Percent.format_for_tag(0.5, "tr"), "%50");
+        assert_eq!(
+            NumberFormat::Currency("USD").format_for_tag(1234.5, "en-US"),
+            "$1,234.50"
+        );
+        assert_eq!(
+            NumberFormat::Currency("XYZ").format_for_tag(2.0, "en"),
+            "XYZ\u{A0}2.00"
+        );
+        assert_eq!(
+            NumberFormat::Number.apply_for_tag("1000", "fr"),
+            "1\u{202F}000"
+        );
+        assert_eq!(NumberFormat::Number.apply_for_tag("n/a", "fr"), "n/a");
+        assert_eq!(NumberFormat::Number.format_for_tag(-0.0001, "en"), "0");
+        assert_eq!(NumberFormat::Number.format_for_tag(f64::NAN, "en"), "NaN");
+    }
+
+    #[test]
+    fn test_format_cldr() {
+        // the grouping, the digits and the symbols of the CLDR data of each locale.
+        assert_eq!(
+            NumberFormat::Number.format_for_tag(1234567.0, "en-IN"),
+            "12,34,567"
+        );
+        assert_eq!(
+            NumberFormat::Number.format_for_tag(1234.5, "ar-EG"),
+            "\u{661}\u{66C}\u{662}\u{663}\u{664}\u{66B}\u{665}"
+        );
+        assert_eq!(
+            NumberFormat::Currency("JPY").format_for_tag(1234.5, "ja"),
+            "￥1,234"
+        );
+        assert_eq!(
+            NumberFormat::Currency("EUR").format_for_tag(9.5, "pt-BR"),
+            "€\u{A0}9,50"
+        );
+        // the digits written are kept as is, without going through `f64`.
+        assert_eq!(
+            NumberFormat::Number.apply_for_tag("12345678901234567890.5", "en"),
+            "12,345,678,901,234,567,890.5"
+        );
+        assert_eq!(NumberFormat::Percent.apply_for_tag("0.125", "en"), "12%");
+    }
+
+    #[test]
+    fn test_parse_date_time() {
+        assert_eq!(
+            DateTime::parse("2024-03-05"),
+            Some(DateTime::new(2024, 3, 5, 0, 0, 0))
+        );
+        assert_eq!(
+            DateTime::parse("2024-03-05T10:05:30.123Z"),
+            Some(DateTime::new(2024, 3, 5, 10, 5, 30))
+        );
+        assert_eq!(
+            DateTime::parse(" 2024-03-05 10:05:30 UTC"),
+            Some(DateTime::new(2024, 3, 5, 10, 5, 30))
+        );
+        assert_eq!(DateTime::parse("2024-13-05"), None);
+        assert_eq!(DateTime::parse("10:05:30"), None);
+        assert_eq!(DateTime::parse("yesterday"), None);
+
+        let date = DateTime::new(2024, 3, 5, 10, 5, 30);
+        assert_eq!(DateTime::parse(&date.to_string()), Some(date));
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        let date = DateTime::new(2024, 3, 5, 9, 5, 30);
+        let format = |format: DateTimeFormat, tag| format.format_for_tag(date, tag);
+
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Short), "en-GB"),
+            "05/03/2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Short), "de"),
+            "05.03.24"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Medium), "en"),
+            "Mar 5, 2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Medium), "fr"),
+            "5 mars 2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "de"),
+            "5. März 2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "es"),
+            "5 de marzo de 2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "ja"),
+            "2024年3月5日"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "sv"),
+            "5 mars 2024"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "ru"),
+            "5 марта 2024\u{202F}г."
+        );
+        // converted to the buddhist calendar.
+        assert_eq!(
+            format(DateTimeFormat::Date(DateLength::Long), "th"),
+            "5 มีนาคม 2567"
+        );
+        assert_eq!(
+            format(DateTimeFormat::Time(DateLength::Medium), "en"),
+            "9:05:30\u{202F}AM"
+        );
+        assert_eq!(
+            format(DateTimeFormat::DateTime(DateLength::Long), "fr"),
+            "5 mars 2024 à 09:05:30"
+        );
+        assert_eq!(
+            format(DateTimeFormat::DateTime(DateLength::Short), "en"),
+            "3/5/24, 9:05\u{202F}AM"
+        );
+
+        let time = DateTimeFormat::Time(DateLength::Short);
+        assert_eq!(time.apply_for_tag("21:30:00", "en"), "9:30\u{202F}PM");
+        assert_eq!(time.apply_for_tag("2024-03-05", "en"), "2024-03-05");
+        assert_eq!(
+            DateTimeFormat::Date(DateLength::Short).apply_for_tag("soon", "en"),
+            "soon"
+        );
+        assert_eq!(
+            DateTimeFormat::Date(DateLength::Short).apply_for_tag("2023-02-30", "en"),
+            "2023-02-30"
+        );
+        assert_eq!(
+            DateTimeFormat::Date(DateLength::Short)
+                .format_for_tag(DateTime::new(2023, 2, 30, 0, 0, 0), "en"),
+            "2023-02-30T00:00:00"
+        );
+    }
+}
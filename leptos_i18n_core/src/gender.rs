@@ -0,0 +1,63 @@
+//! Grammatical gender, used to select the agreement branch of a translation.
+//!
+//! A value declared as a list of branches starting with `"gender"` is selected by the `gender` interpolation:
+//!
+//! ```json
+//! {
+//!     "arrived": ["gender", ["{{ name }} est arrivé", "masculine"], ["{{ name }} est arrivée", "feminine"], ["{{ name }} est arrivé·e"]]
+//! }
+//! ```
+//!
+//! ```rust, ignore
+//! t!(i18n, arrived, name = user.name.clone(), gender = move || user.gender)
+//! ```
+
+/// The grammatical genders a translation can agree with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Gender {
+    /// `masculine`.
+    Masculine,
+    /// `feminine`.
+    Feminine,
+    /// `neuter`.
+    Neuter,
+    /// `other`, for an unknown or unspecified gender.
+    #[default]
+    Other,
+}
+
+impl Gender {
+    /// All the genders.
+    pub const ALL: &'static [Gender] = &[
+        Gender::Masculine,
+        Gender::Feminine,
+        Gender::Neuter,
+        Gender::Other,
+    ];
+
+    /// Return the name of the gender as written in the locale files, e.g. `"feminine"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Gender::Masculine => "masculine",
+            Gender::Feminine => "feminine",
+            Gender::Neuter => "neuter",
+            Gender::Other => "other",
+        }
+    }
+
+    /// Parse the name of a gender as written in the locale files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|gender| gender.as_str() == name.trim())
+    }
+}
+
+impl core::fmt::Display for Gender {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
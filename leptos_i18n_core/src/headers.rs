@@ -0,0 +1,190 @@
+//! Conversions between the locales and the HTTP headers related to the language of a request or a response.
+//!
+//! They are framework agnostic, so any server or client integration can use the same header formatting:
+//!
+//! ```rust, ignore
+//! use leptos_i18n_core::headers::{self, Negotiation};
+//!
+//! let locale: LocaleEnum = headers::from_accept_language("fr-CH, fr;q=0.9, en;q=0.8");
+//!
+//! for (name, value) in headers::negotiated_response_headers(locale, Negotiation::CookieThenAcceptLanguage) {
+//!     response.insert_header(name, value);
+//! }
+//! ```
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::LocaleVariant;
+
+/// The name of the `Accept-Language` header.
+pub const ACCEPT_LANGUAGE: &str = "Accept-Language";
+
+/// The name of the `Content-Language` header.
+pub const CONTENT_LANGUAGE: &str = "Content-Language";
+
+/// The name of the `Vary` header.
+pub const VARY: &str = "Vary";
+
+/// The name of the `Cookie` header.
+pub const COOKIE: &str = "Cookie";
+
+/// How the locale of a response was found, which decides the request headers the response varies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Negotiation {
+    /// The locale does not depend on the request headers, for example when it is part of the URL.
+    Fixed,
+    /// The locale is found from the `Accept-Language` header.
+    AcceptLanguage,
+    /// The locale is found from a cookie first, then from the `Accept-Language` header.
+    CookieThenAcceptLanguage,
+}
+
+impl Negotiation {
+    /// Return the `Vary` header value for the negotiation, `None` when the response does not depend on the request headers.
+    pub const fn vary_value(self) -> Option<&'static str> {
+        match self {
+            Negotiation::Fixed => None,
+            Negotiation::AcceptLanguage => Some(ACCEPT_LANGUAGE),
+            Negotiation::CookieThenAcceptLanguage => Some("Accept-Language, Cookie"),
+        }
+    }
+}
+
+/// Return the languages of an `Accept-Language` header, sorted by their quality value.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut parsed_lang: Vec<_> = header
+        .split(';')
+        .map(|lang| {
+            let mut langs = lang.split(',').peekable();
+            let q = if let Some(a) = langs
+                .peek()
+                .and_then(|maybe_q| maybe_q.trim().strip_prefix("q="))
+            {
+                let q = a.parse::<f32>().unwrap_or(1.0);
+                langs.next();
+                q
+            } else {
+                1.0
+            };
+            (q, langs)
+        })
+        .collect();
+
+    parsed_lang.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+
+    parsed_lang
+        .into_iter()
+        .flat_map(|(_q, langs)| langs.map(str::trim).map(String::from))
+        .collect()
+}
+
+/// Return the locale that fit the best the given `Accept-Language` header, or the default locale.
+pub fn from_accept_language<L: LocaleVariant>(header: &str) -> L {
+    L::find_locale(&parse_accept_language(header))
+}
+
+/// Format an `Accept-Language` header value from locales sorted in preferred order,
+/// e.g. `"fr, en;q=0.9, de;q=0.8"`.
+///
+/// The quality value decrease by 0.1 for each locale, down to a minimum of 0.1.
+pub fn accept_language_value<L: LocaleVariant>(locales: &[L]) -> String {
+    let mut value = String::new();
+    for (i, locale) in locales.iter().enumerate() {
+        if i > 0 {
+            let q = 10usize.saturating_sub(i).max(1);
+            value.push_str(&format!(", {};q=0.{}", locale.as_str(), q));
+        } else {
+            value.push_str(locale.as_str());
+        }
+    }
+    value
+}
+
+/// Return the `Content-Language` header value for the given locale.
+#[inline]
+pub fn content_language_value<L: LocaleVariant>(locale: L) -> &'static str {
+    locale.as_str()
+}
+
+/// Return the locale described by a `Content-Language` header value, if it is supported.
+///
+/// When the header list multiple languages, the first supported one is returned.
+pub fn from_content_language<L: LocaleVariant>(header: &str) -> Option<L> {
+    header.split(',').map(str::trim).find_map(L::from_str)
+}
+
+/// Return the headers to add to a response rendered in the given locale.
+///
+/// Caches must know the response depends on the `Accept-Language` header of the request,
+/// so a `Vary: Accept-Language` header is returned alongside the `Content-Language` one.
+pub fn response_headers<L: LocaleVariant>(locale: L) -> [(&'static str, &'static str); 2] {
+    [
+        (CONTENT_LANGUAGE, content_language_value(locale)),
+        (VARY, ACCEPT_LANGUAGE),
+    ]
+}
+
+/// Return the headers to add to a response rendered in the given locale, found as described by `negotiation`.
+///
+/// The `Vary` header lists every request header read to find the locale, even when the request did not have it,
+/// so a shared cache such as a CDN keeps one variant per language and never serves it to a request asking for another one.
+pub fn negotiated_response_headers<L: LocaleVariant>(
+    locale: L,
+    negotiation: Negotiation,
+) -> Vec<(&'static str, &'static str)> {
+    let mut headers = vec![(CONTENT_LANGUAGE, content_language_value(locale))];
+    if let Some(vary) = negotiation.vary_value() {
+        headers.push((VARY, vary));
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let parsed_lang = parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5");
+
+        assert_eq!(parsed_lang, &["fr-CH", "fr", "en", "de", "*"]);
+    }
+
+    #[test]
+    fn test_parse_unsorted() {
+        let parsed_lang = parse_accept_language("q=0.3, fr-CH, fr;q=0.9, en;de;q=0.7, *;q=0.5");
+
+        assert_eq!(parsed_lang, &["de", "en", "*", "fr-CH", "fr"]);
+    }
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    struct Fr;
+
+    impl LocaleVariant for Fr {
+        fn from_str(s: &str) -> Option<Self> {
+            (s == "fr").then_some(Fr)
+        }
+        fn as_str(self) -> &'static str {
+            "fr"
+        }
+        fn get_all() -> &'static [Self] {
+            &[Fr]
+        }
+    }
+
+    #[test]
+    fn test_negotiated_response_headers() {
+        assert_eq!(
+            negotiated_response_headers(Fr, Negotiation::CookieThenAcceptLanguage),
+            [(CONTENT_LANGUAGE, "fr"), (VARY, "Accept-Language, Cookie")]
+        );
+        assert_eq!(
+            negotiated_response_headers(Fr, Negotiation::AcceptLanguage),
+            response_headers(Fr)
+        );
+        assert_eq!(
+            negotiated_response_headers(Fr, Negotiation::Fixed),
+            [(CONTENT_LANGUAGE, "fr")]
+        );
+    }
+}
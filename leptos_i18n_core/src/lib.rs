@@ -0,0 +1,49 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+//! # About Leptos i18n core
+//!
+//! This crate contains the parts of `leptos_i18n` that don't depend on leptos:
+//! the traits implemented by the types generated by `load_locales!`, the catalogs, the HTTP headers helpers, the plural rules and the formatting helpers.
+//!
+//! CLIs, backend services and tests can use it to consume the same compiled translations as the application,
+//! `leptos_i18n` re-export everything and add the reactive layer on top.
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`, so the lookup and formatting of catalogs
+//! compiled by `load_locales!` can be reused by embedded dashboards or WASI components.
+//!
+//! # Feature Flags
+//! - `std` (*Default*): Use the standard library, implement `std::error::Error` for the error types. Required by `os_locale`.
+//! - `os_locale`: Enable the `os_locale` module detecting the locale of the operating system.
+//! - `segmentation`: Enable the `segmentation` module with grapheme and word segmentation helpers.
+//! - `serde`: Implement `serde::Serialize` and `serde::Deserialize` for `validation::ValidationError` and `gender::Gender`.
+
+extern crate alloc;
+
+pub mod casing;
+pub mod catalog;
+pub mod currency;
+pub mod format;
+pub mod gender;
+pub mod headers;
+mod locale_data;
+mod locale_traits;
+pub mod measurement;
+pub mod number;
+#[cfg(feature = "os_locale")]
+pub mod os_locale;
+pub mod plurals;
+#[cfg(feature = "segmentation")]
+pub mod segmentation;
+pub mod slug;
+pub mod style;
+pub mod template;
+pub mod validation;
+
+pub use locale_traits::*;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use super::locale_data::{language, region};
+}
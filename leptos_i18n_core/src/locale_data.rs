@@ -0,0 +1,94 @@
+// Small subset of CLDR data used by the runtime helpers.
+// It only covers what is needed to read numbers and pick regional preferences,
+// locales that are not listed here fallback to the english conventions.
+// The numbers are formatted by ICU4X, see the `format` module.
+
+use alloc::string::String;
+
+fn subtags(tag: &str) -> impl Iterator<Item = &str> {
+    tag.split(['-', '_']).filter(|s| !s.is_empty())
+}
+
+/// Return the language subtag of a locale tag, lowercased.
+pub fn language(tag: &str) -> String {
+    subtags(tag)
+        .next()
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default()
+}
+
+/// Return the region subtag of a locale tag uppercased,
+/// or the most likely region of the language if the tag does'nt specify one.
+pub fn region(tag: &str) -> Option<String> {
+    let explicit = subtags(tag)
+        .skip(1)
+        .take_while(|s| s.len() > 1)
+        .find(|s| {
+            (s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic()))
+                || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_ascii_uppercase);
+
+    explicit.or_else(|| likely_region(&language(tag)).map(String::from))
+}
+
+fn likely_region(language: &str) -> Option<&'static str> {
+    let region = match language {
+        "en" => "US",
+        "fr" => "FR",
+        "de" => "DE",
+        "es" => "ES",
+        "it" => "IT",
+        "pt" => "BR",
+        "nl" => "NL",
+        "ja" => "JP",
+        "zh" => "CN",
+        "ko" => "KR",
+        "ru" => "RU",
+        "my" => "MM",
+        _ => return None,
+    };
+    Some(region)
+}
+
+/// Return the ellipsis marking a truncated text.
+#[cfg(feature = "segmentation")]
+pub(crate) fn ellipsis(tag: &str) -> &'static str {
+    match language(tag).as_str() {
+        // chinese uses a doubled ellipsis, centered on the line.
+        "zh" => "……",
+        _ => "…",
+    }
+}
+
+/// Return the value of the given key in the unicode extension (`-u-`) of a locale tag.
+///
+/// `unicode_extension("en-US-u-ms-metric", "ms")` returns `Some("metric")`.
+pub(crate) fn unicode_extension<'a>(tag: &'a str, key: &str) -> Option<&'a str> {
+    let mut subtags = subtags(tag)
+        .skip_while(|s| !s.eq_ignore_ascii_case("u"))
+        .skip(1)
+        .take_while(|s| s.len() > 1);
+    subtags.find(|s| s.eq_ignore_ascii_case(key))?;
+    subtags.next().filter(|s| s.len() > 2)
+}
+
+/// Return the separator used between the integer and the fractional part of a number.
+pub(crate) fn decimal_separator(tag: &str) -> char {
+    match group_separator(tag) {
+        ',' => '.',
+        _ => ',',
+    }
+}
+
+/// Return the separator used to group the digits of the integer part of a number.
+pub(crate) fn group_separator(tag: &str) -> char {
+    match language(tag).as_str() {
+        "de" | "es" | "it" | "nl" | "pt" | "tr" | "id" | "da" | "el" | "ro" | "hr" | "sl"
+        | "sr" => '.',
+        "fr" => '\u{202F}',
+        "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "hu" | "bg" | "lt"
+        | "lv" | "et" => '\u{A0}',
+        _ => ',',
+    }
+}
@@ -0,0 +1,181 @@
+// use serde::{de::DeserializeOwned, Serialize};
+
+use alloc::string::String;
+
+use crate::{locale_data, style::LocaleStyle};
+
+/// Trait implemented the enum representing the supported locales of the application
+///
+/// Appart from maybe `as_str` you will probably never need to use it has it only serves the internals of the library.
+pub trait LocaleVariant: 'static + Default + Clone + Copy {
+    /// Try to match the given str to a locale and returns it.
+    fn from_str(s: &str) -> Option<Self>;
+
+    /// Return a static str that represent the locale.
+    fn as_str(self) -> &'static str;
+
+    /// Return all the supported locales, the default one first.
+    fn get_all() -> &'static [Self];
+
+    /// Return all the supported locales in the order used by the negotiation when several of them match a request equally,
+    /// the `priority` setting of the configuration followed by the other locales in declaration order.
+    fn get_priority() -> &'static [Self] {
+        Self::get_all()
+    }
+
+    /// Return the styling hints of the locale, set in the `locale-styles` table of the configuration.
+    fn style(self) -> LocaleStyle {
+        LocaleStyle::DEFAULT
+    }
+
+    /// Return the share of the keys translated in this locale, from `0.0` to `1.0`,
+    /// the other keys displaying the value of the default locale.
+    fn completeness(self) -> f32 {
+        1.0
+    }
+
+    /// Return `true` if every key is translated in this locale.
+    fn is_complete(self) -> bool {
+        true
+    }
+
+    /// Given a slice of accepted languages sorted in preferred order, return the locale that fit the best the request.
+    ///
+    /// A language matches a locale exactly, ignoring the case, else the first locale in priority order with the same language,
+    /// so `en` or `en-AU` match `en-GB` over `en-US` if it comes first in the priority.
+    fn find_locale<T: AsRef<str>>(accepted_langs: &[T]) -> Self {
+        accepted_langs
+            .iter()
+            .find_map(|l| {
+                let l = l.as_ref();
+                // tags are case-insensitive, `en-us` is `en-US`.
+                let exact = Self::from_str(l).or_else(|| {
+                    Self::get_all()
+                        .iter()
+                        .copied()
+                        .find(|locale| locale.as_str().eq_ignore_ascii_case(l))
+                });
+                exact.or_else(|| {
+                    let language = locale_data::language(l);
+                    Self::get_priority()
+                        .iter()
+                        .copied()
+                        .find(|locale| locale_data::language(locale.as_str()) == language)
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Error returned when parsing a string that does not match any of the supported locales.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownLocaleError(pub String);
+
+impl core::fmt::Display for UnknownLocaleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown locale {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownLocaleError {}
+
+/// Trait implemented the struct representing the translation keys
+///
+/// You will probably never need to use it has it only serves the internals of the library.
+pub trait LocaleKeys: 'static + Clone + Copy {
+    /// The associated `Locales` types that serves as a bridge beetween the locale enum and the keys.
+    type Locales: Locales;
+
+    /// Create self according to the given locale.
+    fn from_variant(variant: <Self::Locales as Locales>::Variants) -> &'static Self;
+}
+
+/// This trait servers as a bridge beetween the locale enum and the keys struct
+pub trait Locales: 'static + Clone + Copy {
+    /// The enum that represent the different locales supported.
+    type Variants: LocaleVariant;
+    /// The struct that represent the translations keys.
+    type LocaleKeys: LocaleKeys<Locales = Self>;
+
+    /// The tenants declared in the configuration, each with its own catalog sharing the keys of the default one.
+    const TENANTS: &'static [&'static str] = &[];
+
+    /// Create the keys according to the given locale.
+    #[inline]
+    fn get_keys(locale: Self::Variants) -> &'static Self::LocaleKeys {
+        <Self::LocaleKeys as LocaleKeys>::from_variant(locale)
+    }
+
+    /// Create the keys according to the given locale in the catalog of the given tenant,
+    /// the shared keys are returned if the tenant is unknown or doesn't change this locale.
+    #[inline]
+    fn get_tenant_keys(locale: Self::Variants, tenant: &str) -> &'static Self::LocaleKeys {
+        let _ = tenant;
+        Self::get_keys(locale)
+    }
+}
+
+/// Bridge from the locale enum to its `Locales` type, so functions taking a locale can find the keys and the context.
+pub trait VariantLocales: LocaleVariant {
+    /// The `Locales` type of this locale enum.
+    type Locales: Locales<Variants = Self>;
+}
+
+/// This is used to call `.build` on `&str` when building interpolations
+///
+/// if it's a `&str` it will just return the str,
+/// but if it's a builder `.build` will either emit an error for a missing key or if all keys
+/// are supplied it will return the correct value
+#[doc(hidden)]
+pub trait BuildStr: Sized {
+    #[inline]
+    fn build(self) -> Self {
+        self
+    }
+}
+
+impl BuildStr for &str {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    enum Locale {
+        #[default]
+        EnUs,
+        EnGb,
+        Fr,
+    }
+
+    impl LocaleVariant for Locale {
+        fn from_str(s: &str) -> Option<Self> {
+            Self::get_all().iter().copied().find(|l| l.as_str() == s)
+        }
+        fn as_str(self) -> &'static str {
+            match self {
+                Locale::EnUs => "en-US",
+                Locale::EnGb => "en-GB",
+                Locale::Fr => "fr",
+            }
+        }
+        fn get_all() -> &'static [Self] {
+            &[Locale::EnUs, Locale::EnGb, Locale::Fr]
+        }
+        fn get_priority() -> &'static [Self] {
+            &[Locale::EnGb, Locale::EnUs, Locale::Fr]
+        }
+    }
+
+    #[test]
+    fn test_find_locale() {
+        assert_eq!(Locale::find_locale(&["en-US", "en-GB"]), Locale::EnUs);
+        assert_eq!(Locale::find_locale(&["en"]), Locale::EnGb);
+        assert_eq!(Locale::find_locale(&["en-AU"]), Locale::EnGb);
+        assert_eq!(Locale::find_locale(&["en-us"]), Locale::EnUs);
+        assert_eq!(Locale::find_locale(&["FR", "en-US"]), Locale::Fr);
+        assert_eq!(Locale::find_locale(&["fr-CH", "en"]), Locale::Fr);
+        assert_eq!(Locale::find_locale(&["de", "*"]), Locale::EnUs);
+    }
+}
@@ -0,0 +1,244 @@
+//! Conversion and formatting of measurements according to the measurement system preferred by a locale.
+//!
+//! Values are always supplied in a metric unit, they are then converted to the preferred system of the locale:
+//!
+//! ```rust
+//! use leptos_i18n_core::measurement::{Measure, MetricUnit};
+//!
+//! let distance = Measure::new(12.5, MetricUnit::Kilometer);
+//!
+//! assert_eq!(distance.format_for_tag("fr"), "12,5\u{A0}km");
+//! assert_eq!(distance.format_for_tag("en-US"), "7.77\u{A0}mi");
+//! ```
+
+use alloc::{format, string::String};
+
+use crate::{format, locale_data, LocaleVariant};
+
+/// The measurement systems a locale can prefer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MeasurementSystem {
+    /// The metric system.
+    #[default]
+    Metric,
+    /// The system used in the United States: miles, pounds, US gallons and degrees Fahrenheit.
+    UsCustomary,
+    /// The system used in the United Kingdom: miles, pounds and imperial gallons, but degrees Celsius.
+    Imperial,
+}
+
+impl MeasurementSystem {
+    /// Return the measurement system preferred by the given locale tag.
+    ///
+    /// A unicode `ms` extension in the tag (`metric`, `ussystem` or `uksystem`, e.g. `"en-US-u-ms-metric"`) overrides the regional preference.
+    pub fn from_tag(tag: &str) -> Self {
+        match locale_data::unicode_extension(tag, "ms") {
+            Some("metric") => return MeasurementSystem::Metric,
+            Some("ussystem") => return MeasurementSystem::UsCustomary,
+            Some("uksystem") => return MeasurementSystem::Imperial,
+            _ => {}
+        }
+        match locale_data::region(tag).as_deref() {
+            Some("US" | "LR" | "MM") => MeasurementSystem::UsCustomary,
+            Some("GB") => MeasurementSystem::Imperial,
+            _ => MeasurementSystem::Metric,
+        }
+    }
+
+    /// Return the measurement system preferred by the given locale.
+    #[inline]
+    pub fn for_locale<L: LocaleVariant>(locale: L) -> Self {
+        Self::from_tag(locale.as_str())
+    }
+}
+
+/// The metric units a canonical value can be expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricUnit {
+    /// Kilometers, converted to miles.
+    Kilometer,
+    /// Meters, converted to feet.
+    Meter,
+    /// Centimeters, converted to inches.
+    Centimeter,
+    /// Degrees Celsius, converted to degrees Fahrenheit.
+    Celsius,
+    /// Kilograms, converted to pounds.
+    Kilogram,
+    /// Grams, converted to ounces.
+    Gram,
+    /// Liters, converted to US or imperial gallons.
+    Liter,
+}
+
+/// All the units a measure can be displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// A metric unit.
+    Metric(MetricUnit),
+    /// Miles.
+    Mile,
+    /// Feet.
+    Foot,
+    /// Inches.
+    Inch,
+    /// Degrees Fahrenheit.
+    Fahrenheit,
+    /// Pounds.
+    Pound,
+    /// Ounces.
+    Ounce,
+    /// US gallons.
+    UsGallon,
+    /// Imperial gallons.
+    ImperialGallon,
+}
+
+impl Unit {
+    /// Return the symbol of the unit.
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Unit::Metric(MetricUnit::Kilometer) => "km",
+            Unit::Metric(MetricUnit::Meter) => "m",
+            Unit::Metric(MetricUnit::Centimeter) => "cm",
+            Unit::Metric(MetricUnit::Celsius) => "°C",
+            Unit::Metric(MetricUnit::Kilogram) => "kg",
+            Unit::Metric(MetricUnit::Gram) => "g",
+            Unit::Metric(MetricUnit::Liter) => "L",
+            Unit::Mile => "mi",
+            Unit::Foot => "ft",
+            Unit::Inch => "in",
+            Unit::Fahrenheit => "°F",
+            Unit::Pound => "lb",
+            Unit::Ounce => "oz",
+            Unit::UsGallon => "gal",
+            Unit::ImperialGallon => "gal",
+        }
+    }
+}
+
+/// A value expressed in a metric unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measure {
+    value: f64,
+    unit: MetricUnit,
+    max_fraction_digits: usize,
+    system: Option<MeasurementSystem>,
+}
+
+impl Measure {
+    /// Create a new measure from a canonical metric value.
+    pub const fn new(value: f64, unit: MetricUnit) -> Self {
+        Measure {
+            value,
+            unit,
+            max_fraction_digits: 2,
+            system: None,
+        }
+    }
+
+    /// Set the maximum number of fractional digits when formatting, default to 2.
+    pub const fn max_fraction_digits(self, max_fraction_digits: usize) -> Self {
+        Measure {
+            max_fraction_digits,
+            ..self
+        }
+    }
+
+    /// Force the measurement system to use, ignoring the preference of the locale.
+    pub const fn with_system(self, system: MeasurementSystem) -> Self {
+        Measure {
+            system: Some(system),
+            ..self
+        }
+    }
+
+    /// Convert the value to the given measurement system.
+    pub fn convert(self, system: MeasurementSystem) -> (f64, Unit) {
+        let Measure { value, unit, .. } = self;
+        match (system, unit) {
+            (MeasurementSystem::Metric, unit) => (value, Unit::Metric(unit)),
+            (MeasurementSystem::Imperial, MetricUnit::Celsius) => (value, Unit::Metric(unit)),
+            (_, MetricUnit::Kilometer) => (value / 1.609_344, Unit::Mile),
+            (_, MetricUnit::Meter) => (value / 0.3048, Unit::Foot),
+            (_, MetricUnit::Centimeter) => (value / 2.54, Unit::Inch),
+            (_, MetricUnit::Celsius) => (value * 9.0 / 5.0 + 32.0, Unit::Fahrenheit),
+            (_, MetricUnit::Kilogram) => (value / 0.453_592_37, Unit::Pound),
+            (_, MetricUnit::Gram) => (value / 28.349_523_125, Unit::Ounce),
+            (MeasurementSystem::UsCustomary, MetricUnit::Liter) => {
+                (value / 3.785_411_784, Unit::UsGallon)
+            }
+            (MeasurementSystem::Imperial, MetricUnit::Liter) => {
+                (value / 4.546_09, Unit::ImperialGallon)
+            }
+        }
+    }
+
+    /// Convert and format the measure for the given locale tag.
+    pub fn format_for_tag(self, tag: &str) -> String {
+        let system = self
+            .system
+            .unwrap_or_else(|| MeasurementSystem::from_tag(tag));
+        let (value, unit) = self.convert(system);
+        let max_fraction_digits = u8::try_from(self.max_fraction_digits).unwrap_or(u8::MAX);
+        let value = format::format_number_for_tag(value, max_fraction_digits, tag);
+        format!("{}\u{A0}{}", value, unit.symbol())
+    }
+
+    /// Convert and format the measure for the given locale.
+    #[inline]
+    pub fn format<L: LocaleVariant>(self, locale: L) -> String {
+        self.format_for_tag(locale.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_from_tag() {
+        assert_eq!(MeasurementSystem::from_tag("fr"), MeasurementSystem::Metric);
+        assert_eq!(
+            MeasurementSystem::from_tag("en"),
+            MeasurementSystem::UsCustomary
+        );
+        assert_eq!(
+            MeasurementSystem::from_tag("en-GB"),
+            MeasurementSystem::Imperial
+        );
+        assert_eq!(
+            MeasurementSystem::from_tag("en_AU"),
+            MeasurementSystem::Metric
+        );
+        assert_eq!(
+            MeasurementSystem::from_tag("en-US-u-ms-metric"),
+            MeasurementSystem::Metric
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let (value, unit) =
+            Measure::new(100.0, MetricUnit::Celsius).convert(MeasurementSystem::UsCustomary);
+        assert_eq!((value, unit), (212.0, Unit::Fahrenheit));
+
+        let (value, unit) =
+            Measure::new(100.0, MetricUnit::Celsius).convert(MeasurementSystem::Imperial);
+        assert_eq!((value, unit), (100.0, Unit::Metric(MetricUnit::Celsius)));
+    }
+
+    #[test]
+    fn test_format() {
+        let distance = Measure::new(1234.6, MetricUnit::Kilometer);
+        assert_eq!(distance.format_for_tag("de"), "1.234,6\u{A0}km");
+        assert_eq!(distance.format_for_tag("en"), "767.14\u{A0}mi");
+        assert_eq!(
+            distance
+                .with_system(MeasurementSystem::Metric)
+                .max_fraction_digits(0)
+                .format_for_tag("en"),
+            "1,235\u{A0}km"
+        );
+    }
+}
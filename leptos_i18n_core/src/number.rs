@@ -0,0 +1,116 @@
+//! Parsing of numbers entered by users, with the decimal and group separators of their locale.
+//!
+//! ```rust
+//! use leptos_i18n_core::number::parse_number_for_tag;
+//!
+//! assert_eq!(parse_number_for_tag("1,234.5", "en"), Ok(1234.5));
+//! assert_eq!(parse_number_for_tag("1 234,5", "fr"), Ok(1234.5));
+//! assert_eq!(parse_number_for_tag("1.234,5", "de"), Ok(1234.5));
+//! ```
+//!
+//! It is the counterpart of the formatting of the `currency` and `measurement` modules, for the inputs of forms.
+
+use alloc::string::String;
+
+use crate::{locale_data, LocaleVariant};
+
+/// Error returned when an input can't be parsed as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseNumberError {
+    /// The input is empty or only contains whitespaces.
+    Empty,
+    /// The input contains a character that is neither a digit nor a separator of the locale,
+    /// or a group separator after the decimal separator.
+    Invalid,
+}
+
+impl core::fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseNumberError::Empty => f.write_str("empty number"),
+            ParseNumberError::Invalid => f.write_str("invalid number"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNumberError {}
+
+// users type a regular space where the locale uses a non breaking one.
+fn is_group_separator(c: char, group_separator: char) -> bool {
+    c == group_separator || (group_separator.is_whitespace() && c.is_whitespace())
+}
+
+/// Parse a number written with the separators of the given locale tag.
+///
+/// Group separators are allowed anywhere in the integer part, a leading `+`, `-` or `−` (U+2212) sign is accepted.
+pub fn parse_number_for_tag(input: &str, tag: &str) -> Result<f64, ParseNumberError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseNumberError::Empty);
+    }
+
+    let decimal_separator = locale_data::decimal_separator(tag);
+    let group_separator = locale_data::group_separator(tag);
+
+    let mut normalized = String::with_capacity(input.len());
+    let mut in_fraction = false;
+    for (i, c) in input.chars().enumerate() {
+        match c {
+            '-' | '\u{2212}' if i == 0 => normalized.push('-'),
+            '+' if i == 0 => {}
+            '0'..='9' => normalized.push(c),
+            c if c == decimal_separator && !in_fraction => {
+                in_fraction = true;
+                normalized.push('.');
+            }
+            c if !in_fraction && is_group_separator(c, group_separator) => {}
+            _ => return Err(ParseNumberError::Invalid),
+        }
+    }
+
+    normalized.parse().map_err(|_| ParseNumberError::Invalid)
+}
+
+/// Parse a number written with the separators of the given locale.
+#[inline]
+pub fn parse_number<L: LocaleVariant>(input: &str, locale: L) -> Result<f64, ParseNumberError> {
+    parse_number_for_tag(input, locale.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number_for_tag(" 42 ", "en"), Ok(42.0));
+        assert_eq!(parse_number_for_tag("-1,000.25", "en-US"), Ok(-1000.25));
+        assert_eq!(parse_number_for_tag("\u{2212}3,5", "fr"), Ok(-3.5));
+        assert_eq!(parse_number_for_tag("1\u{202F}000", "fr"), Ok(1000.0));
+        assert_eq!(parse_number_for_tag("1\u{A0}000,5", "ru"), Ok(1000.5));
+        assert_eq!(parse_number_for_tag("+0,75", "es"), Ok(0.75));
+        assert_eq!(
+            parse_number_for_tag("  ", "en"),
+            Err(ParseNumberError::Empty)
+        );
+        assert_eq!(parse_number_for_tag("1.5", "de"), Ok(15.0));
+        assert_eq!(parse_number_for_tag("1,5", "en"), Ok(15.0));
+        assert_eq!(
+            parse_number_for_tag("1.000,5", "fr"),
+            Err(ParseNumberError::Invalid)
+        );
+        assert_eq!(
+            parse_number_for_tag("1.5.0", "en"),
+            Err(ParseNumberError::Invalid)
+        );
+        assert_eq!(
+            parse_number_for_tag("12a", "en"),
+            Err(ParseNumberError::Invalid)
+        );
+        assert_eq!(
+            parse_number_for_tag("-", "en"),
+            Err(ParseNumberError::Invalid)
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Detection of the locale of the operating system, for desktop deployments (e.g. with Tauri)
+//! where there is no `Accept-Language` header to negotiate the locale with.
+//!
+//! When the `os_locale` feature of `leptos_i18n` is enabled and neither `ssr` nor `hydrate` are, `provide_i18n_context`
+//! use the locale of the system as the initial locale.
+
+use crate::LocaleVariant;
+
+/// The environment variables describing the language of the system, in priority order.
+const LOCALE_ENV_VARS: [&str; 4] = ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Turn a POSIX locale such as `fr_FR.UTF-8@euro` into a language tag such as `fr-FR`.
+///
+/// Return `None` for the `C` and `POSIX` locales, as they don't describe any language.
+pub fn to_language_tag(posix_locale: &str) -> Option<String> {
+    let tag = posix_locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .replace('_', "-");
+    match tag.as_str() {
+        "" | "C" | "POSIX" => None,
+        _ => Some(tag),
+    }
+}
+
+/// Return the languages of the system sorted in preferred order.
+///
+/// Regional languages are followed by their base language, so `fr-FR` also match the `fr` locale.
+pub fn system_languages() -> Vec<String> {
+    let mut langs = Vec::new();
+    for var in LOCALE_ENV_VARS {
+        let Ok(value) = std::env::var(var) else {
+            continue;
+        };
+        // `LANGUAGE` is a list of languages separated by colons.
+        for tag in value.split(':').filter_map(to_language_tag) {
+            push_with_base(&mut langs, tag);
+        }
+    }
+    langs
+}
+
+fn push_with_base(langs: &mut Vec<String>, tag: String) {
+    let base = tag.split('-').next().map(str::to_owned);
+    if !langs.contains(&tag) {
+        langs.push(tag);
+    }
+    if let Some(base) = base {
+        if !langs.contains(&base) {
+            langs.push(base);
+        }
+    }
+}
+
+/// Return the locale that fit the best the languages of the system, or the default locale.
+pub fn detect_locale<L: LocaleVariant>() -> L {
+    L::find_locale(&system_languages())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_language_tag() {
+        assert_eq!(
+            to_language_tag("fr_FR.UTF-8@euro").as_deref(),
+            Some("fr-FR")
+        );
+        assert_eq!(to_language_tag("en_US").as_deref(), Some("en-US"));
+        assert_eq!(to_language_tag("de").as_deref(), Some("de"));
+        assert_eq!(to_language_tag("C.UTF-8"), None);
+        assert_eq!(to_language_tag("POSIX"), None);
+        assert_eq!(to_language_tag(""), None);
+    }
+
+    #[test]
+    fn test_push_with_base() {
+        let mut langs = Vec::new();
+        push_with_base(&mut langs, "fr-CH".to_string());
+        push_with_base(&mut langs, "fr-FR".to_string());
+        push_with_base(&mut langs, "en".to_string());
+
+        assert_eq!(langs, &["fr-CH", "fr", "fr-FR", "en"]);
+    }
+}
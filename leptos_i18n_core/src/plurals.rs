@@ -0,0 +1,182 @@
+//! Plural categories of the CLDR cardinal rules, to check which form a count selects in a given locale.
+//!
+//! ```rust
+//! use leptos_i18n_core::plurals::{plural_category_for_tag, PluralCategory};
+//!
+//! assert_eq!(plural_category_for_tag("ru", 22), PluralCategory::Few);
+//! assert_eq!(plural_category_for_tag("ru", 25), PluralCategory::Many);
+//! assert_eq!(plural_category_for_tag("fr", 0), PluralCategory::One);
+//! ```
+//!
+//! Only integer counts are supported, languages that are not listed follow the english rules.
+
+use crate::{locale_data, LocaleVariant};
+
+/// The plural categories defined by CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PluralCategory {
+    /// `zero`, e.g. 0 in arabic or latvian.
+    Zero,
+    /// `one`, e.g. 1 in english or 21 in russian.
+    One,
+    /// `two`, e.g. 2 in arabic or slovenian.
+    Two,
+    /// `few`, e.g. 2 to 4 in russian or polish.
+    Few,
+    /// `many`, e.g. 5 to 20 in russian.
+    Many,
+    /// `other`, the category every language has.
+    Other,
+}
+
+impl PluralCategory {
+    /// Return the CLDR name of the category, e.g. `"few"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl core::fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Return the plural category `count` selects in the given locale.
+#[inline]
+pub fn plural_category<L: LocaleVariant>(locale: L, count: i64) -> PluralCategory {
+    plural_category_for_tag(locale.as_str(), count)
+}
+
+/// Return the plural category `count` selects in the language of the given locale tag.
+pub fn plural_category_for_tag(tag: &str, count: i64) -> PluralCategory {
+    use PluralCategory::*;
+
+    let n = count.unsigned_abs();
+    let n10 = n % 10;
+    let n100 = n % 100;
+
+    match locale_data::language(tag).as_str() {
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "my" | "lo" | "km" => Other,
+        "fr" | "pt" | "hi" | "bn" | "fa" | "zu" | "am" => match n {
+            0 | 1 => One,
+            _ => Other,
+        },
+        "ru" | "uk" | "be" => match (n10, n100) {
+            (1, _) if n100 != 11 => One,
+            (2..=4, _) if !(12..=14).contains(&n100) => Few,
+            _ => Many,
+        },
+        "pl" => match (n, n10) {
+            (1, _) => One,
+            (_, 2..=4) if !(12..=14).contains(&n100) => Few,
+            _ => Many,
+        },
+        "cs" | "sk" => match n {
+            1 => One,
+            2..=4 => Few,
+            _ => Other,
+        },
+        "hr" | "sr" | "bs" => match n10 {
+            1 if n100 != 11 => One,
+            2..=4 if !(12..=14).contains(&n100) => Few,
+            _ => Other,
+        },
+        "lt" => match n10 {
+            _ if (11..=19).contains(&n100) => Other,
+            1 => One,
+            2..=9 => Few,
+            _ => Other,
+        },
+        "lv" => match n10 {
+            0 => Zero,
+            _ if (11..=19).contains(&n100) => Zero,
+            1 => One,
+            _ => Other,
+        },
+        "ro" => match n {
+            1 => One,
+            0 => Few,
+            _ if (2..=19).contains(&n100) => Few,
+            _ => Other,
+        },
+        "sl" => match n100 {
+            1 => One,
+            2 => Two,
+            3 | 4 => Few,
+            _ => Other,
+        },
+        "he" => match n {
+            1 => One,
+            2 => Two,
+            _ => Other,
+        },
+        "ar" => match n {
+            0 => Zero,
+            1 => One,
+            2 => Two,
+            _ if (3..=10).contains(&n100) => Few,
+            _ if (11..=99).contains(&n100) => Many,
+            _ => Other,
+        },
+        "ga" => match n {
+            1 => One,
+            2 => Two,
+            3..=6 => Few,
+            7..=10 => Many,
+            _ => Other,
+        },
+        "cy" => match n {
+            0 => Zero,
+            1 => One,
+            2 => Two,
+            3 => Few,
+            6 => Many,
+            _ => Other,
+        },
+        _ => match n {
+            1 => One,
+            _ => Other,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PluralCategory::*;
+
+    fn categories(tag: &str, counts: &[i64]) -> Vec<PluralCategory> {
+        counts
+            .iter()
+            .map(|count| plural_category_for_tag(tag, *count))
+            .collect()
+    }
+
+    #[test]
+    fn test_plural_category() {
+        assert_eq!(categories("en-US", &[0, 1, 2]), [Other, One, Other]);
+        assert_eq!(categories("fr", &[0, 1, 2]), [One, One, Other]);
+        assert_eq!(
+            categories("ru", &[1, 2, 5, 11, 12, 21, 22, 25, 111]),
+            [One, Few, Many, Many, Many, One, Few, Many, Many]
+        );
+        assert_eq!(
+            categories("pl", &[1, 2, 5, 21, 22]),
+            [One, Few, Many, Many, Few]
+        );
+        assert_eq!(
+            categories("ar", &[0, 1, 2, 3, 11, 100, 103]),
+            [Zero, One, Two, Few, Many, Other, Few]
+        );
+        assert_eq!(categories("ja", &[0, 1, 2]), [Other, Other, Other]);
+        assert_eq!(categories("en", &[-1]), [One]);
+    }
+}
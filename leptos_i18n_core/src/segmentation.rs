@@ -0,0 +1,199 @@
+//! Text segmentation helpers following the Unicode rules (UAX #29), with the ICU4X segmenter.
+//!
+//! Counting or slicing translated text by `char` or by byte breaks on emojis, combining accents and many scripts,
+//! these helpers work on grapheme clusters (what a user perceive as a character) and words instead.
+//!
+//! ```rust
+//! use leptos_i18n_core::segmentation::*;
+//!
+//! assert_eq!(grapheme_count("👩‍👩‍👧 café"), 6);
+//! assert_eq!(take_graphemes("👩‍👩‍👧 café", 3), "👩‍👩‍👧 c");
+//! assert_eq!(words_for_tag("en", "Hello, world!").collect::<Vec<_>>(), ["Hello", "world"]);
+//! assert_eq!(words_for_tag("th", "ภาษาไทย").collect::<Vec<_>>(), ["ภาษา", "ไทย"]);
+//! assert_eq!(truncate_for_tag("fr", "Les misérables", 8), "Les mis…");
+//! assert_eq!(truncate_for_tag("zh", "我们的产品目录", 5), "我们的……");
+//! ```
+//!
+//! Words and sentences are found with the rules of the language of the locale, the scripts written without spaces
+//! (Chinese, Japanese, Thai, Lao, Khmer, Burmese) are split with dictionaries.
+
+use alloc::{borrow::Cow, format, vec::Vec};
+
+use icu_locale_core::LanguageIdentifier;
+use icu_segmenter::{
+    options::{SentenceBreakOptions, WordBreakOptions},
+    GraphemeClusterSegmenter, SentenceSegmenter, WordSegmenter,
+};
+
+use crate::{locale_data, LocaleVariant};
+
+// the segments between the successive breakpoints, the first breakpoint being the start of the text.
+fn segments(text: &str, breakpoints: impl Iterator<Item = usize>) -> impl Iterator<Item = &str> {
+    let mut start = 0;
+    breakpoints.filter(|&end| end > 0).map(move |end| {
+        let segment = &text[start..end];
+        start = end;
+        segment
+    })
+}
+
+// the language of a tag given to the segmenters, none if the tag is not a valid BCP 47 tag.
+fn content_language(tag: &str) -> Option<LanguageIdentifier> {
+    LanguageIdentifier::try_from_str(tag).ok()
+}
+
+/// Return an iterator over the grapheme clusters of the text.
+#[inline]
+pub fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    segments(text, GraphemeClusterSegmenter::new().segment_str(text))
+}
+
+/// Return the number of grapheme clusters in the text.
+#[inline]
+pub fn grapheme_count(text: &str) -> usize {
+    graphemes(text).count()
+}
+
+/// Return the longest prefix of the text containing at most `max` grapheme clusters.
+pub fn take_graphemes(text: &str, max: usize) -> &str {
+    match GraphemeClusterSegmenter::new().segment_str(text).nth(max) {
+        Some(end) => &text[..end],
+        None => text,
+    }
+}
+
+/// Return the words of the text in the language of the given locale, ignoring punctuation and whitespaces.
+#[inline]
+pub fn words<L: LocaleVariant>(locale: L, text: &str) -> impl Iterator<Item = &str> {
+    words_for_tag(locale.as_str(), text)
+}
+
+/// Return the words of the text in the language of the given locale tag, ignoring punctuation and whitespaces.
+pub fn words_for_tag<'a>(tag: &str, text: &'a str) -> impl Iterator<Item = &'a str> {
+    let language = content_language(tag);
+    let mut options = WordBreakOptions::default();
+    options.content_locale = language.as_ref();
+    let segmenter = WordSegmenter::try_new_dictionary(options)
+        .unwrap_or_else(|_| WordSegmenter::new_dictionary(Default::default()).static_to_owned());
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (end, word_type) in segmenter
+        .as_borrowed()
+        .segment_str(text)
+        .iter_with_word_type()
+    {
+        if end > 0 && word_type.is_word_like() {
+            words.push(&text[start..end]);
+        }
+        start = end;
+    }
+    words.into_iter()
+}
+
+/// Return the number of words in the text in the language of the given locale.
+#[inline]
+pub fn word_count<L: LocaleVariant>(locale: L, text: &str) -> usize {
+    words(locale, text).count()
+}
+
+/// Return the number of words in the text in the language of the given locale tag.
+#[inline]
+pub fn word_count_for_tag(tag: &str, text: &str) -> usize {
+    words_for_tag(tag, text).count()
+}
+
+/// Return the sentences of the text in the language of the given locale.
+#[inline]
+pub fn sentences<L: LocaleVariant>(locale: L, text: &str) -> impl Iterator<Item = &str> {
+    sentences_for_tag(locale.as_str(), text)
+}
+
+/// Return the sentences of the text in the language of the given locale tag.
+pub fn sentences_for_tag<'a>(tag: &str, text: &'a str) -> impl Iterator<Item = &'a str> {
+    let language = content_language(tag);
+    let mut options = SentenceBreakOptions::default();
+    options.content_locale = language.as_ref();
+    let segmenter = SentenceSegmenter::try_new(options)
+        .unwrap_or_else(|_| SentenceSegmenter::new(Default::default()).static_to_owned());
+
+    let breakpoints = segmenter
+        .as_borrowed()
+        .segment_str(text)
+        .collect::<Vec<_>>();
+    segments(text, breakpoints.into_iter())
+}
+
+/// Return the ellipsis marking a truncated text in the language of the given locale tag, `"…"` in most languages.
+#[inline]
+pub fn ellipsis_for_tag(tag: &str) -> &'static str {
+    locale_data::ellipsis(tag)
+}
+
+/// Truncate the text to at most `max_graphemes` grapheme clusters, the ellipsis of the locale included.
+///
+/// The text is returned as is if it fits, else it is cut on a grapheme boundary and the ellipsis is appended
+/// after removing the trailing whitespaces.
+#[inline]
+pub fn truncate<L: LocaleVariant>(locale: L, text: &str, max_graphemes: usize) -> Cow<'_, str> {
+    truncate_for_tag(locale.as_str(), text, max_graphemes)
+}
+
+/// Truncate the text to at most `max_graphemes` grapheme clusters, the ellipsis of the language of the given locale tag included.
+pub fn truncate_for_tag<'a>(tag: &str, text: &'a str, max_graphemes: usize) -> Cow<'a, str> {
+    if GraphemeClusterSegmenter::new()
+        .segment_str(text)
+        .nth(max_graphemes + 1)
+        .is_none()
+    {
+        return Cow::Borrowed(text);
+    }
+    let ellipsis = ellipsis_for_tag(tag);
+    let kept = max_graphemes.saturating_sub(grapheme_count(ellipsis));
+    if kept == 0 {
+        return Cow::Borrowed(take_graphemes(ellipsis, max_graphemes));
+    }
+    let kept = take_graphemes(text, kept).trim_end();
+    Cow::Owned(format!("{}{}", kept, ellipsis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate_for_tag("fr", "Bonjour", 7), "Bonjour");
+        assert_eq!(
+            truncate_for_tag("fr", "Bonjour tout le monde", 9),
+            "Bonjour…"
+        );
+        assert_eq!(truncate_for_tag("en", "👩‍👩‍👧👩‍👩‍👧👩‍👩‍👧", 2), "👩‍👩‍👧…");
+        assert_eq!(truncate_for_tag("zh-CN", "产品目录", 3), "产……");
+        assert_eq!(truncate_for_tag("zh", "产品目录", 1), "…");
+        assert_eq!(truncate_for_tag("fr", "Bonjour", 0), "");
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(
+            words_for_tag("en", "It's 3.5 km, isn't it?").collect::<Vec<_>>(),
+            ["It's", "3.5", "km", "isn't", "it"]
+        );
+        assert_eq!(
+            words_for_tag("th", "ภาษาไทยง่ายนิดเดียว").collect::<Vec<_>>(),
+            ["ภาษา", "ไทย", "ง่าย", "นิด", "เดียว"]
+        );
+        assert_eq!(word_count_for_tag("ja", "東京都に住んでいます"), 7);
+        assert_eq!(word_count_for_tag("not a tag", "Hello world"), 2);
+        assert_eq!(words_for_tag("en", "").count(), 0);
+    }
+
+    #[test]
+    fn test_sentences() {
+        assert_eq!(
+            sentences_for_tag("en", "Hello world. How are you? Fine.").collect::<Vec<_>>(),
+            ["Hello world. ", "How are you? ", "Fine."]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Locale-aware URL slugs, to build localized permalinks from translated titles.
+//!
+//! ```rust
+//! use leptos_i18n_core::slug::slugify_for_tag;
+//!
+//! assert_eq!(slugify_for_tag("Crème brûlée à l'été !", "fr"), "creme-brulee-a-l-ete");
+//! assert_eq!(slugify_for_tag("Größe & Maße", "de"), "groesse-masse");
+//! assert_eq!(slugify_for_tag("IŞIK ve İNCİ", "tr"), "isik-ve-inci");
+//! assert_eq!(slugify_for_tag("東京タワー 2024", "ja"), "東京タワー-2024");
+//! ```
+//!
+//! The text is lowercased with the rules of the locale, the latin letters lose their diacritics and every run of
+//! spaces or punctuation becomes a single `-`. Letters of other scripts, such as CJK or cyrillic, are kept as is:
+//! they can't be transliterated without a dictionary, and browsers display them in the address bar.
+
+use alloc::string::String;
+
+use crate::{casing::Casing, locale_data, LocaleVariant};
+
+/// Turn `s` into a URL slug with the rules of the given locale.
+#[inline]
+pub fn slugify<L: LocaleVariant>(s: &str, locale: L) -> String {
+    slugify_for_tag(s, locale.as_str())
+}
+
+/// Turn `s` into a URL slug with the rules of the language of the given locale tag.
+pub fn slugify_for_tag(s: &str, tag: &str) -> String {
+    let language = locale_data::language(tag);
+    let lowered = Casing::Lower.apply_for_tag(s, tag);
+
+    let mut slug = String::with_capacity(lowered.len());
+    let mut separator = false;
+    for c in lowered.chars() {
+        let folded = fold(c, &language);
+        if folded.is_none() && !c.is_alphanumeric() {
+            separator = true;
+            continue;
+        }
+        if separator && !slug.is_empty() {
+            slug.push('-');
+        }
+        separator = false;
+        match folded {
+            Some(ascii) => slug.push_str(ascii),
+            None => slug.push(c),
+        }
+    }
+    slug
+}
+
+// the ascii replacement of a lowercase latin letter, `None` for the characters kept or dropped as separators.
+fn fold(c: char, language: &str) -> Option<&'static str> {
+    let ascii = match c {
+        'a'..='z' | '0'..='9' => return None,
+        // german spells the umlauts out.
+        'ä' if language == "de" => "ae",
+        'ö' if language == "de" => "oe",
+        'ü' if language == "de" => "ue",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ð' | 'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĳ' => "ij",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'ŉ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify_for_tag("  Hello, World!  ", "en"), "hello-world");
+        assert_eq!(slugify_for_tag("Über uns", "de-AT"), "ueber-uns");
+        assert_eq!(slugify_for_tag("Über uns", "fr"), "uber-uns");
+        assert_eq!(slugify_for_tag("Żółć — Łódź", "pl"), "zolc-lodz");
+        assert_eq!(slugify_for_tag("DIŞ HEKİMİ", "tr"), "dis-hekimi");
+        assert_eq!(slugify_for_tag("Ι ΜΕΓΑΛΗ", "el"), "ι-μεγαλη");
+        assert_eq!(slugify_for_tag("北京、上海", "zh"), "北京-上海");
+        assert_eq!(slugify_for_tag("?!", "en"), "");
+    }
+}
@@ -0,0 +1,61 @@
+//! Styling hints of the locales, for locale-conditional styling without a mapping maintained by the application.
+//!
+//! They are set per locale in the `locale-styles` table of the configuration:
+//!
+//! ```toml
+//! [package.metadata.leptos-i18n.locale-styles.ja]
+//! class = "cjk"
+//! font-family = "'Hiragino Sans', 'Noto Sans JP', sans-serif"
+//! line-height = 1.8
+//! ```
+//!
+//! and returned by `LocaleVariant::style`:
+//!
+//! ```rust
+//! use leptos_i18n_core::style::LocaleStyle;
+//!
+//! let style = LocaleStyle {
+//!     class: Some("cjk"),
+//!     font_family: Some("'Noto Sans JP', sans-serif"),
+//!     line_height: Some(1.8),
+//! };
+//! assert_eq!(style.to_css(), "font-family: 'Noto Sans JP', sans-serif; line-height: 1.8;");
+//! assert_eq!(LocaleStyle::DEFAULT.to_css(), "");
+//! ```
+
+use alloc::{format, string::String};
+
+/// The styling hints of a locale, every hint is optional.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LocaleStyle {
+    /// A CSS class to add to the root element, e.g. to group the CJK locales.
+    pub class: Option<&'static str>,
+    /// The preferred font stack, as the value of the CSS `font-family` property.
+    pub font_family: Option<&'static str>,
+    /// The preferred line height, as a multiplier of the font size.
+    pub line_height: Option<f32>,
+}
+
+impl LocaleStyle {
+    /// The style of the locales without hints.
+    pub const DEFAULT: Self = LocaleStyle {
+        class: None,
+        font_family: None,
+        line_height: None,
+    };
+
+    /// Return the hints as CSS declarations for a `style` attribute, empty if there are none.
+    pub fn to_css(&self) -> String {
+        let mut css = String::new();
+        if let Some(font_family) = self.font_family {
+            css.push_str(&format!("font-family: {};", font_family));
+        }
+        if let Some(line_height) = self.line_height {
+            if !css.is_empty() {
+                css.push(' ');
+            }
+            css.push_str(&format!("line-height: {};", line_height));
+        }
+        css
+    }
+}
@@ -0,0 +1,235 @@
+//! Interpolation of templates known only at runtime, such as announcements written by the administrators of an application.
+//!
+//! ```rust
+//! use leptos_i18n_core::template::Template;
+//!
+//! let template = Template::parse("{{ name, upper }} ships on {{ day, date(long) }} for {{ price, currency(EUR) }}").unwrap();
+//!
+//! assert_eq!(
+//!     template.render_for_tag("fr", &[("name", &"chalet"), ("day", &"2024-03-05"), ("price", &9.5)]),
+//!     "CHALET ships on 5 mars 2024 for 9,50\u{A0}€"
+//! );
+//! ```
+//!
+//! The syntax is the one of the variables of the locale files, with the same formatters, but without components nor plurals:
+//! the output is plain text, `<b>` is kept as is and is escaped like any other string when rendered by leptos.
+//! A variable missing from the values is kept as written, so a typo in a template stays visible.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+use crate::{
+    casing::Casing,
+    format::{DateTimeFormat, NumberFormat},
+    LocaleVariant,
+};
+
+/// Error returned when a template can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// A `{{` is not closed by `}}`.
+    UnclosedVariable,
+    /// The name of a variable is not made of letters, digits, `_` and `-`, or starts with a digit.
+    InvalidVariableName(String),
+    /// The formatter of a variable is unknown.
+    InvalidFormatter(String),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TemplateError::UnclosedVariable => f.write_str("unclosed variable"),
+            TemplateError::InvalidVariableName(name) => {
+                write!(f, "invalid variable name {:?}", name)
+            }
+            TemplateError::InvalidFormatter(formatter) => {
+                write!(f, "invalid formatter {:?}", formatter)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemplateError {}
+
+/// How the value of a variable is formatted, written after its name: `{{ name, upper }}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Formatter {
+    /// `upper`, `lower` or `title`.
+    Casing(Casing),
+    /// `number`, `percent` or `currency(CODE)`.
+    Number(NumberFormat),
+    /// `date(LENGTH)`, `time(LENGTH)` or `datetime(LENGTH)`.
+    DateTime(DateTimeFormat),
+}
+
+impl Formatter {
+    /// Parse a formatter as written in the locale files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Casing::from_name(name)
+            .map(Formatter::Casing)
+            .or_else(|| NumberFormat::from_name(name).map(Formatter::Number))
+            .or_else(|| DateTimeFormat::from_name(name).map(Formatter::DateTime))
+    }
+
+    /// Format the text of a value with the conventions of the given locale tag.
+    pub fn apply_for_tag(self, s: &str, tag: &str) -> String {
+        match self {
+            Formatter::Casing(casing) => casing.apply_for_tag(s, tag),
+            Formatter::Number(format) => format.apply_for_tag(s, tag),
+            Formatter::DateTime(format) => format.apply_for_tag(s, tag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Part {
+    Text(String),
+    Variable {
+        name: String,
+        formatter: Option<Formatter>,
+        // the variable as written, displayed when no value is given.
+        source: String,
+    },
+}
+
+/// A parsed template.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+impl Template {
+    /// Parse a template.
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some((before, after_open)) = rest.split_once("{{") {
+            let (variable, after_close) = after_open
+                .split_once("}}")
+                .ok_or(TemplateError::UnclosedVariable)?;
+            if !before.is_empty() {
+                parts.push(Part::Text(before.to_string()));
+            }
+            let (name, formatter) = match variable.split_once(',') {
+                Some((name, formatter)) => {
+                    let formatter = Formatter::from_name(formatter).ok_or_else(|| {
+                        TemplateError::InvalidFormatter(formatter.trim().to_string())
+                    })?;
+                    (name.trim(), Some(formatter))
+                }
+                None => (variable.trim(), None),
+            };
+            if !is_valid_name(name) {
+                return Err(TemplateError::InvalidVariableName(name.to_string()));
+            }
+            let start = template.len() - rest.len() + before.len();
+            let end = template.len() - after_close.len();
+            parts.push(Part::Variable {
+                name: name.to_string(),
+                formatter,
+                source: template[start..end].to_string(),
+            });
+            rest = after_close;
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        Ok(Template { parts })
+    }
+
+    /// Return the names of the variables of the template, in order of appearance.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Variable { name, .. } => Some(name.as_str()),
+            Part::Text(_) => None,
+        })
+    }
+
+    /// Render the template with the given values of the variables and the conventions of the given locale.
+    #[inline]
+    pub fn render<L: LocaleVariant>(&self, locale: L, values: &[(&str, &dyn Display)]) -> String {
+        self.render_for_tag(locale.as_str(), values)
+    }
+
+    /// Render the template with the given values of the variables and the conventions of the given locale tag.
+    pub fn render_for_tag(&self, tag: &str, values: &[(&str, &dyn Display)]) -> String {
+        let mut output = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Variable {
+                    name,
+                    formatter,
+                    source,
+                } => {
+                    let value = values
+                        .iter()
+                        .find(|(value_name, _)| value_name == name)
+                        .map(|(_, value)| value.to_string());
+                    match (value, formatter) {
+                        (Some(value), Some(formatter)) => {
+                            output.push_str(&formatter.apply_for_tag(&value, tag))
+                        }
+                        (Some(value), None) => output.push_str(&value),
+                        (None, _) => output.push_str(source),
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
+impl core::str::FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let template = Template::parse("Hi {{ name }}, {{count,number}} new").unwrap();
+        assert_eq!(template.variables().collect::<Vec<_>>(), ["name", "count"]);
+        assert_eq!(
+            Template::parse("Hi {{ name"),
+            Err(TemplateError::UnclosedVariable)
+        );
+        assert_eq!(
+            Template::parse("{{ first name }}"),
+            Err(TemplateError::InvalidVariableName("first name".to_string()))
+        );
+        assert_eq!(
+            Template::parse("{{ price, currency(XYZ) }}"),
+            Err(TemplateError::InvalidFormatter("currency(XYZ)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let template =
+            Template::parse("<b>{{ name, upper }}</b>: {{ count, number }} {{ missing }}").unwrap();
+        assert_eq!(
+            template.render_for_tag("tr", &[("name", &"istanbul"), ("count", &1234)]),
+            "<b>İSTANBUL</b>: 1.234 {{ missing }}"
+        );
+        assert_eq!(Template::parse("").unwrap().render_for_tag("en", &[]), "");
+    }
+}
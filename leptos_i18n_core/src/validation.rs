@@ -0,0 +1,184 @@
+//! Validation of form values, with errors that can be translated.
+//!
+//! The rules are the same on the client and on the server, so a `#[server]` function can return the same errors a component displays:
+//!
+//! ```rust
+//! use leptos_i18n_core::validation::{Rules, ValidationError};
+//!
+//! let username = Rules::new().required().min_length(3).max_length(20);
+//!
+//! assert_eq!(username.validate(""), Err(ValidationError::Required));
+//! assert_eq!(username.validate("ab"), Err(ValidationError::MinLength { min: 3 }));
+//! assert_eq!(username.validate("alice"), Ok(()));
+//! ```
+//!
+//! The translation keys of each error are configured in the `validation` table of the `leptos-i18n` metadata,
+//! `load_locales!` then generates a `LocaleEnum::validation_message` method returning the translated message.
+
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display};
+
+/// The reason a value is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The value is required but empty.
+    Required,
+    /// The value has less than `min` characters.
+    MinLength {
+        /// The minimum number of characters.
+        min: usize,
+    },
+    /// The value has more than `max` characters.
+    MaxLength {
+        /// The maximum number of characters.
+        max: usize,
+    },
+    /// The value does not match the pattern with the given name, e.g. `"email"`.
+    Pattern {
+        /// The name of the pattern.
+        pattern: String,
+    },
+}
+
+/// English messages, used when no translation key is configured for an error.
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Required => write!(f, "This field is required"),
+            ValidationError::MinLength { min } => {
+                write!(f, "This field must have at least {} characters", min)
+            }
+            ValidationError::MaxLength { max } => {
+                write!(f, "This field must have at most {} characters", max)
+            }
+            ValidationError::Pattern { pattern } => {
+                write!(f, "This field must be a valid {}", pattern)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// A named predicate a value must match.
+type Pattern = (&'static str, fn(&str) -> bool);
+
+/// The rules a value must follow, checked in order: required, minimum length, maximum length, and pattern.
+///
+/// Lengths are counted in characters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rules {
+    required: bool,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Pattern>,
+}
+
+impl Rules {
+    /// Create rules accepting any value.
+    pub const fn new() -> Self {
+        Rules {
+            required: false,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+        }
+    }
+
+    /// Reject empty values, other rules are not checked for empty values that are not required.
+    pub const fn required(self) -> Self {
+        Rules {
+            required: true,
+            ..self
+        }
+    }
+
+    /// Reject values with less than `min` characters.
+    pub const fn min_length(self, min: usize) -> Self {
+        Rules {
+            min_length: Some(min),
+            ..self
+        }
+    }
+
+    /// Reject values with more than `max` characters.
+    pub const fn max_length(self, max: usize) -> Self {
+        Rules {
+            max_length: Some(max),
+            ..self
+        }
+    }
+
+    /// Reject values for which `matches` returns `false`, `name` is given to the error to describe the expected format.
+    pub const fn pattern(self, name: &'static str, matches: fn(&str) -> bool) -> Self {
+        Rules {
+            pattern: Some((name, matches)),
+            ..self
+        }
+    }
+
+    /// Check the value against the rules, returning the first one it breaks.
+    pub fn validate(&self, value: &str) -> Result<(), ValidationError> {
+        if value.is_empty() {
+            return if self.required {
+                Err(ValidationError::Required)
+            } else {
+                Ok(())
+            };
+        }
+        let len = value.chars().count();
+        if let Some(min) = self.min_length.filter(|min| len < *min) {
+            return Err(ValidationError::MinLength { min });
+        }
+        if let Some(max) = self.max_length.filter(|max| len > *max) {
+            return Err(ValidationError::MaxLength { max });
+        }
+        if let Some((name, matches)) = self.pattern {
+            if !matches(value) {
+                return Err(ValidationError::Pattern {
+                    pattern: name.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let rules = Rules::new()
+            .min_length(2)
+            .max_length(4)
+            .pattern("number", |s| s.chars().all(|c| c.is_ascii_digit()));
+
+        assert_eq!(rules.validate(""), Ok(()));
+        assert_eq!(
+            rules.required().validate(""),
+            Err(ValidationError::Required)
+        );
+        assert_eq!(
+            rules.validate("1"),
+            Err(ValidationError::MinLength { min: 2 })
+        );
+        assert_eq!(
+            rules.validate("12345"),
+            Err(ValidationError::MaxLength { max: 4 })
+        );
+        assert_eq!(
+            rules.validate("1a"),
+            Err(ValidationError::Pattern {
+                pattern: "number".to_string()
+            })
+        );
+        assert_eq!(rules.validate("123"), Ok(()));
+        // characters are counted, not bytes
+        assert_eq!(Rules::new().max_length(3).validate("été"), Ok(()));
+    }
+}
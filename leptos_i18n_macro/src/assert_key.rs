@@ -0,0 +1,54 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, token::Comma, Token};
+
+use crate::t_macro::parsed_input::Keys;
+
+pub fn assert_key(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(tokens as AssertKeyInput);
+    assert_key_inner(input).into()
+}
+
+struct AssertKeyInput {
+    module: syn::Path,
+    keys: Punctuated<Keys, Comma>,
+}
+
+impl syn::parse::Parse for AssertKeyInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut module = syn::parse_quote!(crate::i18n);
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let name: syn::Ident = input.parse()?;
+            if name != "module" {
+                return Err(syn::Error::new(name.span(), "expected `module = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            module = input.parse()?;
+            input.parse::<Comma>()?;
+        }
+        let keys = Punctuated::parse_terminated(input)?;
+        if keys.is_empty() {
+            return Err(input.error("expected at least one key"));
+        }
+        Ok(AssertKeyInput { module, keys })
+    }
+}
+
+// accessing the keys in a function that is never called makes the build fail on a missing key,
+// with the same error as the `t!` macro, and generate no code.
+fn assert_key_inner(input: AssertKeyInput) -> TokenStream {
+    let AssertKeyInput { module, keys } = input;
+    let accesses = keys.iter().map(|keys| match keys {
+        Keys::SingleKey(key) => quote!(let _ = &_keys.#key;),
+        Keys::Subkeys(keys) => quote!(let _ = &_keys #(.#keys)*;),
+        Keys::Namespace(namespace, keys) => quote!(let _ = &_keys.#namespace #(.#keys)*;),
+    });
+    quote! {
+        const _: () = {
+            #[allow(deprecated, dead_code)]
+            fn __assert_keys(_keys: &#module::I18nKeys) {
+                #(#accesses)*
+            }
+        };
+    }
+}
@@ -0,0 +1,131 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Token};
+
+use crate::{localized_error::to_snake_case, t_macro::parsed_input::Keys};
+
+pub fn derive_i18n_display(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(tokens as DeriveInput);
+    match derive_i18n_display_inner(input) {
+        Ok(ts) => ts.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+enum I18nAttr {
+    Key(TokenStream),
+    Namespace(Ident),
+    Module(syn::Path),
+}
+
+fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<I18nAttr>> {
+    let mut parsed = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("i18n")) {
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match name.to_string().as_str() {
+                "key" => {
+                    let key: TokenStream = input.parse()?;
+                    // only check the key is valid, the `t!` macro does the rest.
+                    syn::parse2::<Keys>(key.clone())?;
+                    parsed.push(I18nAttr::Key(key));
+                }
+                "namespace" => parsed.push(I18nAttr::Namespace(input.parse()?)),
+                "module" => parsed.push(I18nAttr::Module(input.parse()?)),
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "expected one of `key = ...`, `namespace = ...` or `module = ...`",
+                    ))
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(parsed)
+}
+
+fn derive_i18n_display_inner(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "I18nDisplay can only be derived for enums",
+        ));
+    };
+
+    let mut module: syn::Path = syn::parse_quote!(crate::i18n);
+    let mut namespace = None;
+    for attr in parse_attrs(&input.attrs)? {
+        match attr {
+            I18nAttr::Module(path) => module = path,
+            I18nAttr::Namespace(ns) => namespace = Some(ns),
+            I18nAttr::Key(key) => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "the key must be set on the variants",
+                ))
+            }
+        }
+    }
+
+    let mut view_arms = Vec::with_capacity(data.variants.len());
+    let mut text_arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let mut key = None;
+        for attr in parse_attrs(&variant.attrs)? {
+            match attr {
+                I18nAttr::Key(k) => key = Some(k),
+                _ => {
+                    return Err(syn::Error::new(
+                        variant_ident.span(),
+                        "only `key = ...` can be used on variants",
+                    ))
+                }
+            }
+        }
+        // a key set on the variant is the full path, the namespace only applies to the default key.
+        let key = key.unwrap_or_else(|| {
+            let key = to_snake_case(variant_ident);
+            match &namespace {
+                Some(namespace) => quote!(#namespace::#key),
+                None => quote!(#key),
+            }
+        });
+
+        // the fields don't take part in the name of the variant.
+        let pattern = match &variant.fields {
+            syn::Fields::Unit => quote!(Self::#variant_ident),
+            syn::Fields::Unnamed(_) => quote!(Self::#variant_ident(..)),
+            syn::Fields::Named(_) => quote!(Self::#variant_ident { .. }),
+        };
+        view_arms.push(quote! {
+            #pattern => leptos::IntoView::into_view(leptos_i18n::t!(i18n, #key))
+        });
+        text_arms.push(quote! {
+            #pattern => leptos_i18n::render::render_to_text(leptos_i18n::td!(locale, #key))
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Return the name of this variant in the current locale of the context, updated when the locale change.
+            pub fn localized_name(&self, i18n: leptos_i18n::I18nContext<#module::Locales>) -> leptos::View {
+                match self {
+                    #(#view_arms,)*
+                }
+            }
+
+            /// Return the name of this variant in the given locale as plain text.
+            pub fn localized_name_in(&self, locale: #module::LocaleEnum) -> String {
+                match self {
+                    #(#text_arms,)*
+                }
+            }
+        }
+    })
+}
@@ -8,7 +8,11 @@
 //!
 //! This crate must be used with `leptos_i18n` and should'nt be used outside of it.
 
+pub(crate) mod assert_key;
+pub(crate) mod i18n_display;
 pub(crate) mod load_locales;
+pub(crate) mod localized_error;
+pub(crate) mod scoped;
 pub(crate) mod t_macro;
 
 // for deserializing the files custom deserialization is done,
@@ -22,9 +26,43 @@ pub(crate) mod t_macro;
 /// - `LocaleEnum`: an enum representing the available locales of the application.
 /// - `I18nKeys`: a struct representing the translation keys.
 /// - `Locales`: an empty type that serves as a bridge beetween the two types.
+///
+/// The directory of the locale files can be overridden, for example to load small fixture catalogs in an integration test:
+///
+/// ```rust, ignore
+/// leptos_i18n::load_locales!(locales_dir = "./tests/fixtures/locales");
+/// ```
+///
+/// A feature crate can generate only the subtree of the catalog it owns with `prefix`,
+/// a path of subkeys, or with namespaces a namespace optionally followed by a path of subkeys (`"checkout::cart"`):
+///
+/// ```rust, ignore
+/// leptos_i18n::load_locales!(prefix = "checkout");
+/// ```
+#[proc_macro]
+pub fn load_locales(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(tokens as load_locales::LoadLocalesArgs);
+    match load_locales::load_locales(args) {
+        Ok(ts) => ts.into(),
+        Err(err) => err.into(),
+    }
+}
+
+/// Load the locales like [`load_locales!`], but only generate the parts that don't depend on leptos,
+/// the `LocaleEnum` and its implementations of `leptos_i18n_core::LocaleVariant` and `leptos_i18n_core::catalog::LocaleCatalogs`,
+/// the catalogs being always embedded.
+///
+/// It is meant for the crates using `leptos_i18n_core` alone, even without `std`:
+///
+/// ```rust, ignore
+/// leptos_i18n_macro::load_catalogs!();
+/// ```
+///
+/// It takes the same arguments as [`load_locales!`].
 #[proc_macro]
-pub fn load_locales(_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match load_locales::load_locales() {
+pub fn load_catalogs(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(tokens as load_locales::LoadLocalesArgs);
+    match load_locales::load_catalogs(args) {
         Ok(ts) => ts.into(),
         Err(err) => err.into(),
     }
@@ -58,5 +96,162 @@ pub fn load_locales(_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// ```
 #[proc_macro]
 pub fn t(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    t_macro::t_macro(tokens)
+    t_macro::t_macro(tokens, t_macro::OutputType::View)
+}
+
+/// Just like the `t!` macro but take a locale instead of the context, and return the value directly instead of a closure.
+///
+/// It is not reactive, so it is suited for code running outside of the view, such as emails or notifications generated on the server:
+///
+/// ```rust, ignore
+/// let subject = td!(user.locale, welcome_subject);
+/// let body = td!(user.locale, welcome_body, name = user.name.clone(), <b> = |children| view! { <b>{children}</b> });
+/// ```
+#[proc_macro]
+pub fn td(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, t_macro::OutputType::Direct)
+}
+
+/// Just like the `td!` macro but render the value to an HTML `String`, components included.
+///
+/// The hydration markers added by leptos are removed, so the output can be used as is in an email body:
+///
+/// ```rust, ignore
+/// let html: String = td_string!(LocaleEnum::fr, welcome_body, name = user.name.clone(), <b> = |children| view! { <b>{children}</b> });
+/// // "Bienvenue <b>Alice</b> !"
+/// ```
+#[proc_macro]
+pub fn td_string(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, t_macro::OutputType::String)
+}
+
+/// Just like the `t!` macro but return a `Signal<String>` of the translation rendered to text.
+///
+/// The signal is updated when the locale or the interpolated values change,
+/// so it can be stored in state such as the validation messages of a form:
+///
+/// ```rust, ignore
+/// let error: Signal<String> = derive_translation!(i18n, too_short, min = move || min_len.get());
+/// ```
+#[proc_macro]
+pub fn derive_translation(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, t_macro::OutputType::Signal)
+}
+
+/// Just like the `t!` macro but return a closure of the translation rendered to text, for the attributes of an element.
+///
+/// Attributes can't hold a view, so accessibility attributes such as `aria-label`, `alt` or `title` are translated in one line:
+///
+/// ```rust, ignore
+/// view! {
+///     <button aria-label=t_aria!(i18n, close_dialog)>"×"</button>
+///     <img src=avatar alt=t_aria!(i18n, avatar_of, name = user.name.clone()) />
+/// }
+/// ```
+///
+/// The attribute is updated when the locale or the interpolated values change.
+#[proc_macro]
+pub fn t_aria(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, t_macro::OutputType::Attribute)
+}
+
+/// Just like the `td!` macro but render the value to plain text, for assertions in tests.
+///
+/// Components given without a value are rendered as markers, so the output is the same whatever the component renders:
+///
+/// ```rust, ignore
+/// assert_eq!(td_test!(LocaleEnum::fr, welcome_body, name = "Alice", <b>), "Bienvenue <b>Alice</b> !");
+/// ```
+#[proc_macro]
+pub fn td_test(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    t_macro::t_macro(tokens, t_macro::OutputType::Test)
+}
+
+/// Fail the build if one of the given keys doesn't exist, the keys are written as in the `t!` macro.
+///
+/// It pins the keys assumed by code building them at runtime, such as a `format!("errors.{}", code)` being migrated to `t!`:
+///
+/// ```rust, ignore
+/// leptos_i18n::assert_key!(errors.not_found, errors.forbidden, billing::invoice.title);
+/// ```
+///
+/// No code is generated. The keys are looked up in `crate::i18n::I18nKeys`, a first `module = path::to::i18n` argument change the module:
+///
+/// ```rust, ignore
+/// leptos_i18n::assert_key!(module = crate::translations::i18n, errors.not_found);
+/// ```
+#[proc_macro]
+pub fn assert_key(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    assert_key::assert_key(tokens)
+}
+
+/// Return a scope of the `I18nContext` whose keys resolve relative to the given subkeys, written as in the `t!` macro.
+///
+/// The scope is used in place of the context in the `t!`, `t_aria!` and `derive_translation!` macros:
+///
+/// ```rust, ignore
+/// let i18n = use_i18n_scoped!(dashboard.widgets);
+/// t!(i18n, title) // `dashboard.widgets.title`
+/// ```
+///
+/// A whole namespace is selected with `namespace::`. The context is returned by the `use_i18n` function of `crate::i18n`,
+/// a first `module = path::to::i18n` argument change the module.
+#[proc_macro]
+pub fn use_i18n_scoped(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    scoped::use_i18n_scoped(tokens)
+}
+
+/// Derive a `localize(&self, locale: LocaleEnum) -> String` method for an error enum, mapping each variant to a translation key.
+///
+/// The key of a variant is its name in snake case, or can be set with `#[i18n(key = ...)]` using the same syntax as the `t!` macro.
+/// The named fields are used as interpolation variables, `#[i18n(skip)]` exclude one:
+///
+/// ```rust, ignore
+/// #[derive(Debug, LocalizedError)]
+/// enum SignupError {
+///     // uses the `email_taken` key
+///     EmailTaken,
+///     #[i18n(key = errors.password_too_short)]
+///     PasswordTooShort { min: usize },
+///     #[i18n(key = errors.server)]
+///     Server {
+///         #[i18n(skip)]
+///         source: std::io::Error,
+///     },
+/// }
+///
+/// let message: String = SignupError::PasswordTooShort { min: 8 }.localize(LocaleEnum::fr);
+/// ```
+///
+/// The locale type is expected at `crate::i18n::LocaleEnum`, `#[i18n(module = path::to::i18n)]` on the enum change the module it is looked up in.
+#[proc_macro_derive(LocalizedError, attributes(i18n))]
+pub fn derive_localized_error(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    localized_error::derive_localized_error(tokens)
+}
+
+/// Derive `localized_name(&self, i18n) -> View` and `localized_name_in(&self, locale) -> String` methods for an enum,
+/// mapping each variant to a translation key, for status labels or select options.
+///
+/// The key of a variant is its name in snake case, in the namespace set with `#[i18n(namespace = ...)]` on the enum if any,
+/// or can be set with `#[i18n(key = ...)]` using the same syntax as the `t!` macro. The keys must not have interpolations.
+///
+/// ```rust, ignore
+/// #[derive(Clone, Copy, I18nDisplay)]
+/// #[i18n(namespace = order_status)]
+/// enum OrderStatus {
+///     // uses the `order_status::pending` key
+///     Pending,
+///     Shipped,
+///     #[i18n(key = common::delivered)]
+///     Delivered,
+/// }
+///
+/// let i18n = use_i18n();
+/// view! { <span>{OrderStatus::Shipped.localized_name(i18n)}</span> }
+/// ```
+///
+/// The locale types are expected in `crate::i18n`, `#[i18n(module = path::to::i18n)]` on the enum change the module they are looked up in.
+#[proc_macro_derive(I18nDisplay, attributes(i18n))]
+pub fn derive_i18n_display(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    i18n_display::derive_i18n_display(tokens)
 }
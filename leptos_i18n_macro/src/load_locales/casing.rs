@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+// same as `leptos_i18n_core::casing::Casing`, the macro can't depend on the runtime crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Casing {
+    Upper,
+    Lower,
+    Title,
+}
+
+impl Casing {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "upper" => Some(Casing::Upper),
+            "lower" => Some(Casing::Lower),
+            "title" => Some(Casing::Title),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Casing::Upper => "upper",
+            Casing::Lower => "lower",
+            Casing::Title => "title",
+        }
+    }
+}
+
+impl ToTokens for Casing {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Casing::Upper => quote!(Upper),
+            Casing::Lower => quote!(Lower),
+            Casing::Title => quote!(Title),
+        };
+        let core_crate = super::core_path();
+        quote!(#core_crate::casing::Casing::#variant).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,391 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    cfg_file::ConfigFile,
+    key::Key,
+    locale::{BuildersKeys, BuildersKeysInner, Locale, LocaleValue},
+    parsed_value::{InterpolateKey, ParsedValue},
+};
+
+// FNV-1a, stable across compilers and platforms so the hash can be used as an ETag.
+pub fn content_hash(content: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    content.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+// the text of a value as written in the locale files, with its interpolations and components.
+fn write_text(value: &ParsedValue, text: &mut String) {
+    let var_name = |key: &Key| {
+        key.name
+            .strip_prefix("var_")
+            .unwrap_or(&key.name)
+            .to_string()
+    };
+    match value {
+        ParsedValue::String(s) => text.push_str(s),
+        ParsedValue::Variable(key) => text.push_str(&format!("{{{{ {} }}}}", var_name(key))),
+        ParsedValue::CasedVariable { key, casing } => {
+            text.push_str(&format!("{{{{ {}, {} }}}}", var_name(key), casing.name()))
+        }
+        ParsedValue::FormattedVariable { key, format } => {
+            text.push_str(&format!("{{{{ {}, {} }}}}", var_name(key), format))
+        }
+        ParsedValue::Component { key, inner } => {
+            let name = key.name.strip_prefix("comp_").unwrap_or(&key.name);
+            text.push_str(&format!("<{}>", name));
+            write_text(inner, text);
+            text.push_str(&format!("</{}>", name));
+        }
+        ParsedValue::Bloc(values) => values.iter().for_each(|value| write_text(value, text)),
+        // plurals, genders and subkeys are only found at the top of a value, see `value_json`.
+        ParsedValue::Plural(_) | ParsedValue::Gender(_) | ParsedValue::Subkeys(_) => {}
+    }
+}
+
+fn value_json(value: &ParsedValue) -> serde_json::Value {
+    match value {
+        ParsedValue::Subkeys(locale) => keys_json(&locale.borrow().keys),
+        ParsedValue::Plural(plurals) => {
+            let plural_type = serde_json::json!(plurals.get_type().to_string());
+            let branches = plurals
+                .branches()
+                .into_iter()
+                .map(|(condition, _, value)| serde_json::json!([value_json(value), condition]));
+            serde_json::Value::Array(std::iter::once(plural_type).chain(branches).collect())
+        }
+        ParsedValue::Gender(genders) => {
+            let branches = genders.0.iter().map(|(condition, value)| {
+                let mut branch = vec![value_json(value)];
+                branch.extend(condition.names().into_iter().map(serde_json::Value::from));
+                serde_json::Value::Array(branch)
+            });
+            let gender = serde_json::json!("gender");
+            serde_json::Value::Array(std::iter::once(gender).chain(branches).collect())
+        }
+        value => {
+            let mut text = String::new();
+            write_text(value, &mut text);
+            serde_json::Value::String(text)
+        }
+    }
+}
+
+// the keys are sorted by serde_json, so the json and its hash are stable between builds.
+fn keys_json(keys: &HashMap<Rc<Key>, Rc<ParsedValue>>) -> serde_json::Value {
+    keys.iter()
+        .map(|(key, value)| (key.name.clone(), value_json(value)))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+// the catalogs are served as json whatever the format of the files. They are made from the checked locales,
+// so the metadata is left out, and the keys missing in a locale and the passthrough keys have the value of the default locale.
+fn catalog_json(locale: &Locale) -> String {
+    keys_json(&locale.keys).to_string()
+}
+
+fn catalog_tokens(locale: &Key, namespace: Option<&Rc<Key>>, json: &str) -> TokenStream {
+    let locale_name = &locale.name;
+    let namespace = match namespace {
+        Some(namespace) => {
+            let name = &namespace.name;
+            quote!(Some(#name))
+        }
+        None => quote!(None),
+    };
+    let hash = content_hash(json);
+    let core_crate = super::core_path();
+    quote! {
+        &#core_crate::catalog::Catalog {
+            locale: #locale_name,
+            namespace: #namespace,
+            json: #json,
+            hash: #hash,
+        }
+    }
+}
+
+struct KeySchema {
+    namespace: Option<String>,
+    path: String,
+    plural: Option<String>,
+    variables: Vec<String>,
+    components: Vec<String>,
+    description: Option<String>,
+}
+
+// the description of the first locale giving one, the default locale comes first.
+fn find_description(locales: &[Rc<RefCell<Locale>>], key: &Key) -> Option<String> {
+    locales
+        .iter()
+        .find_map(|locale| locale.borrow().descriptions.get(key).cloned())
+}
+
+fn collect_key_schemas(
+    namespace: Option<&str>,
+    prefix: &str,
+    locales: &[Rc<RefCell<Locale>>],
+    keys: &BuildersKeysInner,
+    schemas: &mut Vec<KeySchema>,
+) {
+    for (key, value) in &keys.0 {
+        let path = if prefix.is_empty() {
+            key.name.clone()
+        } else {
+            format!("{}.{}", prefix, key.name)
+        };
+        match value {
+            LocaleValue::Subkeys {
+                locales: sublocales,
+                keys,
+            } => collect_key_schemas(namespace, &path, sublocales, keys, schemas),
+            LocaleValue::Value(interpolate_keys) => {
+                let mut schema = KeySchema {
+                    namespace: namespace.map(str::to_string),
+                    path,
+                    plural: None,
+                    variables: vec![],
+                    components: vec![],
+                    description: find_description(locales, key),
+                };
+                for interpolate_key in interpolate_keys.iter().flatten() {
+                    let name = interpolate_key.get_real_name().to_string();
+                    match interpolate_key {
+                        InterpolateKey::Count(plural_type) => {
+                            schema.plural = Some(plural_type.to_string());
+                            schema.variables.push(name);
+                        }
+                        InterpolateKey::Gender | InterpolateKey::Variable(_) => {
+                            schema.variables.push(name)
+                        }
+                        InterpolateKey::Component(_) => schema.components.push(name),
+                    }
+                }
+                schema.variables.sort_unstable();
+                schema.components.sort_unstable();
+                schemas.push(schema);
+            }
+        }
+    }
+}
+
+fn schema_json(cfg_file: &ConfigFile, schemas: &[KeySchema]) -> String {
+    let keys = schemas
+        .iter()
+        .map(|schema| {
+            serde_json::json!({
+                "namespace": schema.namespace,
+                "key": schema.path,
+                "plural": schema.plural,
+                "variables": schema.variables,
+                "components": schema.components,
+                "description": schema.description,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({
+        "default_locale": cfg_file.default.name,
+        "locales": cfg_file.locales.iter().map(|locale| &locale.name).collect::<Vec<_>>(),
+        "namespaces": cfg_file.name_spaces.iter().flatten().map(|namespace| &namespace.name).collect::<Vec<_>>(),
+        "keys": keys,
+    })
+    .to_string()
+}
+
+// describe every key with its interpolation variables and components, for clients needing
+// the shape of the translations without parsing the locale files, such as a mobile app.
+pub fn create_schema(cfg_file: &ConfigFile, keys: &BuildersKeys) -> TokenStream {
+    let mut schemas = vec![];
+    match keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            for namespace in namespaces {
+                if let Some(keys) = keys.get(&namespace.key) {
+                    let name = Some(namespace.key.name.as_str());
+                    collect_key_schemas(name, "", &namespace.locales, keys, &mut schemas);
+                }
+            }
+        }
+        BuildersKeys::Locales { locales, keys } => {
+            collect_key_schemas(None, "", locales, keys, &mut schemas)
+        }
+    }
+    schemas.sort_unstable_by(|a, b| (&a.namespace, &a.path).cmp(&(&b.namespace, &b.path)));
+
+    let json = schema_json(cfg_file, &schemas);
+    let hash = content_hash(&json);
+    let default_locale = &cfg_file.default.name;
+    let core_crate = super::core_path();
+    let locales = cfg_file.locales.iter().map(|locale| &locale.name);
+    let namespaces = cfg_file
+        .name_spaces
+        .iter()
+        .flatten()
+        .map(|namespace| &namespace.name);
+    let keys = schemas.iter().map(|schema| {
+        let namespace = match &schema.namespace {
+            Some(namespace) => quote!(Some(#namespace)),
+            None => quote!(None),
+        };
+        let path = &schema.path;
+        let plural = match &schema.plural {
+            Some(plural) => quote!(Some(#plural)),
+            None => quote!(None),
+        };
+        let variables = &schema.variables;
+        let components = &schema.components;
+        let description = match &schema.description {
+            Some(description) => quote!(Some(#description)),
+            None => quote!(None),
+        };
+        quote! {
+            #core_crate::catalog::KeySchema {
+                namespace: #namespace,
+                path: #path,
+                plural: #plural,
+                variables: &[#(#variables,)*],
+                components: &[#(#components,)*],
+                description: #description,
+            }
+        }
+    });
+
+    quote! {
+        &#core_crate::catalog::CatalogSchema {
+            default_locale: #default_locale,
+            locales: &[#(#locales,)*],
+            namespaces: &[#(#namespaces,)*],
+            keys: &[#(#keys,)*],
+            json: #json,
+            hash: #hash,
+        }
+    }
+}
+
+pub fn create_catalogs(
+    cfg_file: &ConfigFile,
+    keys: &BuildersKeys,
+    schema: TokenStream,
+) -> TokenStream {
+    let catalog_arm = |locale: &Rc<RefCell<Locale>>, namespace: Option<&Rc<Key>>| {
+        let locale = locale.borrow();
+        let ident = &locale.name.ident;
+        let catalog = catalog_tokens(&locale.name, namespace, &catalog_json(&locale));
+        match namespace {
+            Some(namespace) => {
+                let name = &namespace.name;
+                quote!((LocaleEnum::#ident, Some(#name)) => Some(#catalog))
+            }
+            None => quote!((LocaleEnum::#ident, None) => Some(#catalog)),
+        }
+    };
+    let match_arms = match keys {
+        BuildersKeys::NameSpaces { namespaces, .. } => namespaces
+            .iter()
+            .flat_map(|namespace| {
+                namespace
+                    .locales
+                    .iter()
+                    .map(|locale| catalog_arm(locale, Some(&namespace.key)))
+            })
+            .collect::<Vec<_>>(),
+        BuildersKeys::Locales { locales, .. } => locales
+            .iter()
+            .map(|locale| catalog_arm(locale, None))
+            .collect(),
+    };
+
+    let namespaces = cfg_file
+        .name_spaces
+        .iter()
+        .flatten()
+        .map(|namespace| &namespace.name);
+    let core_crate = super::core_path();
+
+    quote! {
+        impl #core_crate::catalog::LocaleCatalogs for LocaleEnum {
+            const NAMESPACES: &'static [&'static str] = &[#(#namespaces,)*];
+
+            const SCHEMA: &'static #core_crate::catalog::CatalogSchema = #schema;
+
+            fn get_catalog(self, namespace: Option<&str>) -> Option<&'static #core_crate::catalog::Catalog> {
+                match (self, namespace) {
+                    #(#match_arms,)*
+                    _ => None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load_locales::locale::LocaleSeed;
+    use serde::de::DeserializeSeed;
+
+    fn parse_locale(name: &str, json: &str) -> Rc<RefCell<Locale>> {
+        let locale = Rc::new(Key::new(name).unwrap());
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let locale = LocaleSeed(Rc::clone(&locale), 0, locale)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        Rc::new(RefCell::new(locale))
+    }
+
+    #[test]
+    fn fnv_hash() {
+        assert_eq!(content_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(content_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(content_hash("{\"a\":\"b\"}"), content_hash("{\"a\":\"c\"}"));
+    }
+
+    #[test]
+    fn checked_catalog() {
+        let en = parse_locale(
+            "en",
+            r#"{
+                "@meta": { "strict": true },
+                "sku": "ACME-42",
+                "@sku": { "passthrough": true },
+                "greeting": "Hi <b>{{ name, upper }}</b>",
+                "@greeting": "Shown on the home page",
+                "price": "{{ amount, currency(usd) }}",
+                "items": ["u8", ["one item", "one"], ["{{ count }} items", "_"]],
+                "pronoun": ["gender", ["he", "masculine"], ["they", "_"]],
+                "nested": { "@meta": "A key", "missing": "Missing" }
+            }"#,
+        );
+        let fr = parse_locale(
+            "fr",
+            r#"{
+                "greeting": "Salut <b>{{ name, upper }}</b>",
+                "@greeting": "Affiché sur l'accueil",
+                "price": "{{ amount, currency(usd) }}",
+                "items": ["u8", ["un élément", "one"], ["{{ count }} éléments", "_"]],
+                "pronoun": ["gender", ["il", "masculine"], ["iel", "_"]],
+                "nested": {}
+            }"#,
+        );
+        Locale::check_locales_inner(&[en, Rc::clone(&fr)], None).unwrap();
+
+        let json = catalog_json(&fr.borrow());
+        assert!(!json.contains("\"@"));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({
+                "greeting": "Salut <b>{{ name, upper }}</b>",
+                "items": ["u8", ["un élément", "one"], ["{{ count }} éléments", "_"]],
+                "nested": { "missing": "Missing" },
+                "price": "{{ amount, currency(USD) }}",
+                "pronoun": ["gender", ["il", "masculine"], ["iel", "_"]],
+                "sku": "ACME-42",
+            })
+        );
+    }
+}
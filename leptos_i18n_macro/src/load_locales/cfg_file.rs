@@ -4,7 +4,11 @@ use super::{
     error::{Error, Result},
     key::Key,
 };
-use std::{borrow::Cow, collections::HashSet, rc::Rc};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashSet},
+    rc::Rc,
+};
 
 #[derive(Debug)]
 pub struct ConfigFile {
@@ -12,6 +16,58 @@ pub struct ConfigFile {
     pub locales: Vec<Rc<Key>>,
     pub name_spaces: Option<Vec<Rc<Key>>>,
     pub locales_dir: Cow<'static, str>,
+    pub check_plural_types: bool,
+    pub check_plural_branches: bool,
+    pub require_plural_count: bool,
+    pub strict: bool,
+    pub validation: BTreeMap<String, String>,
+    pub bidi_isolation: bool,
+    pub priority: Vec<Rc<Key>>,
+    pub tenants: Vec<Rc<Key>>,
+    pub complete_namespaces: Vec<Rc<Key>>,
+    pub locale_styles: BTreeMap<String, toml::Table>,
+    pub transformers: BTreeMap<String, Vec<String>>,
+    pub file_format: FileFormat,
+}
+
+// the format of the locale files, set with "file-format", the files are named after it, e.g. "en.yaml".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FileFormat {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl FileFormat {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(FileFormat::Json),
+            "yaml" => Some(FileFormat::Yaml),
+            "toml" => Some(FileFormat::Toml),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            FileFormat::Json => "json",
+            FileFormat::Yaml => "yaml",
+            FileFormat::Toml => "toml",
+        }
+    }
+
+    // the content of a file as json, for the embedded catalogs and the comparisons of the raw values.
+    pub fn parse_value(
+        self,
+        content: &str,
+    ) -> std::result::Result<serde_json::Value, Box<dyn std::error::Error>> {
+        match self {
+            FileFormat::Json => Ok(serde_json::from_str(content)?),
+            FileFormat::Yaml => Ok(serde_yaml::from_str(content)?),
+            FileFormat::Toml => Ok(toml::from_str(content)?),
+        }
+    }
 }
 
 impl ConfigFile {
@@ -33,24 +89,25 @@ impl ConfigFile {
         duplicates
     }
 
-    pub fn new() -> Result<ConfigFile> {
+    pub fn new(locales_dir: Option<String>) -> Result<ConfigFile> {
         let cfg_file_str =
             std::fs::read_to_string("./Cargo.toml").map_err(Error::ManifestNotFound)?;
 
-        let Some((before, i18n_cfg)) = cfg_file_str.split_once("[package.metadata.leptos-i18n]")
-        else {
+        Self::parse(&cfg_file_str, locales_dir)
+    }
+
+    pub fn parse(manifest: &str, locales_dir: Option<String>) -> Result<ConfigFile> {
+        // the whole manifest is deserialized for the sub-tables such as "[package.metadata.leptos-i18n.transformers]"
+        // to be read, and for the errors to have the line numbers of the manifest.
+        let ManifestConfig(cfg) = toml::de::from_str(manifest).map_err(Error::ConfigFileDeser)?;
+        let Some(mut cfg) = cfg else {
             return Err(Error::ConfigNotPresent);
         };
 
-        // this is to have the correct line number in the reported error.
-        let cfg_file_whitespaced = before
-            .chars()
-            .filter(|c| *c == '\n')
-            .chain(i18n_cfg.chars())
-            .collect::<String>();
-
-        let mut cfg: ConfigFile =
-            toml::de::from_str(&cfg_file_whitespaced).map_err(Error::ConfigFileDeser)?;
+        // set in the macro call, e.g. to load fixtures in integration tests.
+        if let Some(locales_dir) = locales_dir {
+            cfg.locales_dir = Cow::Owned(locales_dir);
+        }
 
         if let Some(i) = cfg.locales.iter().position(|l| l == &cfg.default) {
             // put default as first locale
@@ -61,8 +118,24 @@ impl ConfigFile {
             cfg.locales.swap(0, len);
         }
 
+        if let Some(unknown) = cfg.priority.iter().find(|l| !cfg.locales.contains(l)) {
+            return Err(Error::UnknownPriorityLocale(Rc::clone(unknown)));
+        }
+
+        if let Some(unknown) = cfg.complete_namespaces.iter().find(|ns| {
+            !cfg.name_spaces
+                .as_ref()
+                .is_some_and(|name_spaces| name_spaces.contains(ns))
+        }) {
+            return Err(Error::UnknownCompleteNamespace(Rc::clone(unknown)));
+        }
+
         if let Some(duplicates) = Self::contain_duplicates(&cfg.locales) {
             Err(Error::DuplicateLocalesInConfig(duplicates))
+        } else if let Some(duplicates) = Self::contain_duplicates(&cfg.priority) {
+            Err(Error::DuplicateLocalesInConfig(duplicates))
+        } else if let Some(duplicates) = Self::contain_duplicates(&cfg.tenants) {
+            Err(Error::DuplicateTenantsInConfig(duplicates))
         } else if let Some(duplicates) = cfg
             .name_spaces
             .as_deref()
@@ -75,9 +148,65 @@ impl ConfigFile {
     }
 }
 
-/// -----------------------------------------
-/// Deserialization
-/// -----------------------------------------
+// -----------------------------------------
+// Deserialization
+// -----------------------------------------
+
+// the path of the config in the manifest.
+const CONFIG_PATH: &[&str] = &["package", "metadata", "leptos-i18n"];
+
+// the config found at `CONFIG_PATH` in the manifest, the other tables are skipped.
+struct ManifestConfig(Option<ConfigFile>);
+
+impl<'de> serde::Deserialize<'de> for ManifestConfig {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::de::DeserializeSeed::deserialize(TableSeed(CONFIG_PATH), deserializer)
+            .map(ManifestConfig)
+    }
+}
+
+// the remaining path to the config in the current table.
+struct TableSeed(&'static [&'static str]);
+
+impl<'de> serde::de::DeserializeSeed<'de> for TableSeed {
+    type Value = Option<ConfigFile>;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match self.0 {
+            [] => serde::Deserialize::deserialize(deserializer).map(Some),
+            _ => deserializer.deserialize_map(self),
+        }
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for TableSeed {
+    type Value = Option<ConfigFile>;
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut cfg = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.0[0] {
+                cfg = map.next_value_seed(TableSeed(&self.0[1..]))?;
+            } else {
+                map.next_value::<serde::de::IgnoredAny>()?;
+            }
+        }
+        Ok(cfg)
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a table with a {:?} key", self.0[0])
+    }
+}
 
 struct CfgFileVisitor;
 
@@ -95,11 +224,40 @@ enum Field {
     Locales,
     Namespaces,
     LocalesDir,
+    CheckPluralTypes,
+    CheckPluralBranches,
+    RequirePluralCount,
+    Strict,
+    Validation,
+    BidiIsolation,
+    Priority,
+    Tenants,
+    CompleteNamespaces,
+    LocaleStyles,
+    Transformers,
+    FileFormat,
     Unknown,
 }
 
 impl Field {
-    const FIELDS: &'static [&'static str] = &["default", "locales, namespaces", "locales-dir"];
+    const FIELDS: &'static [&'static str] = &[
+        "default",
+        "locales",
+        "namespaces",
+        "locales-dir",
+        "check-plural-types",
+        "check-plural-branches",
+        "require-plural-count",
+        "strict",
+        "validation",
+        "bidi-isolation",
+        "priority",
+        "tenants",
+        "complete-namespaces",
+        "locale-styles",
+        "transformers",
+        "file-format",
+    ];
 }
 
 struct FieldVisitor;
@@ -133,6 +291,18 @@ impl<'de> serde::de::Visitor<'de> for FieldVisitor {
             "locales" => Ok(Field::Locales),
             "namespaces" => Ok(Field::Namespaces),
             "locales-dir" => Ok(Field::LocalesDir),
+            "check-plural-types" => Ok(Field::CheckPluralTypes),
+            "check-plural-branches" => Ok(Field::CheckPluralBranches),
+            "require-plural-count" => Ok(Field::RequirePluralCount),
+            "strict" => Ok(Field::Strict),
+            "validation" => Ok(Field::Validation),
+            "bidi-isolation" => Ok(Field::BidiIsolation),
+            "priority" => Ok(Field::Priority),
+            "tenants" => Ok(Field::Tenants),
+            "complete-namespaces" => Ok(Field::CompleteNamespaces),
+            "locale-styles" => Ok(Field::LocaleStyles),
+            "transformers" => Ok(Field::Transformers),
+            "file-format" => Ok(Field::FileFormat),
             _ => Ok(Field::Unknown), // skip unknown fields
         }
     }
@@ -164,12 +334,48 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         let mut locales = None;
         let mut name_spaces = None;
         let mut locales_dir = None;
+        let mut check_plural_types = None;
+        let mut check_plural_branches = None;
+        let mut require_plural_count = None;
+        let mut strict = None;
+        let mut validation = None;
+        let mut bidi_isolation = None;
+        let mut priority = None;
+        let mut tenants = None;
+        let mut complete_namespaces = None;
+        let mut locale_styles = None;
+        let mut transformers = None;
+        let mut file_format = None::<String>;
         while let Some(field) = map.next_key::<Field>()? {
             match field {
                 Field::Default => deser_field(&mut default, &mut map, "default")?,
                 Field::Locales => deser_field(&mut locales, &mut map, "locales")?,
                 Field::Namespaces => deser_field(&mut name_spaces, &mut map, "namespaces")?,
                 Field::LocalesDir => deser_field(&mut locales_dir, &mut map, "locales-dir")?,
+                Field::CheckPluralTypes => {
+                    deser_field(&mut check_plural_types, &mut map, "check-plural-types")?
+                }
+                Field::CheckPluralBranches => deser_field(
+                    &mut check_plural_branches,
+                    &mut map,
+                    "check-plural-branches",
+                )?,
+                Field::RequirePluralCount => {
+                    deser_field(&mut require_plural_count, &mut map, "require-plural-count")?
+                }
+                Field::Strict => deser_field(&mut strict, &mut map, "strict")?,
+                Field::Validation => deser_field(&mut validation, &mut map, "validation")?,
+                Field::BidiIsolation => {
+                    deser_field(&mut bidi_isolation, &mut map, "bidi-isolation")?
+                }
+                Field::Priority => deser_field(&mut priority, &mut map, "priority")?,
+                Field::Tenants => deser_field(&mut tenants, &mut map, "tenants")?,
+                Field::CompleteNamespaces => {
+                    deser_field(&mut complete_namespaces, &mut map, "complete-namespaces")?
+                }
+                Field::LocaleStyles => deser_field(&mut locale_styles, &mut map, "locale-styles")?,
+                Field::Transformers => deser_field(&mut transformers, &mut map, "transformers")?,
+                Field::FileFormat => deser_field(&mut file_format, &mut map, "file-format")?,
                 Field::Unknown => continue,
             }
         }
@@ -185,11 +391,29 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
             .map(Cow::Owned)
             .unwrap_or(Cow::Borrowed("./locales"));
 
+        let file_format = match file_format {
+            None => FileFormat::default(),
+            Some(name) => FileFormat::from_name(&name)
+                .ok_or_else(|| serde::de::Error::custom(Error::InvalidFileFormat(name)))?,
+        };
+
         Ok(ConfigFile {
             default,
             locales,
             name_spaces,
             locales_dir,
+            check_plural_types: check_plural_types.unwrap_or_default(),
+            check_plural_branches: check_plural_branches.unwrap_or_default(),
+            require_plural_count: require_plural_count.unwrap_or_default(),
+            strict: strict.unwrap_or_default(),
+            validation: validation.unwrap_or_default(),
+            bidi_isolation: bidi_isolation.unwrap_or_default(),
+            priority: priority.unwrap_or_default(),
+            tenants: tenants.unwrap_or_default(),
+            complete_namespaces: complete_namespaces.unwrap_or_default(),
+            locale_styles: locale_styles.unwrap_or_default(),
+            transformers: transformers.unwrap_or_default(),
+            file_format,
         })
     }
 
@@ -200,3 +424,74 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::transform;
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[dependencies]
+leptos = "0.5"
+
+[package.metadata.leptos-i18n]
+default = "en"
+locales = ["en", "fr"]
+
+[package.metadata.leptos-i18n.transformers]
+fr = ["smart_quotes", "french_spacing"]
+
+[package.metadata.leptos]
+output-name = "app"
+"#;
+
+    #[test]
+    fn parse_manifest() {
+        let cfg = ConfigFile::parse(MANIFEST, None).unwrap();
+        assert_eq!(cfg.default.name, "en");
+        assert_eq!(cfg.locales.len(), 2);
+        assert_eq!(cfg.locales_dir, "./locales");
+
+        let transformers = transform::transformers_for(&cfg, "fr");
+        let text = transformers
+            .iter()
+            .fold(String::from(r#"Dites "bonjour" !"#), |text, transformer| {
+                transformer.apply(&text, "fr", None)
+            });
+        assert_eq!(text, "Dites «\u{202f}bonjour\u{202f}»\u{202f}!");
+        assert!(transform::transformers_for(&cfg, "en").is_empty());
+    }
+
+    #[test]
+    fn parse_inline_manifest() {
+        let manifest = r#"
+[package.metadata.leptos-i18n]
+default = "en"
+locales = ["en", "fr"]
+transformers = { fr = ["smart_quotes"] }
+"#;
+        let cfg = ConfigFile::parse(manifest, Some("./fixtures".to_string())).unwrap();
+        assert_eq!(cfg.transformers["fr"], ["smart_quotes"]);
+        assert_eq!(cfg.locales_dir, "./fixtures");
+    }
+
+    #[test]
+    fn parse_manifest_errors() {
+        let manifest = "[package]\nname = \"app\"\n";
+        assert!(matches!(
+            ConfigFile::parse(manifest, None),
+            Err(Error::ConfigNotPresent)
+        ));
+
+        let manifest =
+            "[package]\nname = \"app\"\n\n[package.metadata.leptos-i18n]\nlocales = [\"en\"]\n";
+        let Err(Error::ConfigFileDeser(err)) = ConfigFile::parse(manifest, None) else {
+            panic!("expected a deserialization error");
+        };
+        assert!(err.to_string().contains("line 4"), "{}", err);
+    }
+}
@@ -1,13 +1,26 @@
-use std::{collections::HashSet, fmt::Display, rc::Rc};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    rc::Rc,
+};
 
 use super::{
+    cfg_file::FileFormat,
     key::{Key, KeyPath},
+    locale::{Locale, Namespace, MAX_LOCALE_FILE_SIZE},
+    parsed_value::MAX_NESTING_DEPTH,
     plural::PluralType,
+    tenant::tenant_dir,
 };
 use quote::quote;
 
 #[derive(Debug)]
 pub enum Error {
+    Multiple(Vec<Error>),
+    InFile {
+        path: String,
+        errors: Vec<Error>,
+    },
     ManifestNotFound(std::io::Error),
     ConfigNotPresent,
     ConfigFileDeser(toml::de::Error),
@@ -15,16 +28,52 @@ pub enum Error {
         path: String,
         err: std::io::Error,
     },
+    MissingNamespaceFiles(Vec<(Rc<Key>, Rc<Key>, String)>),
+    LocaleFileTooBig {
+        path: String,
+        size: u64,
+    },
     LocaleFileDeser {
         path: String,
-        err: serde_json::Error,
+        err: Box<dyn std::error::Error>,
     },
+    InvalidFileFormat(String),
     DuplicateLocalesInConfig(HashSet<String>),
     DuplicateNamespacesInConfig(HashSet<String>),
+    UnknownPriorityLocale(Rc<Key>),
+    UnknownCompleteNamespace(Rc<Key>),
+    UnknownStyleLocale(String),
+    UnknownTransformerLocale(String),
+    UnknownTransformer {
+        locale: String,
+        name: String,
+    },
+    InvalidLocaleStyle {
+        locale: String,
+        property: String,
+    },
+    InvalidPrefix(String),
+    DuplicateTenantsInConfig(HashSet<String>),
+    InvalidTenantKey {
+        tenant: Rc<Key>,
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
     MissingKeyInLocale {
         locale: Rc<Key>,
         key_path: KeyPath,
     },
+    UndeclaredPluralVariable {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        branch: String,
+        name: String,
+    },
+    MissingPluralCount {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        branch: String,
+    },
     SubKeyMissmatch {
         locale: Rc<Key>,
         key_path: KeyPath,
@@ -45,22 +94,156 @@ pub enum Error {
         type2: PluralType,
     },
     InvalidKey(String),
+    InvalidValidationKind(String),
+    InvalidValidationKey {
+        kind: String,
+        key: String,
+    },
     EmptyPlural,
     InvalidPluralType(String),
+    InvalidGender(String),
+    InvalidKeyMetadata(Rc<Key>),
+    InvalidFileMeta(Option<String>),
+    MissingGenderFallback,
     NestedPlurals,
     InvalidFallback,
     MultipleFallbacks,
     MissingFallback(PluralType),
+    PluralCategoryType {
+        category: String,
+        plural_type: PluralType,
+    },
+    MissingOtherCategory,
     PluralSubkeys,
     PluralNumberType {
         found: PluralType,
         expected: PluralType,
     },
+    DuplicateKey(Rc<Key>),
+    TooDeeplyNested(Rc<Key>),
+    EmptyValue {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    InvisibleCharInKey {
+        key: String,
+        character: char,
+    },
+    UnclosedComponent {
+        locale: Rc<Key>,
+        key: Rc<Key>,
+        opening: String,
+    },
+    UnopenedComponent {
+        locale: Rc<Key>,
+        key: Rc<Key>,
+        closing: String,
+    },
+    MisnestedComponent {
+        locale: Rc<Key>,
+        key: Rc<Key>,
+        opening: String,
+        closing: String,
+    },
+    InvalidVariableName {
+        key: Rc<Key>,
+        name: String,
+        suggestion: Option<String>,
+    },
+    InvalidFormatter {
+        key: Rc<Key>,
+        formatter: String,
+    },
+}
+
+impl Error {
+    // merge the errors of independent steps, so every problem is reported in one build.
+    pub fn collect(errors: Vec<Error>) -> Result<()> {
+        let mut errors = Error::Multiple(errors).into_errors();
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.pop().unwrap()),
+            _ => Err(Error::Multiple(errors)),
+        }
+    }
+
+    // the locale file an error was found in, for the errors located at a key.
+    fn file(&self, locales_dir: &str, format: FileFormat) -> Option<String> {
+        let (dir, locale, key_path) = match self {
+            Error::InvalidTenantKey {
+                tenant,
+                locale,
+                key_path,
+            } => (tenant_dir(locales_dir, tenant), locale, key_path),
+            Error::MissingKeyInLocale { locale, key_path }
+            | Error::UndeclaredPluralVariable {
+                locale, key_path, ..
+            }
+            | Error::MissingPluralCount {
+                locale, key_path, ..
+            }
+            | Error::SubKeyMissmatch { locale, key_path }
+            | Error::PluralTypeMissmatch {
+                locale, key_path, ..
+            }
+            | Error::EmptyValue { locale, key_path } => (locales_dir.to_string(), locale, key_path),
+            _ => return None,
+        };
+        let path = match key_path.namespace() {
+            Some(namespace) => Namespace::file_path(&dir, locale, namespace, format),
+            None => Locale::file_path(&dir, locale, format),
+        };
+        Some(path)
+    }
+
+    // a TMS export can break dozens of keys of the same file, print the path of the file once for all its errors.
+    pub fn group_by_file(self, locales_dir: &str, format: FileFormat) -> Self {
+        let mut files = BTreeMap::<String, Vec<Error>>::new();
+        let mut errors = vec![];
+        for error in self.into_errors() {
+            match error.file(locales_dir, format) {
+                Some(path) => files.entry(path).or_default().push(error),
+                None => errors.push(error),
+            }
+        }
+        errors.extend(
+            files
+                .into_iter()
+                .map(|(path, errors)| Error::InFile { path, errors }),
+        );
+        match errors.len() {
+            1 => errors.pop().unwrap(),
+            _ => Error::Multiple(errors),
+        }
+    }
+
+    fn into_errors(self) -> Vec<Error> {
+        match self {
+            Error::Multiple(errors) => errors.into_iter().flat_map(Error::into_errors).collect(),
+            error => vec![error],
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Error::Multiple(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                    }
+                    Display::fmt(error, f)?;
+                }
+                Ok(())
+            }
+            Error::InFile { path, errors } => {
+                write!(f, "{} problem(s) in {:?}:", errors.len(), path)?;
+                for error in errors {
+                    write!(f, "\n  - {}", error)?;
+                }
+                Ok(())
+            }
             Error::ManifestNotFound(err) => {
                 write!(f, "Error accessing cargo manifest (Cargo.toml) : {}", err)
             },
@@ -76,14 +259,39 @@ impl Display for Error {
                     path, err
                 )
             }
+            Error::MissingNamespaceFiles(missing) => {
+                write!(f, "Could not found {} namespace files:", missing.len())?;
+                for (locale, namespace, path) in missing {
+                    write!(f, "\n - namespace {:?} for locale {:?}: {:?}", namespace, locale, path)?;
+                }
+                Ok(())
+            }
+            Error::LocaleFileTooBig { path, size } => {
+                write!(f,
+                    "File {:?} is too big ({} bytes), locale files are limited to {} bytes, try splitting it into namespaces",
+                    path, size, MAX_LOCALE_FILE_SIZE
+                )
+            }
             Error::LocaleFileDeser { path, err} => write!(f,
                 "Parsing of file {:?} failed: {}",
                 path, err
             ),
+            Error::InvalidFileFormat(format) => write!(f,
+                "unknown file format {:?} in the \"file-format\" config, expected one of \"json\", \"yaml\" or \"toml\"",
+                format
+            ),
             Error::MissingKeyInLocale { key_path, locale } => write!(f,
-                "Some keys are different beetween locale files, locale {:?} is missing key: {}",
+                "locale {:?} is missing key {}, every locale must have all the keys of the namespaces listed in \"complete-namespaces\" and of the files marked \"complete\" in their \"@meta\" entry",
                 locale, key_path
             ),
+            Error::UndeclaredPluralVariable { locale, key_path, branch, name } => write!(f,
+                "plural branch {:?} of key {} in locale {:?} uses {:?}, which the key doesn't have in the default locale",
+                branch, key_path, locale, name
+            ),
+            Error::MissingPluralCount { locale, key_path, branch } => write!(f,
+                "plural branch {:?} of key {} in locale {:?} doesn't display {{{{ count }}}}, \"require-plural-count\" requires it in every branch that isn't an exact number",
+                branch, key_path, locale
+            ),
             Error::PluralParse {
                 plural,
                 plural_type
@@ -116,28 +324,124 @@ impl Display for Error {
                 "Found duplicates namespaces in configuration (Cargo.toml): {:?}", 
                 duplicates
             ),
+            Error::UnknownPriorityLocale(locale) => write!(f,
+                "locale {:?} in the \"priority\" config is not declared in \"locales\"",
+                locale
+            ),
+            Error::UnknownCompleteNamespace(namespace) => write!(f,
+                "namespace {:?} in the \"complete-namespaces\" config is not declared in \"namespaces\"",
+                namespace
+            ),
+            Error::UnknownStyleLocale(locale) => write!(f,
+                "locale {:?} in the \"locale-styles\" config is not declared in \"locales\"",
+                locale
+            ),
+            Error::UnknownTransformerLocale(locale) => write!(f,
+                "locale {:?} in the \"transformers\" config is not declared in \"locales\"",
+                locale
+            ),
+            Error::UnknownTransformer { locale, name } => write!(f,
+                "unknown transformer {:?} for locale {:?} in the \"transformers\" config, expected one of \"smart_quotes\", \"french_spacing\" or \"cjk_punctuation\"",
+                name, locale
+            ),
+            Error::InvalidLocaleStyle { locale, property } => write!(f,
+                "invalid property {:?} for locale {:?} in the \"locale-styles\" config, expected \"class\" or \"font-family\" as a string, or \"line-height\" as a number",
+                property, locale
+            ),
+            Error::InvalidPrefix(prefix) => write!(f,
+                "invalid prefix {:?}, expected the path of subkeys of the default locale such as \"checkout\" or \"checkout.cart\", or with namespaces a namespace such as \"checkout\" or \"checkout::cart\"",
+                prefix
+            ),
+            Error::DuplicateTenantsInConfig(duplicates) => write!(f,
+                "Found duplicates tenants in configuration (Cargo.toml): {:?}",
+                duplicates
+            ),
+            Error::InvalidTenantKey { tenant, locale, key_path } => write!(f,
+                "Key {} of tenant {:?} in locale {:?} is not a key without interpolations of the shared catalog, a tenant can only change the text of those keys",
+                key_path, tenant, locale
+            ),
             Error::PluralTypeMissmatch { locale, key_path, type1, type2 } => write!(f, "Missmatch plural value type as key {}, locale {:?} has type {} but another locale has type {}", key_path, locale, type1, type2),
+            Error::InvalidKey(key) if matches!(key.trim(), "self" | "super" | "crate" | "Self" | "_") => write!(f, "invalid key {:?}, this keyword can't be used as a rust identifier, even as a raw identifier, try renaming it", key),
             Error::InvalidKey(key) => write!(f, "invalid key {:?}, it can't be used as a rust identifier, try removing whitespaces and special characters", key),
+            Error::InvalidValidationKind(kind) => write!(f, "unknown validation error {:?} in the \"validation\" config, expected one of \"required\", \"min-length\", \"max-length\" or \"pattern\"", kind),
+            Error::InvalidValidationKey { kind, key } => write!(f, "invalid key {:?} for the validation error {:?}, expected a key path such as \"validation.required\" or \"namespace::validation.required\"", key, kind),
             Error::EmptyPlural => write!(f, "empty plurals are not allowed"),
-            Error::InvalidPluralType(t) => write!(f, "invalid plural type {:?}", t),
-            Error::NestedPlurals => write!(f, "nested plurals are not allowed"),
+            Error::InvalidPluralType(t) => write!(f, "invalid plural type {:?}, expected a number type or \"gender\"", t),
+            Error::InvalidGender(name) => write!(f, "invalid gender {:?}, expected \"masculine\", \"feminine\", \"neuter\", \"other\" or \"_\" for the fallback", name),
+            Error::InvalidFileMeta(None) => write!(f, "invalid \"@meta\" entry, expected a map of options"),
+            Error::InvalidFileMeta(Some(option)) => write!(f, "invalid option {:?} in the \"@meta\" entry, expected \"strict\" or \"complete\" with a boolean", option),
+            Error::InvalidKeyMetadata(key) => write!(f, "invalid metadata for key {:?}, expected a description or a map with \"description\", \"deprecated\", \"passthrough\" or \"source\" entries", key),
+            Error::MissingGenderFallback => write!(f, "gender branches require a fallback, so every gender has a value"),
+            Error::NestedPlurals => write!(f, "nested plurals and genders are not allowed"),
             Error::InvalidFallback => write!(f, "fallbacks are only allowed in last position"),
             Error::MultipleFallbacks => write!(f, "only one fallback is allowed"),
             Error::MissingFallback(t) => write!(f, "plural type {} require a fallback (or a fullrange \"..\")", t),
+            Error::PluralCategoryType { category, plural_type } => write!(f, "plural category {:?} can't be used for plural type {}, the CLDR rules only apply to integers", category, plural_type),
+            Error::MissingOtherCategory => write!(f, "plurals using plural categories must end with an \"other\" branch or a fallback"),
             Error::PluralSubkeys => write!(f, "subkeys for plurals are not allowed"),
             Error::SubKeyMissmatch { locale, key_path } => {
                 write!(f, "Missmatch value type beetween locale {:?} and default at key {}: one has subkeys and the other has direct value.", locale, key_path)
             },
             Error::PluralNumberType { found, expected } => write!(f, "number type {} can't be used for plural type {}", found, expected),
+            Error::DuplicateKey(key) => write!(f, "duplicate key {:?}, only one value per key is allowed", key),
+            Error::TooDeeplyNested(key) => write!(f, "value too deeply nested at key {:?}, subkeys and components can't be nested more than {} times", key, MAX_NESTING_DEPTH),
+            Error::EmptyValue { locale, key_path } => write!(f, "empty value in locale {:?} at key {}, strict mode requires every value to contain some text", locale, key_path),
+            Error::InvisibleCharInKey { key, character } => write!(f, "key {:?} contains the invisible character U+{:04X}, it most likely comes from a copy-paste, remove it", key, *character as u32),
+            Error::UnclosedComponent { locale, key, opening } => write!(f, "component {:?} is never closed in locale {:?} at key {:?}", opening, locale, key),
+            Error::UnopenedComponent { locale, key, closing } => write!(f, "closing tag {:?} does not match any opened component in locale {:?} at key {:?}", closing, locale, key),
+            Error::InvalidVariableName { key, name, suggestion: Some(suggestion) } => write!(f, "invalid variable name {:?} in value of key {:?}, it must be a valid rust identifier, try renaming it {:?}", name, key, suggestion),
+            Error::InvalidVariableName { key, name, suggestion: None } => write!(f, "invalid variable name {:?} in value of key {:?}, it must be a valid rust identifier", name, key),
+            Error::InvalidFormatter { key, formatter } => write!(f, "invalid formatter {:?} in value of key {:?}, expected \"upper\", \"lower\", \"title\", \"number\", \"percent\", \"currency(CODE)\" with a 3 letters ISO 4217 code, or \"date(LENGTH)\", \"time(LENGTH)\" or \"datetime(LENGTH)\" with a length of \"short\", \"medium\" or \"long\"", formatter, key),
+            Error::MisnestedComponent { locale, key, opening, closing } => write!(f, "closing tag {:?} found while component {:?} is still open in locale {:?} at key {:?}, components must be properly nested", closing, opening, locale, key),
         }
     }
 }
 
 impl From<Error> for proc_macro::TokenStream {
     fn from(value: Error) -> Self {
-        let error = value.to_string();
-        quote!(compile_error!(#error);).into()
+        let errors = value.into_errors().into_iter().map(|err| err.to_string());
+        quote!(#(compile_error!(#errors);)*).into()
     }
 }
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_value(locale: &str, namespace: &str, key: &str) -> Error {
+        let mut key_path = KeyPath::new(Some(Rc::new(Key::new(namespace).unwrap())));
+        key_path.push_key(Rc::new(Key::new(key).unwrap()));
+        Error::EmptyValue {
+            locale: Rc::new(Key::new(locale).unwrap()),
+            key_path,
+        }
+    }
+
+    #[test]
+    fn group_by_file() {
+        let errors = Error::Multiple(vec![
+            empty_value("fr", "home", "title"),
+            Error::InvalidPrefix("a..b".to_string()),
+            empty_value("fr", "home", "subtitle"),
+            empty_value("de", "home", "title"),
+        ]);
+
+        let grouped = errors
+            .group_by_file("./locales", FileFormat::Json)
+            .to_string();
+
+        assert_eq!(
+            grouped.lines().collect::<Vec<_>>(),
+            [
+                Error::InvalidPrefix("a..b".to_string()).to_string(),
+                "1 problem(s) in \"./locales/de/home.json\":".to_string(),
+                format!("  - {}", empty_value("de", "home", "title")),
+                "2 problem(s) in \"./locales/fr/home.json\":".to_string(),
+                format!("  - {}", empty_value("fr", "home", "title")),
+                format!("  - {}", empty_value("fr", "home", "subtitle")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+// same as `leptos_i18n_core::format::DateLength`, the macro can't depend on the runtime crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateLength {
+    Short,
+    Medium,
+    Long,
+}
+
+impl DateLength {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "short" => Some(DateLength::Short),
+            "medium" => Some(DateLength::Medium),
+            "long" => Some(DateLength::Long),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            DateLength::Short => "short",
+            DateLength::Medium => "medium",
+            DateLength::Long => "long",
+        }
+    }
+}
+
+impl ToTokens for DateLength {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            DateLength::Short => quote!(Short),
+            DateLength::Medium => quote!(Medium),
+            DateLength::Long => quote!(Long),
+        };
+        let core_crate = super::core_path();
+        quote!(#core_crate::format::DateLength::#variant).to_tokens(tokens)
+    }
+}
+
+// the formats of `leptos_i18n_core::format::NumberFormat` and `leptos_i18n_core::format::DateTimeFormat`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Format {
+    Number,
+    Percent,
+    // the ISO 4217 code, uppercased.
+    Currency(String),
+    Date(DateLength),
+    Time(DateLength),
+    DateTime(DateLength),
+}
+
+// the argument of `name(argument)`.
+fn argument<'a>(formatter: &'a str, name: &str) -> Option<&'a str> {
+    let argument = formatter
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    Some(argument.trim())
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        match name {
+            "number" => return Some(Format::Number),
+            "percent" => return Some(Format::Percent),
+            _ => {}
+        }
+        if let Some(code) = argument(name, "currency") {
+            let is_code = code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic());
+            return is_code.then(|| Format::Currency(code.to_ascii_uppercase()));
+        }
+        // "datetime" first, "date" is a prefix of it.
+        if let Some(length) = argument(name, "datetime") {
+            return DateLength::from_name(length).map(Format::DateTime);
+        }
+        if let Some(length) = argument(name, "date") {
+            return DateLength::from_name(length).map(Format::Date);
+        }
+        let length = argument(name, "time")?;
+        DateLength::from_name(length).map(Format::Time)
+    }
+}
+
+// the formatter as written in the locale files.
+impl core::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Number => f.write_str("number"),
+            Format::Percent => f.write_str("percent"),
+            Format::Currency(code) => write!(f, "currency({})", code),
+            Format::Date(length) => write!(f, "date({})", length.name()),
+            Format::Time(length) => write!(f, "time({})", length.name()),
+            Format::DateTime(length) => write!(f, "datetime({})", length.name()),
+        }
+    }
+}
+
+impl ToTokens for Format {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let core_crate = super::core_path();
+        let format = match self {
+            Format::Number => quote!(#core_crate::format::NumberFormat::Number),
+            Format::Percent => quote!(#core_crate::format::NumberFormat::Percent),
+            Format::Currency(code) => quote!(#core_crate::format::NumberFormat::Currency(#code)),
+            Format::Date(length) => quote!(#core_crate::format::DateTimeFormat::Date(#length)),
+            Format::Time(length) => quote!(#core_crate::format::DateTimeFormat::Time(#length)),
+            Format::DateTime(length) => {
+                quote!(#core_crate::format::DateTimeFormat::DateTime(#length))
+            }
+        };
+        format.to_tokens(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Format::from_name(" percent "), Some(Format::Percent));
+        assert_eq!(
+            Format::from_name("currency (usd)"),
+            Some(Format::Currency("USD".to_string()))
+        );
+        assert_eq!(
+            Format::from_name("datetime(medium)"),
+            Some(Format::DateTime(DateLength::Medium))
+        );
+        assert_eq!(
+            Format::from_name("date( long )"),
+            Some(Format::Date(DateLength::Long))
+        );
+        assert_eq!(Format::from_name("time(full)"), None);
+        assert_eq!(Format::from_name("currency(US)"), None);
+        assert_eq!(Format::from_name("date"), None);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Map, Value};
+
+use super::{
+    catalog::content_hash,
+    cfg_file::{ConfigFile, FileFormat},
+    key::{Key, KeyPath},
+    locale::{Locale, LocalesOrNamespaces, Namespace},
+    parsed_value::ParsedValue,
+    warning::{emit_warning, Warning},
+};
+
+// the hash of a value of the default locale, as written in the "source" of the "@key" entry of its translations:
+// FNV-1a of the minified json of the value, in 16 hexadecimal digits.
+pub fn source_hash(value: &Value) -> String {
+    format!("{:016x}", content_hash(&value.to_string()))
+}
+
+// the file was already parsed, a file failing to parse now has no source to compare to.
+fn read_default_file(path: &str, format: FileFormat) -> Option<Map<String, Value>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    match format.parse_value(&content).ok()? {
+        Value::Object(map) => Some(map),
+        _ => None,
+    }
+}
+
+// the keys of the file are normalized like the keys of the locales, e.g. "open (verb)" is the key "open__verb".
+fn find_value<'a>(default: &'a Map<String, Value>, key: &Key) -> Option<&'a Value> {
+    default
+        .iter()
+        .find(|(name, _)| Key::new(name).is_some_and(|name| &name == key))
+        .map(|(_, value)| value)
+}
+
+// `top_locale` is the name of the locale, the `name` of the subkeys is their key.
+fn collect_outdated(
+    top_locale: &Rc<Key>,
+    locale: &Locale,
+    default: &Map<String, Value>,
+    key_path: &mut KeyPath,
+    outdated: &mut Vec<String>,
+) {
+    for (key, value) in &locale.keys {
+        let Some(default_value) = find_value(default, key) else {
+            continue;
+        };
+        key_path.push_key(Rc::clone(key));
+        match (locale.sources.get(key), &**value, default_value) {
+            (Some(source), _, _) if *source != source_hash(default_value) => {
+                emit_warning(Warning::OutdatedKey {
+                    locale: Rc::clone(top_locale),
+                    key_path: key_path.clone(),
+                });
+                outdated.push(key_path.path());
+            }
+            (None, ParsedValue::Subkeys(subkeys), Value::Object(default)) => {
+                collect_outdated(top_locale, &subkeys.borrow(), default, key_path, outdated)
+            }
+            _ => {}
+        }
+        key_path.pop_key();
+    }
+}
+
+fn check_locales(
+    locales: &[Rc<RefCell<Locale>>],
+    default_path: &str,
+    format: FileFormat,
+    namespace: Option<&Rc<Key>>,
+    outdated: &mut BTreeMap<String, Vec<String>>,
+) {
+    let Some(default) = read_default_file(default_path, format) else {
+        return;
+    };
+    for locale in locales {
+        let locale = locale.borrow();
+        let locale_outdated = outdated.entry(locale.name.name.clone()).or_default();
+        let mut key_path = KeyPath::new(namespace.cloned());
+        collect_outdated(
+            &locale.name,
+            &locale,
+            &default,
+            &mut key_path,
+            locale_outdated,
+        );
+    }
+}
+
+// compare the "source" of the translated keys to the current values of the default locale,
+// the keys translated from a value that changed since are reported as outdated.
+pub fn find_outdated_keys(
+    cfg_file: &ConfigFile,
+    locales: &LocalesOrNamespaces,
+) -> BTreeMap<String, Vec<String>> {
+    let locales_dir = cfg_file.locales_dir.as_ref();
+    let default = &cfg_file.default;
+    let format = cfg_file.file_format;
+    let mut outdated = BTreeMap::new();
+    match locales {
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                let default_path =
+                    Namespace::file_path(locales_dir, default, &namespace.key, format);
+                check_locales(
+                    &namespace.locales,
+                    &default_path,
+                    format,
+                    Some(&namespace.key),
+                    &mut outdated,
+                );
+            }
+        }
+        LocalesOrNamespaces::Locales(locales) => {
+            let default_path = Locale::file_path(locales_dir, default, format);
+            check_locales(locales, &default_path, format, None, &mut outdated);
+        }
+    }
+    outdated.retain(|_, keys| !keys.is_empty());
+    for keys in outdated.values_mut() {
+        keys.sort_unstable();
+    }
+    outdated
+}
+
+pub fn create_outdated_keys(
+    cfg_file: &ConfigFile,
+    outdated_keys: &BTreeMap<String, Vec<String>>,
+) -> TokenStream {
+    let match_arms = cfg_file.locales.iter().filter_map(|locale| {
+        let keys = outdated_keys.get(&locale.name)?;
+        let ident = &locale.ident;
+        Some(quote!(LocaleEnum::#ident => &[#(#keys,)*]))
+    });
+
+    quote! {
+        impl LocaleEnum {
+            /// Return the keys of this locale translated from a previous value of the default locale, sorted.
+            pub fn outdated_keys(self) -> &'static [&'static str] {
+                match self {
+                    #(#match_arms,)*
+                    _ => &[]
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_hashes() {
+        let value = serde_json::json!("Hello {{ name }}");
+        let default = serde_json::json!({ "greeting": "Hello {{ name }}", "open (verb)": "Open" });
+        let Value::Object(default) = default else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            source_hash(&value),
+            format!("{:016x}", content_hash("\"Hello {{ name }}\""))
+        );
+        assert_eq!(source_hash(&value).len(), 16);
+        assert_eq!(
+            find_value(&default, &Key::new("open__verb").unwrap()),
+            Some(&serde_json::json!("Open"))
+        );
+        assert_eq!(find_value(&default, &Key::new("missing").unwrap()), None);
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::{
+    error::Error,
+    parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+};
+
+// same as `leptos_i18n_core::gender::Gender`, the macro can't depend on the runtime crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+    Other,
+}
+
+impl Gender {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "masculine" => Some(Gender::Masculine),
+            "feminine" => Some(Gender::Feminine),
+            "neuter" => Some(Gender::Neuter),
+            "other" => Some(Gender::Other),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Gender::Masculine => "masculine",
+            Gender::Feminine => "feminine",
+            Gender::Neuter => "neuter",
+            Gender::Other => "other",
+        }
+    }
+}
+
+impl ToTokens for Gender {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Gender::Masculine => quote!(Masculine),
+            Gender::Feminine => quote!(Feminine),
+            Gender::Neuter => quote!(Neuter),
+            Gender::Other => quote!(Other),
+        };
+        let core_crate = super::core_path();
+        quote!(#core_crate::gender::Gender::#variant).to_tokens(tokens)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenderCondition {
+    Genders(Vec<Gender>),
+    Fallback,
+}
+
+impl GenderCondition {
+    pub fn new(s: &str) -> Result<Self, Error> {
+        let mut genders = vec![];
+        for name in s.split('|') {
+            if matches!(name.trim(), "_" | "") {
+                return Ok(GenderCondition::Fallback);
+            }
+            let gender =
+                Gender::from_name(name).ok_or_else(|| Error::InvalidGender(name.to_string()))?;
+            genders.push(gender);
+        }
+        Ok(GenderCondition::Genders(genders))
+    }
+
+    // the names of the genders of the branch as written in the locale files.
+    pub fn names(&self) -> Vec<&'static str> {
+        match self {
+            GenderCondition::Genders(genders) => {
+                genders.iter().map(|gender| gender.name()).collect()
+            }
+            GenderCondition::Fallback => vec!["_"],
+        }
+    }
+}
+
+impl ToTokens for GenderCondition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            GenderCondition::Genders(genders) => quote!(#(#genders)|*).to_tokens(tokens),
+            GenderCondition::Fallback => quote!(_).to_tokens(tokens),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Genders(pub Vec<(GenderCondition, ParsedValue)>);
+
+impl Genders {
+    pub fn get_keys_inner(&self, keys: &mut Option<HashSet<InterpolateKey>>) {
+        for (_, value) in &self.0 {
+            value.get_keys_inner(keys);
+        }
+    }
+
+    pub fn values(&self) -> Vec<&ParsedValue> {
+        self.0.iter().map(|(_, value)| value).collect()
+    }
+
+    pub fn from_serde_seq<'de, A>(
+        mut seq: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<Self, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut branches = vec![];
+        while let Some(branch) = seq.next_element_seed(GenderBranchSeed(parsed_value_seed))? {
+            branches.push(branch);
+        }
+        let genders = Genders(branches);
+        genders.check().map_err(serde::de::Error::custom)?;
+        Ok(genders)
+    }
+
+    // the fallback is required so every gender has a value, even when a language only distinguish some of them.
+    fn check(&self) -> Result<(), Error> {
+        let fallbacks = self
+            .0
+            .iter()
+            .filter(|(condition, _)| *condition == GenderCondition::Fallback)
+            .count();
+        match self.0.last() {
+            None => Err(Error::EmptyPlural),
+            _ if fallbacks > 1 => Err(Error::MultipleFallbacks),
+            Some((GenderCondition::Fallback, _)) => Ok(()),
+            Some(_) if fallbacks == 1 => Err(Error::InvalidFallback),
+            Some(_) => Err(Error::MissingGenderFallback),
+        }
+    }
+}
+
+impl ToTokens for Genders {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let match_arms = self
+            .0
+            .iter()
+            .map(|(condition, value)| quote!(#condition => #value));
+
+        let mut captured_values = None;
+        self.get_keys_inner(&mut captured_values);
+        let captured_values = captured_values.map(|keys| {
+            let keys = keys
+                .into_iter()
+                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
+            quote!(#(#keys)*)
+        });
+
+        quote! {
+            leptos::IntoView::into_view(
+                {
+                    #captured_values
+                    move || {
+                        // the fallback is unreachable when every gender has its own branch.
+                        #[allow(unreachable_patterns)]
+                        let value = match var_gender() {
+                            #(#match_arms,)*
+                        };
+                        value
+                    }
+                },
+            )
+        }
+        .to_tokens(tokens)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GenderBranchSeed<'a>(ParsedValueSeed<'a>);
+
+impl<'de> serde::de::DeserializeSeed<'de> for GenderBranchSeed<'_> {
+    type Value = (GenderCondition, ParsedValue);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for GenderBranchSeed<'_> {
+    type Value = (GenderCondition, ParsedValue);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a sequence with the first element being the value and the other elements being the genders"
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let Some(value) = seq.next_element_seed(self.0)? else {
+            return Err(serde::de::Error::invalid_length(0, &"at least 1 element"));
+        };
+        let mut names = vec![];
+        while let Some(name) = seq.next_element::<String>()? {
+            names.push(name);
+        }
+        let condition = GenderCondition::new(&names.join("|")).map_err(serde::de::Error::custom)?;
+        Ok((condition, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gender_condition() {
+        assert_eq!(
+            GenderCondition::new("masculine | neuter").unwrap(),
+            GenderCondition::Genders(vec![Gender::Masculine, Gender::Neuter])
+        );
+        assert_eq!(
+            GenderCondition::new("_").unwrap(),
+            GenderCondition::Fallback
+        );
+        assert_eq!(GenderCondition::new("").unwrap(), GenderCondition::Fallback);
+        assert!(matches!(
+            GenderCondition::new("female"),
+            Err(Error::InvalidGender(name)) if name == "female"
+        ));
+    }
+}
@@ -16,6 +16,8 @@ pub struct Interpolation {
 
 struct Field<'a> {
     generic: syn::Ident,
+    // the trait bounding the value of the field in the `IntoView` impl, naming the field when it is missing.
+    bound: syn::Ident,
     name: String,
     kind: &'a InterpolateKey,
     #[cfg(feature = "debug_interpolations")]
@@ -38,14 +40,12 @@ impl Interpolation {
             .map(|kind| {
                 #[cfg(feature = "debug_interpolations")]
                 let real_name = kind.get_real_name();
-                let key = kind
-                    .as_key()
-                    .map(|key| key.name.as_str())
-                    .unwrap_or("var_count");
-                let name = format!("__{}", key);
+                let name = format!("__{}", kind.as_ident());
                 let generic = syn::Ident::new(&name, Span::call_site());
+                let bound = syn::Ident::new(&format!("{}{}", ident, name), Span::call_site());
                 Field {
                     generic,
+                    bound,
                     name,
                     kind,
                     #[cfg(feature = "debug_interpolations")]
@@ -55,6 +55,7 @@ impl Interpolation {
             .collect::<Vec<_>>();
 
         let type_def = Self::create_type(&ident, &fields);
+        let bounds = Self::create_bounds(key, &fields);
         let builder_impl = Self::builder_impl(&ident, &locale_field, &fields);
         let into_view_impl =
             Self::into_view_impl(key, &ident, &locale_field, &fields, top_locales, locales);
@@ -67,6 +68,8 @@ impl Interpolation {
         let imp = quote! {
             #type_def
 
+            #bounds
+
             #new_impl
 
             #into_view_impl
@@ -124,8 +127,8 @@ impl Interpolation {
 
         let left_generics = fields.iter().map(|field| {
             let ident = &field.generic;
-            let generic = field.kind.get_generic();
-            quote!(#ident: #generic)
+            let bound = &field.bound;
+            quote!(#ident: #bound)
         });
 
         let right_generics = fields.iter().map(|field| {
@@ -198,14 +201,70 @@ impl Interpolation {
         }
     }
 
-    fn generate_generics<'a, F: 'a, T: Clone + 'a>(
+    // a builder missing a value doesn't implement `IntoView`. The bound of a field is only implemented for `SetInterpolateValue`,
+    // so the error is reported on it and names the missing value, instead of an unsatisfied bound of `EmptyInterpolateValue`.
+    fn create_bounds(key: &Key, fields: &[Field]) -> TokenStream {
+        fields
+            .iter()
+            .map(|field| {
+                let bound = &field.bound;
+                let generic = field.kind.get_generic();
+                let real_name = field.kind.get_real_name();
+                let (message, argument) = match field.kind {
+                    InterpolateKey::Variable(_) => (
+                        format!("missing variable `{}` for the key `{}`", real_name, key.name),
+                        format!("{} = ..", real_name),
+                    ),
+                    InterpolateKey::Component(_) => (
+                        format!("missing component `<{}>` for the key `{}`", real_name, key.name),
+                        format!("<{}> = ..", real_name),
+                    ),
+                    InterpolateKey::Count(_) => (
+                        format!("missing the plural `count` for the key `{}`", key.name),
+                        "count = ..".to_string(),
+                    ),
+                    InterpolateKey::Gender => (
+                        format!("missing the `gender` for the key `{}`", key.name),
+                        "gender = ..".to_string(),
+                    ),
+                };
+                let label = format!("`{}` is not given", argument.trim_end_matches(" = .."));
+                let note = format!(
+                    "the key `{}` interpolates it, give it to the macro with `{}`",
+                    key.name, argument
+                );
+                quote! {
+                    #[doc(hidden)]
+                    #[allow(non_camel_case_types)]
+                    #[diagnostic::on_unimplemented(message = #message, label = #label, note = #note)]
+                    pub trait #bound: core::clone::Clone + 'static {
+                        type Value: #generic;
+
+                        fn into_value(self) -> Self::Value;
+                    }
+
+                    #[allow(non_camel_case_types)]
+                    impl<__T: #generic> #bound for SetInterpolateValue<__T> {
+                        type Value = __T;
+
+                        #[inline]
+                        fn into_value(self) -> __T {
+                            self.0
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn generate_generics<'a, F, T: Clone + 'a>(
         left_fields: &'a [Field],
         field_generic: Option<T>,
         right_fields: &'a [Field],
         other_field_map_fn: F,
     ) -> impl Iterator<Item = T> + 'a + Clone
     where
-        F: FnMut(&'a Field) -> T + Copy,
+        F: FnMut(&'a Field) -> T + Copy + 'a,
     {
         left_fields
             .iter()
@@ -245,14 +304,16 @@ impl Interpolation {
         });
         let left_generics = fields.clone().filter(|(set, _)| *set).map(|(_, field)| {
             let ident = &field.generic;
-            let generic_bound = field.kind.get_generic();
-            quote!(#ident: #generic_bound)
+            let bound = &field.bound;
+            quote!(#ident: #bound)
         });
 
         let missing_fields = fields
             .filter_map(|(set, field)| (!set).then_some(field))
             .map(|field| match field.kind {
-                InterpolateKey::Count(_) | InterpolateKey::Variable(_) => field.real_name.into(),
+                InterpolateKey::Count(_) | InterpolateKey::Gender | InterpolateKey::Variable(_) => {
+                    field.real_name.into()
+                }
                 InterpolateKey::Component(_) => format!("<{}>", field.real_name).into(),
             })
             .collect::<Vec<Cow<_>>>();
@@ -283,16 +344,13 @@ impl Interpolation {
         let output_field_generic = field.kind.get_generic();
         let output_generics = Self::generate_generics(
             left_fields,
-            Some(quote!(impl #output_field_generic)),
+            Some(quote!(SetInterpolateValue<impl #output_field_generic>)),
             right_fields,
             quoted_gen,
         );
         let other_fields = Self::generate_generics(left_fields, None, right_fields, |field| {
-            if let Some(key) = field.kind.as_key() {
-                quote!(#key)
-            } else {
-                quote!(var_count)
-            }
+            let kind = field.kind;
+            quote!(#kind)
         })
         .chain(Some(quote!(#locale_field)));
 
@@ -302,7 +360,7 @@ impl Interpolation {
             let other_fields = other_fields.clone();
             quote!(let Self { #(#other_fields,)* .. } = self;)
         };
-        let restructure = quote!(#ident { #(#other_fields,)* #kind });
+        let restructure = quote!(#ident { #(#other_fields,)* #kind: SetInterpolateValue(#kind) });
 
         let set_function = match kind {
             InterpolateKey::Variable(key) => {
@@ -334,11 +392,24 @@ impl Interpolation {
                 quote! {
                     #[inline]
                     pub fn var_count<__T, __N>(self, var_count: __T) -> #ident<#(#output_generics,)*>
-                        where __T: Fn() -> __N + core::clone::Clone + 'static,
+                        where __T: leptos_i18n::signal::InterpolationFn<__N>,
                               __N: core::convert::Into<#plural_type>
                     {
                         #destructure
-                        let var_count = move || core::convert::Into::into(var_count());
+                        let var_count = move || core::convert::Into::into(leptos_i18n::signal::InterpolationFn::get_value(&var_count));
+                        #restructure
+                    }
+                }
+            }
+            InterpolateKey::Gender => {
+                let core_crate = super::core_path();
+                quote! {
+                    #[inline]
+                    pub fn var_gender<__T>(self, var_gender: __T) -> #ident<#(#output_generics,)*>
+                        where __T: leptos_i18n::signal::InterpolationFn<#core_crate::gender::Gender>
+                    {
+                        #destructure
+                        let var_gender = move || leptos_i18n::signal::InterpolationFn::get_value(&var_gender);
                         #restructure
                     }
                 }
@@ -352,7 +423,8 @@ impl Interpolation {
                 left_fields,
                 Some({
                     let field_gen = &field.generic;
-                    quote!(#field_gen: #output_field_generic)
+                    let bound = &field.bound;
+                    quote!(#field_gen: #bound)
                 }),
                 right_fields,
                 quoted_gen,
@@ -370,6 +442,7 @@ impl Interpolation {
 
             let compile_warning = match field.kind {
                 InterpolateKey::Count(_) => "variable `count` is already set".to_string(),
+                InterpolateKey::Gender => "variable `gender` is already set".to_string(),
                 InterpolateKey::Variable(_) => format!("variable `{}` is already set", field.name),
                 InterpolateKey::Component(_) => {
                     format!("component `{}` is already set", field.name)
@@ -411,20 +484,34 @@ impl Interpolation {
         top_locales: &[Rc<RefCell<Locale>>],
         locales: &[Rc<RefCell<Locale>>],
     ) -> TokenStream {
-        let left_generics = fields.iter().map(|field| {
-            let ident = &field.generic;
-            let generic = field.kind.get_generic();
-            quote!(#ident: #generic)
-        });
+        let left_generics = fields
+            .iter()
+            .map(|field| {
+                let ident = &field.generic;
+                let bound = &field.bound;
+                quote!(#ident: #bound)
+            })
+            .collect::<Vec<_>>();
 
-        let right_generics = fields.iter().map(|field| {
-            let ident = &field.generic;
-            quote!(#ident)
-        });
+        let right_generics = fields
+            .iter()
+            .map(|field| {
+                let ident = &field.generic;
+                quote!(#ident)
+            })
+            .collect::<Vec<_>>();
 
         let fields_key = fields.iter().map(|f| f.kind);
 
-        let destructure = quote!(let Self { #(#fields_key,)* #locale_field } = self;);
+        let fields_values = fields.iter().map(|field| {
+            let key = field.kind;
+            let bound = &field.bound;
+            quote!(let #key = #bound::into_value(#key);)
+        });
+        let destructure = quote! {
+            let Self { #(#fields_key,)* #locale_field } = self;
+            #(#fields_values)*
+        };
 
         let locales_impls = Self::create_locale_impl(key, top_locales, locales);
 
@@ -440,6 +527,15 @@ impl Interpolation {
                     }
                 }
             }
+
+            // the translation as text in the locale of the builder, to log it or send it to an error tracker.
+            #[allow(non_camel_case_types)]
+            impl<#(#left_generics,)*> core::fmt::Display for #ident<#(#right_generics,)*> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let text = leptos_i18n::render::render_to_text(core::clone::Clone::clone(self));
+                    f.write_str(&text)
+                }
+            }
         }
     }
 
@@ -469,5 +565,8 @@ pub fn create_empty_type() -> TokenStream {
     quote! {
         #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
         pub struct EmptyInterpolateValue;
+
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+        pub struct SetInterpolateValue<T>(T);
     }
 }
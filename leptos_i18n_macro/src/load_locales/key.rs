@@ -1,4 +1,5 @@
 use super::error::{Error, Result};
+use icu_normalizer::ComposingNormalizerBorrowed;
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -33,9 +34,13 @@ impl Eq for Key {}
 
 impl Key {
     pub fn new(name: &str) -> Option<Self> {
-        let name = name.trim();
+        let name = normalize(&with_context(name.trim()));
         let ident_repr = name.replace('-', "_");
-        let ident = syn::parse_str::<syn::Ident>(&ident_repr).ok()?;
+        // keywords such as `type` or `move` are turned into raw identifiers (`r#type`),
+        // only `self`, `super`, `crate`, `Self` and `_` can't be represented.
+        let ident = syn::parse_str::<syn::Ident>(&ident_repr)
+            .or_else(|_| syn::parse_str::<syn::Ident>(&format!("r#{}", ident_repr)))
+            .ok()?;
         Some(Key {
             name: name.to_string(),
             ident,
@@ -43,10 +48,51 @@ impl Key {
     }
 
     pub fn try_new(name: &str) -> Result<Self> {
+        if let Some(character) = name.chars().find(|c| is_invisible(*c)) {
+            return Err(Error::InvisibleCharInKey {
+                key: name.to_string(),
+                character,
+            });
+        }
         Self::new(name).ok_or_else(|| Error::InvalidKey(name.to_string()))
     }
 }
 
+// gettext-like message contexts: "open (verb)" and "open (adjective)" are two keys for the same source word,
+// generated as the fields `open__verb` and `open__adjective`, and written `open(verb)` in the `t!` macro.
+fn with_context(name: &str) -> String {
+    match name.strip_suffix(')').and_then(|name| name.split_once('(')) {
+        Some((name, context)) => format!("{}__{}", name.trim(), context.trim()),
+        None => name.to_string(),
+    }
+}
+
+// rustc normalize identifiers to NFC, but only when it lexes them, not in the strings given to the macro.
+// Without it "café" typed with a combining accent and "café" would be two different keys that produce the same field.
+fn normalize(name: &str) -> String {
+    if name.is_ascii() {
+        return name.to_string();
+    }
+    ComposingNormalizerBorrowed::new_nfc()
+        .normalize(name)
+        .into_owned()
+}
+
+// zero width and bidi control characters, they often come from copy-pasting keys from documents.
+fn is_invisible(c: char) -> bool {
+    matches!(c,
+        '\u{00AD}'
+        | '\u{034F}'
+        | '\u{061C}'
+        | '\u{180E}'
+        | '\u{200B}'..='\u{200F}'
+        | '\u{202A}'..='\u{202E}'
+        | '\u{2060}'..='\u{2064}'
+        | '\u{2066}'..='\u{2069}'
+        | '\u{FEFF}'
+    )
+}
+
 impl quote::ToTokens for Key {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         self.ident.to_tokens(tokens)
@@ -67,6 +113,10 @@ impl KeyPath {
         }
     }
 
+    pub fn namespace(&self) -> Option<&Rc<Key>> {
+        self.namespace.as_ref()
+    }
+
     pub fn push_key(&mut self, key: Rc<Key>) {
         self.path.push(key);
     }
@@ -74,22 +124,23 @@ impl KeyPath {
     pub fn pop_key(&mut self) {
         self.path.pop();
     }
+
+    // the path as written by users, e.g. `namespace::key.subkey`.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        if let Some(namespace) = &self.namespace {
+            path.push_str(&namespace.name);
+            path.push_str("::");
+        }
+        let keys = self.path.iter().map(|key| key.name.as_str());
+        path.push_str(&keys.collect::<Vec<_>>().join("."));
+        path
+    }
 }
 
 impl Display for KeyPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("\"")?;
-        if let Some(namespace) = &self.namespace {
-            write!(f, "{}::", namespace.name)?;
-        }
-        let mut iter = self.path.iter();
-        if let Some(first) = iter.next() {
-            f.write_str(&first.name)?;
-            for key in iter {
-                write!(f, ".{}", key.name)?;
-            }
-        }
-        f.write_str("\"")
+        write!(f, "\"{}\"", self.path())
     }
 }
 
@@ -121,3 +172,29 @@ impl<'de> serde::de::Visitor<'de> for KeyVisitor {
         Key::try_new(v).map_err(E::custom)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_with_context() {
+        let key = Key::new("open (verb)").unwrap();
+        assert_eq!(key.name, "open__verb");
+        assert_eq!(key.ident.to_string(), "open__verb");
+        assert_ne!(key, Key::new("open (adjective)").unwrap());
+        assert!(Key::new("open (two words)").is_none());
+    }
+    #[test]
+    fn nfc_keys() {
+        let composed = Key::new("caf\u{e9}").unwrap();
+        let decomposed = Key::new("cafe\u{301}").unwrap();
+        assert_eq!(decomposed, composed);
+        assert_eq!(decomposed.name, "caf\u{e9}");
+        assert_eq!(decomposed.ident.to_string(), "caf\u{e9}");
+        assert_eq!(
+            Key::new("cafe\u{301}-cr\u{e8}me").unwrap().name,
+            "caf\u{e9}-cr\u{e8}me"
+        );
+    }
+}
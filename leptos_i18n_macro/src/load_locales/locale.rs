@@ -1,6 +1,6 @@
 use std::{
     cell::RefCell,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fs::File,
     rc::Rc,
 };
@@ -8,13 +8,21 @@ use std::{
 use serde::de::DeserializeSeed;
 
 use super::{
-    cfg_file::ConfigFile,
+    cfg_file::{ConfigFile, FileFormat},
     error::{Error, Result},
     key::{Key, KeyPath},
     parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
+    plural::PluralType,
     warning::{emit_warning, Warning},
 };
 
+pub const MAX_LOCALE_FILE_SIZE: u64 = 16 * 1024 * 1024;
+
+thread_local! {
+    // keys missing in a locale and filled with the value of the default locale, by locale.
+    pub static FALLBACK_KEYS: RefCell<BTreeMap<String, Vec<String>>> = const { RefCell::new(BTreeMap::new()) };
+}
+
 pub struct Namespace {
     pub key: Rc<Key>,
     pub locales: Vec<Rc<RefCell<Locale>>>,
@@ -39,37 +47,298 @@ pub enum BuildersKeys {
     },
 }
 
+impl BuildersKeys {
+    // The same key can be a plural in multiple namespaces, warn if the count types differ
+    // as it is most likely the same concept counted differently at the call sites.
+    pub fn check_plural_types(&self) {
+        fn collect_plural_types(
+            keys: &BuildersKeysInner,
+            namespace: &Rc<Key>,
+            path: &mut Vec<Rc<Key>>,
+            plural_types: &mut BTreeMap<String, Vec<(Rc<Key>, PluralType)>>,
+        ) {
+            for (key, value) in &keys.0 {
+                path.push(Rc::clone(key));
+                match value {
+                    LocaleValue::Value(interpolations) => {
+                        let plural_type =
+                            interpolations.iter().flatten().find_map(|key| match key {
+                                InterpolateKey::Count(plural_type) => Some(*plural_type),
+                                _ => None,
+                            });
+                        if let Some(plural_type) = plural_type {
+                            let path = path.iter().map(|key| key.name.as_str()).collect::<Vec<_>>();
+                            plural_types
+                                .entry(path.join("."))
+                                .or_default()
+                                .push((Rc::clone(namespace), plural_type));
+                        }
+                    }
+                    LocaleValue::Subkeys { keys, .. } => {
+                        collect_plural_types(keys, namespace, path, plural_types)
+                    }
+                }
+                path.pop();
+            }
+        }
+
+        let BuildersKeys::NameSpaces { namespaces, keys } = self else {
+            return;
+        };
+
+        let mut plural_types = BTreeMap::new();
+        for namespace in namespaces {
+            if let Some(keys) = keys.get(&namespace.key) {
+                collect_plural_types(keys, &namespace.key, &mut vec![], &mut plural_types);
+            }
+        }
+
+        for (key, types) in plural_types {
+            if types
+                .iter()
+                .any(|(_, plural_type)| *plural_type != types[0].1)
+            {
+                emit_warning(Warning::InconsistentPluralTypes { key, types });
+            }
+        }
+    }
+}
+
 impl Namespace {
-    pub fn new(locales_dir: &str, key: Rc<Key>, locale_keys: &[Rc<Key>]) -> Result<Self> {
-        let mut locales = Vec::with_capacity(locale_keys.len());
-        for locale in locale_keys.iter().cloned() {
-            let path = format!("{}/{}/{}.json", locales_dir, locale.name, key.name);
-            locales.push(Rc::new(RefCell::new(Locale::new(path, locale)?)));
+    pub fn file_path(
+        locales_dir: &str,
+        locale: &Key,
+        namespace: &Key,
+        format: FileFormat,
+    ) -> String {
+        format!(
+            "{}/{}/{}.{}",
+            locales_dir,
+            locale.name,
+            namespace.name,
+            format.extension()
+        )
+    }
+
+    // check every file up front, so a missing namespace for multiple locales is fixed in one go.
+    fn check_files_presence(
+        locales_dir: &str,
+        namespace_keys: &[Rc<Key>],
+        locale_keys: &[Rc<Key>],
+        format: FileFormat,
+    ) -> Result<()> {
+        let mut missing = vec![];
+        for namespace in namespace_keys {
+            for locale in locale_keys {
+                let path = Self::file_path(locales_dir, locale, namespace, format);
+                if !std::path::Path::new(&path).is_file() {
+                    missing.push((Rc::clone(locale), Rc::clone(namespace), path));
+                }
+            }
         }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::MissingNamespaceFiles(missing))
+        }
+    }
+
+    pub fn new(
+        locales_dir: &str,
+        key: Rc<Key>,
+        locale_keys: &[Rc<Key>],
+        format: FileFormat,
+    ) -> Result<Self> {
+        let paths = locale_keys.iter().map(|locale| {
+            (
+                Self::file_path(locales_dir, locale, &key, format),
+                Rc::clone(locale),
+            )
+        });
+        let locales = Locale::new_all(paths, format)?;
         Ok(Namespace { key, locales })
     }
 }
 
+// the `prefix` argument of `load_locales!`, the subtree of the catalog to generate.
+#[derive(Debug)]
+pub struct KeyPrefix {
+    pub namespace: Option<Rc<Key>>,
+    pub path: Vec<Rc<Key>>,
+}
+
+impl KeyPrefix {
+    // "a.b", or with namespaces "ns" and "ns::a.b".
+    pub fn new(prefix: &str, with_namespaces: bool) -> Result<Self> {
+        let invalid = || Error::InvalidPrefix(prefix.to_string());
+        let (namespace, path) = match (with_namespaces, prefix.split_once("::")) {
+            (true, Some((namespace, path))) => (Some(namespace), path),
+            (true, None) => (Some(prefix), ""),
+            (false, Some(_)) => return Err(invalid()),
+            (false, None) => (None, prefix),
+        };
+        let namespace = match namespace {
+            Some(namespace) => Some(Rc::new(Key::new(namespace).ok_or_else(invalid)?)),
+            None => None,
+        };
+        let path = path
+            .split('.')
+            .filter(|key| !key.trim().is_empty())
+            .map(|key| Key::new(key).map(Rc::new).ok_or_else(invalid))
+            .collect::<Result<Vec<_>>>()?;
+        if namespace.is_none() && path.is_empty() {
+            return Err(invalid());
+        }
+        Ok(KeyPrefix { namespace, path })
+    }
+
+    // keep the subtree of every locale, it must exist in the default one.
+    // a locale without it is left empty so all its keys are reported missing.
+    fn select_in(&self, locales: &[Rc<RefCell<Locale>>], prefix: &str) -> Result<()> {
+        for (i, locale) in locales.iter().enumerate() {
+            let mut locale = locale.borrow_mut();
+            if !locale.select_subtree(&self.path) {
+                if i == 0 {
+                    return Err(Error::InvalidPrefix(prefix.to_string()));
+                }
+                locale.keys.clear();
+            }
+        }
+        Ok(())
+    }
+}
+
 impl LocalesOrNamespaces {
+    // only keep the keys under the prefix, so the generated code only contains them.
+    pub fn select_prefix(self, prefix: &str) -> Result<Self> {
+        let key_prefix =
+            KeyPrefix::new(prefix, matches!(self, LocalesOrNamespaces::NameSpaces(_)))?;
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                let namespace = namespaces
+                    .into_iter()
+                    .find(|namespace| Some(&namespace.key) == key_prefix.namespace.as_ref())
+                    .ok_or_else(|| Error::InvalidPrefix(prefix.to_string()))?;
+                key_prefix.select_in(&namespace.locales, prefix)?;
+                Ok(LocalesOrNamespaces::NameSpaces(vec![namespace]))
+            }
+            LocalesOrNamespaces::Locales(locales) => {
+                key_prefix.select_in(&locales, prefix)?;
+                Ok(LocalesOrNamespaces::Locales(locales))
+            }
+        }
+    }
+
+    // every file with `strict`, else only the files marked strict in their "@meta" entry.
+    pub fn check_empty_values(&self, strict: bool) -> Result<()> {
+        let mut errors = vec![];
+        let mut check = |locale: &Rc<RefCell<Locale>>, namespace: Option<&Rc<Key>>| {
+            let locale = locale.borrow();
+            if !strict && !locale.meta.strict {
+                return;
+            }
+            let mut key_path = KeyPath::new(namespace.cloned());
+            if let Err(err) = locale.check_empty_values(&locale.name, &mut key_path) {
+                errors.push(err);
+            }
+        };
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    for locale in &namespace.locales {
+                        check(locale, Some(&namespace.key));
+                    }
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => {
+                for locale in locales {
+                    check(locale, None);
+                }
+            }
+        }
+        Error::collect(errors)
+    }
+
+    // the namespaces listed in "complete-namespaces", and the files marked complete in their "@meta" entry,
+    // can't fallback to the default locale.
+    pub fn check_complete_namespaces(&self, complete_namespaces: &[Rc<Key>]) -> Result<()> {
+        let mut errors = vec![];
+        let mut check = |locales: &[Rc<RefCell<Locale>>], namespace: Option<&Rc<Key>>| {
+            let complete =
+                namespace.is_some_and(|namespace| complete_namespaces.contains(namespace));
+            let mut locales = locales.iter();
+            let default_locale = locales.next().unwrap().borrow();
+            for locale in locales {
+                let locale = locale.borrow();
+                if !complete && !locale.meta.complete {
+                    continue;
+                }
+                let mut key_path = KeyPath::new(namespace.cloned());
+                let result = locale.check_complete(&default_locale, &locale.name, &mut key_path);
+                errors.extend(result.err());
+            }
+        };
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    check(&namespace.locales, Some(&namespace.key));
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => check(locales, None),
+        }
+        Error::collect(errors)
+    }
+
+    // every plural branch of every locale is checked against the interpolations of the key in the default locale.
+    pub fn check_plural_branches(&self, require_count: bool) -> Result<()> {
+        let mut errors = vec![];
+        let mut check = |locales: &[Rc<RefCell<Locale>>], namespace: Option<&Rc<Key>>| {
+            let default_locale = locales[0].borrow();
+            for locale in locales {
+                let locale = locale.borrow();
+                let mut key_path = KeyPath::new(namespace.cloned());
+                let result = locale.check_plural_branches(
+                    &default_locale,
+                    &locale.name,
+                    &mut key_path,
+                    require_count,
+                );
+                errors.extend(result.err());
+            }
+        };
+        match self {
+            LocalesOrNamespaces::NameSpaces(namespaces) => {
+                for namespace in namespaces {
+                    check(&namespace.locales, Some(&namespace.key));
+                }
+            }
+            LocalesOrNamespaces::Locales(locales) => check(locales, None),
+        }
+        Error::collect(errors)
+    }
+
     pub fn new(cfg_file: &ConfigFile) -> Result<Self> {
         let locale_keys = &cfg_file.locales;
         let locales_dir = cfg_file.locales_dir.as_ref();
+        let format = cfg_file.file_format;
         if let Some(namespace_keys) = &cfg_file.name_spaces {
+            Namespace::check_files_presence(locales_dir, namespace_keys, locale_keys, format)?;
             let mut namespaces = Vec::with_capacity(namespace_keys.len());
+            let mut errors = vec![];
             for namespace in namespace_keys {
-                namespaces.push(Namespace::new(
-                    locales_dir,
-                    Rc::clone(namespace),
-                    locale_keys,
-                )?);
+                match Namespace::new(locales_dir, Rc::clone(namespace), locale_keys, format) {
+                    Ok(namespace) => namespaces.push(namespace),
+                    Err(err) => errors.push(err),
+                }
             }
+            Error::collect(errors)?;
             Ok(LocalesOrNamespaces::NameSpaces(namespaces))
         } else {
-            let mut locales = Vec::with_capacity(locale_keys.len());
-            for locale in locale_keys.iter().cloned() {
-                let path = format!("{}/{}.json", locales_dir, locale.name);
-                locales.push(Rc::new(RefCell::new(Locale::new(path, locale)?)));
-            }
+            let paths = locale_keys.iter().map(|locale| {
+                let path = Locale::file_path(locales_dir, locale, format);
+                (path, Rc::clone(locale))
+            });
+            let locales = Locale::new_all(paths, format)?;
             Ok(LocalesOrNamespaces::Locales(locales))
         }
     }
@@ -79,20 +348,289 @@ impl LocalesOrNamespaces {
 pub struct Locale {
     pub name: Rc<Key>,
     pub keys: HashMap<Rc<Key>, Rc<ParsedValue>>,
+    // the descriptions given to translators with "@key" entries, ignored by the codegen.
+    pub descriptions: HashMap<Rc<Key>, String>,
+    // keys marked as deprecated in their "@key" entry, with the key replacing them if any.
+    pub deprecations: HashMap<Rc<Key>, Option<String>>,
+    // keys marked as passthrough in their "@key" entry, the same in every locale (product codes, commands, ...).
+    pub passthrough: HashSet<Rc<Key>>,
+    // the hash of the value of the default locale a key was translated from, set in its "@key" entry.
+    pub sources: HashMap<Rc<Key>, String>,
+    // the options of the file, set in its top-level "@meta" entry.
+    pub meta: FileMeta,
+}
+
+// the "@meta" entry of a file, applying checks of the configuration to this file only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FileMeta {
+    // same as the "strict" setting.
+    pub strict: bool,
+    // every key of the default locale must be in the file, as with "complete-namespaces".
+    pub complete: bool,
+}
+
+impl FileMeta {
+    fn parse(meta: serde_json::Value) -> Result<Self> {
+        use serde_json::Value;
+
+        let Value::Object(meta) = meta else {
+            return Err(Error::InvalidFileMeta(None));
+        };
+        let mut file_meta = FileMeta::default();
+        for (option, value) in meta {
+            let field = match option.as_str() {
+                "strict" => &mut file_meta.strict,
+                "complete" => &mut file_meta.complete,
+                _ => return Err(Error::InvalidFileMeta(Some(option))),
+            };
+            *field = value
+                .as_bool()
+                .ok_or_else(|| Error::InvalidFileMeta(Some(option)))?;
+        }
+        Ok(file_meta)
+    }
 }
 
 impl Locale {
-    pub fn new(path: String, locale: Rc<Key>) -> Result<Self> {
+    pub fn file_path(locales_dir: &str, locale: &Key, format: FileFormat) -> String {
+        format!("{}/{}.{}", locales_dir, locale.name, format.extension())
+    }
+
+    pub fn new(path: String, locale: Rc<Key>, format: FileFormat) -> Result<Self> {
         let locale_file = match File::open(&path) {
             Ok(file) => file,
             Err(err) => return Err(Error::LocaleFileNotFound { path, err }),
         };
 
-        let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
+        // a file this big is most likely a mistake, and would make the macro hang for a long time.
+        if let Ok(metadata) = locale_file.metadata() {
+            if metadata.len() > MAX_LOCALE_FILE_SIZE {
+                return Err(Error::LocaleFileTooBig {
+                    path,
+                    size: metadata.len(),
+                });
+            }
+        }
+
+        let seed = LocaleSeed(Rc::clone(&locale), 0, locale);
+        let parsed: std::result::Result<_, Box<dyn std::error::Error>> = match format {
+            FileFormat::Json => {
+                let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
+                seed.deserialize(&mut deserializer).map_err(Box::from)
+            }
+            FileFormat::Yaml => seed
+                .deserialize(serde_yaml::Deserializer::from_reader(locale_file))
+                .map_err(Box::from),
+            // the toml parser needs the whole file.
+            FileFormat::Toml => match std::io::read_to_string(locale_file) {
+                Ok(content) => seed
+                    .deserialize(toml::Deserializer::new(&content))
+                    .map_err(Box::from),
+                Err(err) => return Err(Error::LocaleFileNotFound { path, err }),
+            },
+        };
+        parsed.map_err(|err| Error::LocaleFileDeser { path, err })
+    }
+
+    // replace the keys by the ones of the subkeys at `path`, `false` if there is no subkeys there.
+    pub fn select_subtree(&mut self, path: &[Rc<Key>]) -> bool {
+        let Some((key, rest)) = path.split_first() else {
+            return true;
+        };
+        let Some(ParsedValue::Subkeys(subkeys)) = self.keys.get(key).map(|value| &**value) else {
+            return false;
+        };
+        let mut subkeys = subkeys.borrow().clone();
+        if !subkeys.select_subtree(rest) {
+            return false;
+        }
+        self.keys = subkeys.keys;
+        self.descriptions = subkeys.descriptions;
+        self.deprecations = subkeys.deprecations;
+        self.passthrough = subkeys.passthrough;
+        self.sources = subkeys.sources;
+        true
+    }
 
-        LocaleSeed(locale)
-            .deserialize(&mut deserializer)
-            .map_err(|err| Error::LocaleFileDeser { path, err })
+    // parse every file even if one fails, to report all the parsing errors at once.
+    fn new_all(
+        paths: impl Iterator<Item = (String, Rc<Key>)>,
+        format: FileFormat,
+    ) -> Result<Vec<Rc<RefCell<Locale>>>> {
+        let mut locales = vec![];
+        let mut errors = vec![];
+        for (path, locale) in paths {
+            match Locale::new(path, locale, format) {
+                Ok(locale) => locales.push(Rc::new(RefCell::new(locale))),
+                Err(err) => errors.push(err),
+            }
+        }
+        Error::collect(errors)?;
+        Ok(locales)
+    }
+
+    fn check_empty_values(&self, top_locale: &Rc<Key>, key_path: &mut KeyPath) -> Result<()> {
+        fn check_value(
+            top_locale: &Rc<Key>,
+            value: &ParsedValue,
+            key_path: &mut KeyPath,
+        ) -> Result<()> {
+            match value {
+                ParsedValue::Subkeys(subkeys) => {
+                    subkeys.borrow().check_empty_values(top_locale, key_path)
+                }
+                ParsedValue::Plural(plurals) => {
+                    for value in plurals.values() {
+                        check_value(top_locale, value, key_path)?;
+                    }
+                    Ok(())
+                }
+                ParsedValue::Gender(genders) => {
+                    for value in genders.values() {
+                        check_value(top_locale, value, key_path)?;
+                    }
+                    Ok(())
+                }
+                value if value.is_blank() => Err(Error::EmptyValue {
+                    locale: Rc::clone(top_locale),
+                    key_path: key_path.clone(),
+                }),
+                _ => Ok(()),
+            }
+        }
+
+        for (key, value) in &self.keys {
+            key_path.push_key(Rc::clone(key));
+            check_value(top_locale, value, key_path)?;
+            key_path.pop_key();
+        }
+        Ok(())
+    }
+
+    // a key of the default locale missing in this locale is an error, except the passthrough keys.
+    fn check_complete(
+        &self,
+        default_values: &Self,
+        top_locale: &Rc<Key>,
+        key_path: &mut KeyPath,
+    ) -> Result<()> {
+        let mut errors = vec![];
+        for (key, default_value) in &default_values.keys {
+            if default_values.passthrough.contains(key) {
+                continue;
+            }
+            key_path.push_key(Rc::clone(key));
+            match (self.keys.get(key).map(|value| &**value), &**default_value) {
+                (None, _) => errors.push(Error::MissingKeyInLocale {
+                    locale: Rc::clone(top_locale),
+                    key_path: key_path.clone(),
+                }),
+                (Some(ParsedValue::Subkeys(subkeys)), ParsedValue::Subkeys(default_subkeys)) => {
+                    let result = subkeys.borrow().check_complete(
+                        &default_subkeys.borrow(),
+                        top_locale,
+                        key_path,
+                    );
+                    errors.extend(result.err());
+                }
+                _ => {}
+            }
+            key_path.pop_key();
+        }
+        Error::collect(errors)
+    }
+
+    // the keys missing in the default locale are reported as surplus keys by the merge, they are not checked here.
+    fn check_plural_branches(
+        &self,
+        default_values: &Self,
+        top_locale: &Rc<Key>,
+        key_path: &mut KeyPath,
+        require_count: bool,
+    ) -> Result<()> {
+        // `{{ count }}` is the count of the plural itself, `{{ gender }}` is declared by a gender.
+        fn is_declared(interpolation: &InterpolateKey, declared: &HashSet<InterpolateKey>) -> bool {
+            if interpolation.is_count_variable() {
+                return true;
+            }
+            if interpolation.is_gender_variable() && declared.contains(&InterpolateKey::Gender) {
+                return true;
+            }
+            declared.contains(interpolation)
+        }
+
+        fn check_value(
+            value: &ParsedValue,
+            declared: &HashSet<InterpolateKey>,
+            top_locale: &Rc<Key>,
+            key_path: &KeyPath,
+            require_count: bool,
+            errors: &mut Vec<Error>,
+        ) {
+            match value {
+                ParsedValue::Plural(plurals) => {
+                    for (branch, exact, value) in plurals.branches() {
+                        let used = value.get_keys().unwrap_or_default();
+                        let has_count = used.iter().any(InterpolateKey::is_count_variable);
+                        for interpolation in used {
+                            if !is_declared(&interpolation, declared) {
+                                errors.push(Error::UndeclaredPluralVariable {
+                                    locale: Rc::clone(top_locale),
+                                    key_path: key_path.clone(),
+                                    branch: branch.clone(),
+                                    name: interpolation.get_real_name().to_string(),
+                                });
+                            }
+                        }
+                        if require_count && !exact && !has_count {
+                            errors.push(Error::MissingPluralCount {
+                                locale: Rc::clone(top_locale),
+                                key_path: key_path.clone(),
+                                branch,
+                            });
+                        }
+                    }
+                }
+                ParsedValue::Gender(genders) => {
+                    for value in genders.values() {
+                        check_value(value, declared, top_locale, key_path, require_count, errors);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut errors = vec![];
+        for (key, value) in &self.keys {
+            let Some(default_value) = default_values.keys.get(key) else {
+                continue;
+            };
+            key_path.push_key(Rc::clone(key));
+            match (&**value, &**default_value) {
+                (ParsedValue::Subkeys(subkeys), ParsedValue::Subkeys(default_subkeys)) => {
+                    let result = subkeys.borrow().check_plural_branches(
+                        &default_subkeys.borrow(),
+                        top_locale,
+                        key_path,
+                        require_count,
+                    );
+                    errors.extend(result.err());
+                }
+                (value, default_value) => {
+                    let declared = default_value.get_keys().unwrap_or_default();
+                    check_value(
+                        value,
+                        &declared,
+                        top_locale,
+                        key_path,
+                        require_count,
+                        &mut errors,
+                    );
+                }
+            }
+            key_path.pop_key();
+        }
+        Error::collect(errors)
     }
 
     pub fn to_builder_keys(&self) -> BuildersKeysInner {
@@ -112,34 +650,133 @@ impl Locale {
         top_locale: Rc<Key>,
         key_path: &mut KeyPath,
     ) -> Result<()> {
+        let mut errors = vec![];
         for (key, keys) in &mut keys.0 {
             let default_value = default_values.keys.get(key).unwrap();
             key_path.push_key(Rc::clone(key));
             let locale = self.name.clone();
+            // passthrough keys are not expected in the other locales, they share the value of the default locale.
+            if default_values.passthrough.contains(key) {
+                let previous = self.keys.insert(Rc::clone(key), Rc::clone(default_value));
+                // copying the value of the default locale is harmless, only a different value is ignored.
+                if previous.is_some_and(|previous| previous != *default_value) {
+                    emit_warning(Warning::TranslatedPassthrough {
+                        locale: top_locale.clone(),
+                        key_path: key_path.clone(),
+                    });
+                }
+                key_path.pop_key();
+                continue;
+            }
             let value_entry = self.keys.entry(Rc::clone(key));
             let value = value_entry.or_insert_with(|| {
+                FALLBACK_KEYS.with(|fallback_keys| {
+                    fallback_keys
+                        .borrow_mut()
+                        .entry(top_locale.name.clone())
+                        .or_default()
+                        .push(key_path.path())
+                });
                 emit_warning(Warning::MissingKey {
                     locale: top_locale.clone(),
                     key_path: key_path.clone(),
                 });
                 Rc::clone(default_value)
             });
-            value.merge(keys, default_locale, default_value, locale, key_path)?;
+            if !Rc::ptr_eq(value, default_value) {
+                Self::compare_interpolations(default_value, value, &top_locale, key_path);
+            }
+            if let Err(err) = value.merge(keys, default_locale, default_value, locale, key_path) {
+                errors.push(err);
+            }
             key_path.pop_key();
         }
 
         // reverse key comparaison
         for key in self.keys.keys() {
-            if keys.0.get(key).is_none() {
+            if !keys.0.contains_key(key) {
                 key_path.push_key(Rc::clone(key));
                 emit_warning(Warning::SurplusKey {
                     locale: top_locale.clone(),
                     key_path: key_path.clone(),
                 });
+                key_path.pop_key();
             }
         }
 
-        Ok(())
+        Error::collect(errors)
+    }
+
+    // a locale can legitimately drop or add an interpolation ("{{ count }} items" -> "plusieurs éléments"),
+    // the builder is generated from the union of all locales anyway, so only warn about it.
+    fn compare_interpolations(
+        default_value: &ParsedValue,
+        value: &ParsedValue,
+        top_locale: &Rc<Key>,
+        key_path: &KeyPath,
+    ) {
+        fn interpolations(value: &ParsedValue) -> HashSet<InterpolateKey> {
+            // a plural count and a `{{ count }}` variable both end up as `var_count`, compare them as the same.
+            value
+                .get_keys()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|key| match key {
+                    InterpolateKey::Count(_) => {
+                        InterpolateKey::Variable(Rc::new(Key::new("var_count").unwrap()))
+                    }
+                    key => key,
+                })
+                .collect()
+        }
+
+        fn plural_type(value: &ParsedValue) -> Option<PluralType> {
+            value.get_keys()?.into_iter().find_map(|key| match key {
+                InterpolateKey::Count(plural_type) => Some(plural_type),
+                _ => None,
+            })
+        }
+
+        fn has_count_variable(value: &ParsedValue) -> bool {
+            value
+                .get_keys()
+                .is_some_and(|keys| keys.iter().any(InterpolateKey::is_count_variable))
+        }
+
+        let plural_in_default = match (plural_type(default_value), plural_type(value)) {
+            (Some(plural_type), None) if has_count_variable(value) => Some((true, plural_type)),
+            (None, Some(plural_type)) if has_count_variable(default_value) => {
+                Some((false, plural_type))
+            }
+            _ => None,
+        };
+        if let Some((plural_in_default, plural_type)) = plural_in_default {
+            emit_warning(Warning::CountAsVariable {
+                locale: Rc::clone(top_locale),
+                key_path: key_path.clone(),
+                plural_in_default,
+                plural_type,
+            });
+        }
+
+        let default_keys = interpolations(default_value);
+        let keys = interpolations(value);
+
+        for interpolation in default_keys.difference(&keys) {
+            emit_warning(Warning::MissingInterpolation {
+                locale: Rc::clone(top_locale),
+                key_path: key_path.clone(),
+                interpolation: interpolation.clone(),
+            });
+        }
+
+        for interpolation in keys.difference(&default_keys) {
+            emit_warning(Warning::SurplusInterpolation {
+                locale: Rc::clone(top_locale),
+                key_path: key_path.clone(),
+                interpolation: interpolation.clone(),
+            });
+        }
     }
 
     pub fn check_locales_inner(
@@ -156,17 +793,22 @@ impl Locale {
 
         let mut key_path = KeyPath::new(namespace);
 
+        let mut errors = vec![];
         for locale in locales {
             let top_locale = locale.borrow().name.clone();
-            locale.borrow_mut().merge(
+            let result = locale.borrow_mut().merge(
                 &mut default_keys,
                 default_locale_name,
                 &default_locale.borrow(),
                 top_locale,
                 &mut key_path,
-            )?;
+            );
+            if let Err(err) = result {
+                errors.push(err);
+            }
         }
 
+        Error::collect(errors)?;
         Ok(default_keys)
     }
 
@@ -174,13 +816,19 @@ impl Locale {
         match locales {
             LocalesOrNamespaces::NameSpaces(namespaces) => {
                 let mut keys = HashMap::with_capacity(namespaces.len());
+                let mut errors = vec![];
                 for namespace in &namespaces {
-                    let k = Self::check_locales_inner(
+                    match Self::check_locales_inner(
                         &namespace.locales,
                         Some(Rc::clone(&namespace.key)),
-                    )?;
-                    keys.insert(Rc::clone(&namespace.key), k);
+                    ) {
+                        Ok(k) => {
+                            keys.insert(Rc::clone(&namespace.key), k);
+                        }
+                        Err(err) => errors.push(err),
+                    }
                 }
+                Error::collect(errors)?;
                 Ok(BuildersKeys::NameSpaces { namespaces, keys })
             }
             LocalesOrNamespaces::Locales(locales) => {
@@ -199,27 +847,114 @@ pub enum LocaleValue {
     },
 }
 
+// the name of the locale or of the subkeys, the depth of the subkeys and the locale of the file.
 #[derive(Debug, Clone)]
-pub struct LocaleSeed(pub Rc<Key>);
+pub struct LocaleSeed(pub Rc<Key>, pub usize, pub Rc<Key>);
+
+// "@key" entries are either the description or a map with "description", "deprecated", "passthrough" and "source",
+// "deprecated" being `true` or the key replacing it.
+fn parse_key_metadata(
+    locale: &mut Locale,
+    key: Rc<Key>,
+    metadata: serde_json::Value,
+) -> Result<()> {
+    use serde_json::Value;
+
+    let invalid = || Error::InvalidKeyMetadata(Rc::clone(&key));
+    match metadata {
+        Value::String(description) => {
+            locale.descriptions.insert(key, description);
+        }
+        Value::Object(mut metadata) => {
+            match metadata.remove("description") {
+                None => {}
+                Some(Value::String(description)) => {
+                    locale.descriptions.insert(Rc::clone(&key), description);
+                }
+                Some(_) => return Err(invalid()),
+            }
+            match metadata.remove("deprecated") {
+                None | Some(Value::Bool(false)) => {}
+                Some(Value::Bool(true)) => {
+                    locale.deprecations.insert(Rc::clone(&key), None);
+                }
+                Some(Value::String(replacement)) => {
+                    locale
+                        .deprecations
+                        .insert(Rc::clone(&key), Some(replacement));
+                }
+                Some(_) => return Err(invalid()),
+            }
+            match metadata.remove("passthrough") {
+                None | Some(Value::Bool(false)) => {}
+                Some(Value::Bool(true)) => {
+                    locale.passthrough.insert(Rc::clone(&key));
+                }
+                Some(_) => return Err(invalid()),
+            }
+            match metadata.remove("source") {
+                None => {}
+                Some(Value::String(source)) => {
+                    locale.sources.insert(Rc::clone(&key), source);
+                }
+                Some(_) => return Err(invalid()),
+            }
+            if !metadata.is_empty() {
+                return Err(invalid());
+            }
+        }
+        _ => return Err(invalid()),
+    }
+    Ok(())
+}
 
 impl<'de> serde::de::Visitor<'de> for LocaleSeed {
-    type Value = HashMap<Rc<Key>, Rc<ParsedValue>>;
+    type Value = Locale;
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
-        let mut keys = HashMap::new();
+        let mut locale = Locale {
+            name: self.0,
+            keys: HashMap::new(),
+            descriptions: HashMap::new(),
+            deprecations: HashMap::new(),
+            passthrough: HashSet::new(),
+            sources: HashMap::new(),
+            meta: FileMeta::default(),
+        };
 
-        while let Some(locale_key) = map.next_key()? {
+        while let Some(locale_key) = map.next_key::<String>()? {
+            // "@meta" configures the whole file, it is only reserved at the top level.
+            if locale_key == "@meta" && self.1 == 0 {
+                let meta = map.next_value::<serde_json::Value>()?;
+                locale.meta = FileMeta::parse(meta).map_err(serde::de::Error::custom)?;
+                continue;
+            }
+            if let Some(described_key) = locale_key.strip_prefix('@') {
+                let described_key =
+                    Key::try_new(described_key).map_err(serde::de::Error::custom)?;
+                let metadata = map.next_value::<serde_json::Value>()?;
+                parse_key_metadata(&mut locale, Rc::new(described_key), metadata)
+                    .map_err(serde::de::Error::custom)?;
+                continue;
+            }
+            let locale_key = Rc::new(Key::try_new(&locale_key).map_err(serde::de::Error::custom)?);
+            // serde_json keep the last value of duplicated keys, it almost always indicate a bad merge so error out.
+            if locale.keys.contains_key(&locale_key) {
+                return Err(serde::de::Error::custom(Error::DuplicateKey(locale_key)));
+            }
             let value = map.next_value_seed(ParsedValueSeed {
+                locale: &self.2,
                 key: &locale_key,
                 in_plural: false,
+                depth: self.1,
             })?;
-            keys.insert(locale_key, Rc::new(value));
+            locale.keys.insert(locale_key, Rc::new(value));
         }
 
-        Ok(keys)
+        Ok(locale)
     }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -230,14 +965,324 @@ impl<'de> serde::de::Visitor<'de> for LocaleSeed {
     }
 }
 
-impl<'a: 'de, 'de> serde::de::DeserializeSeed<'de> for LocaleSeed {
+impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed {
     type Value = Locale;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let keys = deserializer.deserialize_map(self.clone())?;
-        Ok(Locale { name: self.0, keys })
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_locale(json: &str) -> serde_json::Result<Locale> {
+        let locale = Rc::new(Key::new("en").unwrap());
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        LocaleSeed(Rc::clone(&locale), 0, locale).deserialize(&mut deserializer)
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let err = parse_locale(r#"{ "a": "first", "b": "b", "a": "second" }"#).unwrap_err();
+
+        assert!(err.to_string().starts_with("duplicate key \"a\""));
+    }
+
+    #[test]
+    fn duplicate_subkeys() {
+        let err = parse_locale(r#"{ "a": { "b": "first", "b": "second" } }"#).unwrap_err();
+
+        assert!(err.to_string().starts_with("duplicate key \"b\""));
+    }
+
+    #[test]
+    fn descriptions() {
+        let locale =
+            parse_locale(r#"{ "greeting": "Hi", "@greeting": "Shown on the home page" }"#).unwrap();
+
+        assert_eq!(locale.keys.len(), 1);
+        assert_eq!(
+            locale
+                .descriptions
+                .get(&Key::new("greeting").unwrap())
+                .unwrap(),
+            "Shown on the home page"
+        );
+    }
+
+    #[test]
+    fn deprecations() {
+        let locale = parse_locale(
+            r#"{ "old": "Old", "@old": { "deprecated": "new" }, "legacy": "Legacy", "@legacy": { "description": "Unused", "deprecated": true } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            locale.deprecations.get(&Key::new("old").unwrap()),
+            Some(&Some("new".to_string()))
+        );
+        assert_eq!(
+            locale.deprecations.get(&Key::new("legacy").unwrap()),
+            Some(&None)
+        );
+        assert_eq!(locale.descriptions.len(), 1);
+
+        let locale =
+            parse_locale(r#"{ "sku": "ACME-42", "@sku": { "passthrough": true } }"#).unwrap();
+
+        assert!(locale.passthrough.contains(&Key::new("sku").unwrap()));
+
+        let locale =
+            parse_locale(r#"{ "title": "Titre", "@title": { "source": "af63bd4c8601b7be" } }"#)
+                .unwrap();
+
+        assert_eq!(
+            locale
+                .sources
+                .get(&Key::new("title").unwrap())
+                .map(String::as_str),
+            Some("af63bd4c8601b7be")
+        );
+
+        let err = parse_locale(r#"{ "old": "Old", "@old": { "deprecated": 1 } }"#).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("invalid metadata for key \"old\""));
+    }
+
+    #[test]
+    fn keyword_keys() {
+        let locale = parse_locale(r#"{ "type": "type", "move": { "loop": "loop" } }"#).unwrap();
+        let key = locale.keys.keys().find(|key| key.name == "type").unwrap();
+
+        assert_eq!(key.ident.to_string(), "r#type");
+
+        let err = parse_locale(r#"{ "self": "self" }"#).unwrap_err();
+
+        assert!(err.to_string().contains("even as a raw identifier"));
+    }
+
+    #[test]
+    fn nfc_keys() {
+        let locale = parse_locale("{ \"cafe\u{301}\": \"Caf\u{e9}\" }").unwrap();
+
+        assert!(locale.keys.contains_key(&Key::new("caf\u{e9}").unwrap()));
+
+        let err = parse_locale("{ \"caf\u{e9}\": \"a\", \"cafe\u{301}\": \"b\" }").unwrap_err();
+
+        assert!(err.to_string().starts_with("duplicate key \"caf\u{e9}\""));
+    }
+
+    #[test]
+    fn invisible_chars_in_keys() {
+        let err = parse_locale("{ \"hello\u{200B}\": \"world\" }").unwrap_err();
+
+        assert!(err.to_string().contains("invisible character U+200B"));
+    }
+
+    #[test]
+    fn empty_values() {
+        let locale =
+            parse_locale(r#"{ "a": "a", "b": { "c": [["zero", 0], [" ", "_"]] } }"#).unwrap();
+        let err = locale
+            .check_empty_values(&locale.name, &mut KeyPath::new(None))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "empty value in locale \"en\" at key \"b.c\", strict mode requires every value to contain some text");
+
+        let locale = parse_locale(r#"{ "a": "a", "b": "<b> </b>" }"#).unwrap();
+
+        assert!(locale
+            .check_empty_values(&locale.name, &mut KeyPath::new(None))
+            .is_ok());
+    }
+
+    #[test]
+    fn complete_locale() {
+        let default_locale = parse_locale(
+            r#"{ "title": "Terms", "sku": "T-1", "@sku": { "passthrough": true }, "sections": { "a": "A", "b": "B" } }"#,
+        )
+        .unwrap();
+        let locale =
+            parse_locale(r#"{ "title": "Conditions", "sections": { "a": "A" } }"#).unwrap();
+        let mut key_path = KeyPath::new(Some(Rc::new(Key::new("legal").unwrap())));
+        let err = locale
+            .check_complete(&default_locale, &locale.name, &mut key_path)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "locale \"en\" is missing key \"legal::sections.b\", every locale must have all the keys of the namespaces listed in \"complete-namespaces\" and of the files marked \"complete\" in their \"@meta\" entry");
+
+        let locale =
+            parse_locale(r#"{ "title": "Conditions", "sections": { "a": "A", "b": "B" } }"#)
+                .unwrap();
+
+        assert!(locale
+            .check_complete(&default_locale, &locale.name, &mut key_path)
+            .is_ok());
+    }
+
+    #[test]
+    fn file_meta() {
+        let locale = parse_locale(
+            r#"{ "@meta": { "strict": true, "complete": false }, "title": "Title", "nested": { "@meta": "A key" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            locale.meta,
+            FileMeta {
+                strict: true,
+                complete: false
+            }
+        );
+        assert_eq!(locale.keys.len(), 2);
+        let nested = locale.keys.keys().find(|key| key.name == "nested").unwrap();
+        let ParsedValue::Subkeys(nested) = &*locale.keys[nested] else {
+            panic!("expected subkeys");
+        };
+        assert_eq!(nested.borrow().meta, FileMeta::default());
+        assert_eq!(nested.borrow().descriptions.len(), 1);
+
+        let err = parse_locale(r#"{ "@meta": { "delimiters": "[[ ]]" } }"#).unwrap_err();
+
+        assert!(err.to_string().starts_with(
+            "invalid option \"delimiters\" in the \"@meta\" entry, expected \"strict\" or \"complete\" with a boolean"
+        ));
+    }
+
+    #[test]
+    fn plural_branches() {
+        let default_locale = parse_locale(
+            r#"{ "items": [["no items", 0], ["{{ count }} items for {{ name }}", "_"]] }"#,
+        )
+        .unwrap();
+        let locale = parse_locale(
+            r#"{ "items": [["aucun élément", 0], ["un élément", 1], ["{{ count }} éléments pour {{ nom }}", "_"]] }"#,
+        )
+        .unwrap();
+        let mut key_path = KeyPath::new(None);
+        let err = locale
+            .check_plural_branches(&default_locale, &locale.name, &mut key_path, true)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "plural branch \"_\" of key \"items\" in locale \"en\" uses \"nom\", which the key doesn't have in the default locale");
+
+        let locale = parse_locale(
+            r#"{ "items": [["aucun", 0], ["quelques-uns", "1..=4"], ["{{ count }}", "_"]] }"#,
+        )
+        .unwrap();
+        let err = locale
+            .check_plural_branches(&default_locale, &locale.name, &mut key_path, true)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "plural branch \"1..=4\" of key \"items\" in locale \"en\" doesn't display {{ count }}, \"require-plural-count\" requires it in every branch that isn't an exact number");
+        assert!(locale
+            .check_plural_branches(&default_locale, &locale.name, &mut key_path, false)
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg(not(feature = "supress_key_warnings"))]
+    fn translated_passthrough() {
+        use super::super::warning::WARNINGS;
+
+        let default_locale =
+            parse_locale(r#"{ "sku": "ACME-42", "@sku": { "passthrough": true } }"#).unwrap();
+        let check = |json: &str| {
+            let locales = [default_locale.clone(), parse_locale(json).unwrap()]
+                .map(|locale| Rc::new(RefCell::new(locale)));
+            WARNINGS.with(|warnings| warnings.borrow_mut().clear());
+            Locale::check_locales_inner(&locales, None).unwrap();
+            WARNINGS.with(|warnings| warnings.borrow_mut().len())
+        };
+
+        assert_eq!(check(r#"{ "sku": "ACME-42" }"#), 0);
+        assert_eq!(check(r#"{}"#), 0);
+        assert_eq!(check(r#"{ "sku": "ACME-43" }"#), 1);
+    }
+
+    #[test]
+    fn too_deeply_nested_subkeys() {
+        let json = format!("{}\"value\"{}", r#"{ "a": "#.repeat(50), "}".repeat(50));
+        let err = parse_locale(&json).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("value too deeply nested at key \"a\""));
+    }
+
+    #[test]
+    fn plural_count_variable() {
+        let locale =
+            parse_locale(r#"{ "a": [["zero", 0], ["{{ count }} {{ other }}", "_"]] }"#).unwrap();
+        let keys = locale.keys.values().next().unwrap().get_keys().unwrap();
+
+        assert!(keys
+            .iter()
+            .any(|key| matches!(key, InterpolateKey::Count(PluralType::I64))));
+        assert!(!keys.iter().any(InterpolateKey::is_count_variable));
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn select_prefix() {
+        let prefix = KeyPrefix::new("checkout.cart", false).unwrap();
+        let mut locale = parse_locale(
+            r#"{ "home": "Home", "checkout": { "cart": { "total": "Total", "@total": "Sum of the items" } } }"#,
+        )
+        .unwrap();
+
+        assert!(locale.select_subtree(&prefix.path));
+        assert_eq!(locale.keys.len(), 1);
+        assert!(locale.keys.contains_key(&Key::new("total").unwrap()));
+        assert_eq!(locale.descriptions.len(), 1);
+
+        let mut locale = parse_locale(r#"{ "checkout": "Checkout" }"#).unwrap();
+        assert!(!locale.select_subtree(&prefix.path));
+
+        let prefix = KeyPrefix::new("checkout", true).unwrap();
+        assert_eq!(
+            prefix.namespace,
+            Some(Rc::new(Key::new("checkout").unwrap()))
+        );
+        assert!(prefix.path.is_empty());
+        assert!(KeyPrefix::new("checkout::cart", false).is_err());
+        assert!(KeyPrefix::new("", false).is_err());
+    }
+
+    fn parse_fixture(format: FileFormat) -> Locale {
+        let locale = Rc::new(Key::new("en").unwrap());
+        let locales_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/locales");
+        let path = Locale::file_path(locales_dir, &locale, format);
+        Locale::new(path, locale, format).unwrap()
+    }
+
+    #[test]
+    fn file_formats() {
+        let locale = parse_fixture(FileFormat::Json);
+        let get = |key: &str| &**locale.keys.get(&Key::new(key).unwrap()).unwrap();
+
+        assert_eq!(locale.keys.len(), 3);
+        assert!(matches!(get("click_count"), ParsedValue::Plural(_)));
+        let ParsedValue::Subkeys(subkeys) = get("subkeys") else {
+            panic!("expected subkeys, got {:?}", get("subkeys"));
+        };
+        assert!(matches!(
+            subkeys
+                .borrow()
+                .keys
+                .get(&Key::new("nested").unwrap())
+                .map(|value| &**value),
+            Some(ParsedValue::Subkeys(_))
+        ));
+
+        assert_eq!(parse_fixture(FileFormat::Yaml), locale);
+        assert_eq!(parse_fixture(FileFormat::Toml), locale);
     }
 }
@@ -1,16 +1,34 @@
-use std::{cell::RefCell, collections::HashMap, ops::Not, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    ops::Not,
+    rc::Rc,
+};
 
+pub mod casing;
+pub mod catalog;
 pub mod cfg_file;
 pub mod error;
+pub mod format;
+pub mod freshness;
+pub mod gender;
 pub mod interpolate;
 pub mod key;
 pub mod locale;
 pub mod parsed_value;
 pub mod plural;
+pub mod provenance;
+pub mod snapshot;
+pub mod style;
+pub mod tenant;
+pub mod transform;
+pub mod typed_keys;
+pub mod usage;
+pub mod validation;
 pub mod warning;
 
 use cfg_file::ConfigFile;
-use error::Result;
+use error::{Error, Result};
 use interpolate::{create_empty_type, Interpolation};
 use key::Key;
 use locale::{Locale, LocaleValue};
@@ -22,15 +40,155 @@ use self::{
     warning::generate_warnings,
 };
 
-pub fn load_locales() -> Result<TokenStream> {
-    let cfg_file = ConfigFile::new()?;
-    let locales = LocalesOrNamespaces::new(&cfg_file)?;
+thread_local! {
+    // set by `load_catalogs!`, the generated code then only depends on `leptos_i18n_core`.
+    pub static CORE_ONLY: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The path of the `leptos_i18n_core` crate in the generated code, re-exported by `leptos_i18n` for `load_locales!`.
+pub fn core_path() -> TokenStream {
+    if CORE_ONLY.with(Cell::get) {
+        quote!(leptos_i18n_core)
+    } else {
+        quote!(leptos_i18n::__private::leptos_i18n_core)
+    }
+}
+
+/// The arguments of the `load_locales!` macro, overriding the configuration of the manifest.
+#[derive(Default)]
+pub struct LoadLocalesArgs {
+    pub locales_dir: Option<String>,
+    pub prefix: Option<String>,
+}
+
+impl syn::parse::Parse for LoadLocalesArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = LoadLocalesArgs::default();
+        while !input.is_empty() {
+            let name = input.parse::<syn::Ident>()?;
+            input.parse::<syn::Token![=]>()?;
+            match name.to_string().as_str() {
+                "locales_dir" => {
+                    let value = input.parse::<syn::LitStr>()?;
+                    args.locales_dir = Some(value.value());
+                }
+                "prefix" => {
+                    let value = input.parse::<syn::LitStr>()?;
+                    args.prefix = Some(value.value());
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "unknown argument `{}`, expected `locales_dir` or `prefix`",
+                            name
+                        ),
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+pub fn load_locales(mut args: LoadLocalesArgs) -> Result<TokenStream> {
+    CORE_ONLY.with(|core_only| core_only.set(false));
+    let cfg_file = ConfigFile::new(args.locales_dir.take())?;
+    let locales_dir = cfg_file.locales_dir.to_string();
+    let format = cfg_file.file_format;
+    load_locales_inner(cfg_file, args).map_err(|err| err.group_by_file(&locales_dir, format))
+}
+
+pub fn load_catalogs(mut args: LoadLocalesArgs) -> Result<TokenStream> {
+    CORE_ONLY.with(|core_only| core_only.set(true));
+    let cfg_file = ConfigFile::new(args.locales_dir.take())?;
+    let locales_dir = cfg_file.locales_dir.to_string();
+    let format = cfg_file.file_format;
+    load_catalogs_inner(cfg_file, args).map_err(|err| err.group_by_file(&locales_dir, format))
+}
+
+// parse and check the locale files, return the keys and the keys outdated in each locale.
+fn check_locale_files(
+    cfg_file: &ConfigFile,
+    prefix: Option<&str>,
+) -> Result<(BuildersKeys, BTreeMap<String, Vec<String>>)> {
+    parsed_value::BIDI_ISOLATION.with(|isolation| isolation.set(cfg_file.bidi_isolation));
+    let mut locales = LocalesOrNamespaces::new(cfg_file)?;
+    // the sources are compared to the whole files, a part of the catalog selected with a prefix is not checked.
+    let outdated_keys = match prefix {
+        Some(prefix) => {
+            locales = locales.select_prefix(prefix)?;
+            BTreeMap::new()
+        }
+        None => freshness::find_outdated_keys(cfg_file, &locales),
+    };
+
+    let mut errors = vec![];
+    errors.extend(transform::apply_transformers(cfg_file, &locales).err());
+    errors.extend(locales.check_empty_values(cfg_file.strict).err());
+    errors.extend(
+        locales
+            .check_complete_namespaces(&cfg_file.complete_namespaces)
+            .err(),
+    );
+    if cfg_file.check_plural_branches {
+        errors.extend(
+            locales
+                .check_plural_branches(cfg_file.require_plural_count)
+                .err(),
+        );
+    }
+
+    let keys = match Locale::check_locales(locales) {
+        Ok(keys) => keys,
+        Err(err) => {
+            errors.push(err);
+            return Err(Error::Multiple(errors));
+        }
+    };
+    Error::collect(errors)?;
+
+    if cfg_file.check_plural_types {
+        keys.check_plural_types();
+    }
+
+    Ok((keys, outdated_keys))
+}
+
+fn load_locales_inner(cfg_file: ConfigFile, args: LoadLocalesArgs) -> Result<TokenStream> {
+    let (keys, outdated_keys) = check_locale_files(&cfg_file, args.prefix.as_deref())?;
+
+    let catalogs = cfg!(feature = "catalog").then(|| {
+        let schema = catalog::create_schema(&cfg_file, &keys);
+        catalog::create_catalogs(&cfg_file, &keys, schema)
+    });
+
+    let snapshot = cfg!(feature = "snapshot").then(|| snapshot::create_snapshot(&keys));
+
+    let typed_keys = cfg!(feature = "typed_keys").then(|| typed_keys::create_typed_keys(&keys));
 
-    let keys = Locale::check_locales(locales)?;
+    let usage_report = cfg!(feature = "usage_stats").then(|| usage::create_usage_report(&keys));
 
+    let fallback_keys = locale::FALLBACK_KEYS.with(|fallback_keys| fallback_keys.take());
+
+    let provenance = cfg!(feature = "provenance")
+        .then(|| provenance::create_provenance(&cfg_file, &keys, &fallback_keys));
+
+    let (tenants, tenant_match_arms) =
+        tenant::create_tenants(&cfg_file, &keys, args.prefix.as_deref())?.unzip();
+
+    let completeness = provenance::create_completeness(&cfg_file, &keys, &fallback_keys);
     let locale_type = create_locale_type(keys);
-    let locale_variants = create_locales_enum(&cfg_file);
-    let locales = create_locales_type(&cfg_file);
+    let locale_styles = style::create_locale_styles(&cfg_file)?;
+    let locale_variants = create_locales_enum(&cfg_file, locale_styles, completeness);
+    let fallback_keys = create_fallback_keys(&cfg_file, &fallback_keys);
+    let outdated_keys = freshness::create_outdated_keys(&cfg_file, &outdated_keys);
+    let locales = create_locales_type(&cfg_file, tenant_match_arms);
+
+    let validation_messages = validation::create_validation_messages(&cfg_file)?;
 
     let warnings = generate_warnings();
 
@@ -40,8 +198,26 @@ pub fn load_locales() -> Result<TokenStream> {
 
             #locale_variants
 
+            #fallback_keys
+
+            #outdated_keys
+
             #locale_type
 
+            #tenants
+
+            #catalogs
+
+            #validation_messages
+
+            #snapshot
+
+            #typed_keys
+
+            #usage_report
+
+            #provenance
+
             #[inline]
             pub fn use_i18n() -> leptos_i18n::I18nContext<Locales> {
                 leptos_i18n::use_i18n_context()
@@ -52,18 +228,65 @@ pub fn load_locales() -> Result<TokenStream> {
                 leptos_i18n::provide_i18n_context()
             }
 
-            pub use leptos_i18n::t;
+            pub use leptos_i18n::{derive_translation, t, td, td_string};
 
             #warnings
         }
     })
 }
 
-fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
+// only the locale enum and the catalogs, for the crates using `leptos_i18n_core` alone.
+// the catalogs are always embedded, they are the translations such a crate can look up.
+fn load_catalogs_inner(cfg_file: ConfigFile, args: LoadLocalesArgs) -> Result<TokenStream> {
+    let (keys, outdated_keys) = check_locale_files(&cfg_file, args.prefix.as_deref())?;
+
+    let fallback_keys = locale::FALLBACK_KEYS.with(|fallback_keys| fallback_keys.take());
+
+    let completeness = provenance::create_completeness(&cfg_file, &keys, &fallback_keys);
+    let locale_styles = style::create_locale_styles(&cfg_file)?;
+    let locale_variants = create_locales_enum(&cfg_file, locale_styles, completeness);
+    let fallback_keys = create_fallback_keys(&cfg_file, &fallback_keys);
+    let outdated_keys = freshness::create_outdated_keys(&cfg_file, &outdated_keys);
+
+    let schema = catalog::create_schema(&cfg_file, &keys);
+    let catalogs = catalog::create_catalogs(&cfg_file, &keys, schema);
+
+    let warnings = generate_warnings();
+
+    Ok(quote! {
+        pub mod i18n {
+            #locale_variants
+
+            #fallback_keys
+
+            #outdated_keys
+
+            #catalogs
+
+            #warnings
+        }
+    })
+}
+
+fn create_locales_enum(
+    cfg_file: &ConfigFile,
+    locale_styles: Option<TokenStream>,
+    completeness: Option<TokenStream>,
+) -> TokenStream {
     let ConfigFile {
-        default, locales, ..
+        default,
+        locales,
+        priority,
+        ..
     } = cfg_file;
 
+    // the locales listed in "priority" come first, then the others in declaration order.
+    let priority = priority
+        .iter()
+        .chain(locales.iter().filter(|locale| !priority.contains(locale)))
+        .map(|key| &key.ident)
+        .collect::<Vec<_>>();
+
     let as_str_match_arms = locales
         .iter()
         .map(|key| (&key.ident, &key.name))
@@ -76,14 +299,62 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
         .map(|(variant, locale)| quote!(#locale => Some(LocaleEnum::#variant)))
         .collect::<Vec<_>>();
 
-    let derives = if cfg!(feature = "serde") {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)])
-    } else {
-        quote!(#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)])
-    };
+    let core_crate = core_path();
+
+    // the locale is (de)serialized as its name, e.g. "en-US", so it is stable across renames of the variants.
+    let core_only = CORE_ONLY.with(Cell::get);
+    let serde_impls = (cfg!(feature = "serde") && !core_only).then(|| {
+        quote! {
+            impl leptos_i18n::__private::serde::Serialize for LocaleEnum {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: leptos_i18n::__private::serde::Serializer,
+                {
+                    serializer.serialize_str(#core_crate::LocaleVariant::as_str(*self))
+                }
+            }
+
+            impl<'de> leptos_i18n::__private::serde::Deserialize<'de> for LocaleEnum {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: leptos_i18n::__private::serde::Deserializer<'de>,
+                {
+                    let s = <String as leptos_i18n::__private::serde::Deserialize>::deserialize(deserializer)?;
+                    <Self as core::str::FromStr>::from_str(&s)
+                        .map_err(<D::Error as leptos_i18n::__private::serde::de::Error>::custom)
+                }
+            }
+        }
+    });
+
+    let locale_names = locales.iter().map(|key| &key.name).collect::<Vec<_>>();
+    let locales_count = locales.len();
+    let default_name = &default.name;
+
+    // plain constants, for build scripts, tests and code not using leptos.
+    let locale_codes = locales.iter().map(|key| {
+        let ident = &key.ident;
+        let name = &key.name;
+        quote!(pub const #ident: &str = #name;)
+    });
 
     quote! {
-        #derives
+        /// The number of supported locales.
+        pub const LOCALES_COUNT: usize = #locales_count;
+
+        /// The names of the supported locales, the default one first.
+        pub const LOCALE_NAMES: &[&str] = &[#(#locale_names,)*];
+
+        /// The name of the default locale.
+        pub const DEFAULT_LOCALE: &str = #default_name;
+
+        /// The name of each supported locale, named like the variants of `LocaleEnum`.
+        #[allow(non_upper_case_globals)]
+        pub mod locale_codes {
+            #(#locale_codes)*
+        }
+
+        #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
         #[allow(non_camel_case_types)]
         pub enum LocaleEnum {
             #(#locales,)*
@@ -95,7 +366,17 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
             }
         }
 
-        impl leptos_i18n::LocaleVariant for LocaleEnum {
+        impl LocaleEnum {
+            /// All the supported locales, the default one first.
+            pub const ALL: &'static [LocaleEnum] = &[#(LocaleEnum::#locales,)*];
+
+            /// Return an iterator over all the supported locales, the default one first.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        impl #core_crate::LocaleVariant for LocaleEnum {
             fn as_str(self) -> &'static str {
                 match self {
                     #(#as_str_match_arms,)*
@@ -107,23 +388,180 @@ fn create_locales_enum(cfg_file: &ConfigFile) -> TokenStream {
                     _ => None
                 }
             }
+            fn get_all() -> &'static [Self] {
+                Self::ALL
+            }
+            fn get_priority() -> &'static [Self] {
+                const PRIORITY: &[LocaleEnum] = &[#(LocaleEnum::#priority,)*];
+                PRIORITY
+            }
+            #locale_styles
+            #completeness
+        }
+
+        impl core::fmt::Display for LocaleEnum {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(#core_crate::LocaleVariant::as_str(*self))
+            }
+        }
+
+        impl LocaleEnum {
+            /// Return the value of this locale in `values`.
+            ///
+            /// Every locale must have a value in `PerLocale`, so adding a locale fails to compile until each call is updated.
+            #[inline]
+            pub fn select<T>(self, values: PerLocale<T>) -> T {
+                values.into_value(self)
+            }
+        }
+
+        /// A value for each supported locale, such as a locale specific asset.
+        ///
+        /// The fields are named after the locales, building it without a wildcard makes the compiler point out every place
+        /// needing a value when a locale is added.
+        #[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+        #[allow(non_snake_case)]
+        pub struct PerLocale<T> {
+            #(pub #locales: T,)*
+        }
+
+        impl<T> PerLocale<T> {
+            /// Build the values by calling `f` with each locale.
+            pub fn from_fn(mut f: impl FnMut(LocaleEnum) -> T) -> Self {
+                PerLocale {
+                    #(#locales: f(LocaleEnum::#locales),)*
+                }
+            }
+
+            /// Return the value of `locale`.
+            pub fn get(&self, locale: LocaleEnum) -> &T {
+                match locale {
+                    #(LocaleEnum::#locales => &self.#locales,)*
+                }
+            }
+
+            /// Return the value of `locale`, dropping the others.
+            pub fn into_value(self, locale: LocaleEnum) -> T {
+                match locale {
+                    #(LocaleEnum::#locales => self.#locales,)*
+                }
+            }
+
+            /// Transform the value of each locale.
+            pub fn map<U>(self, mut f: impl FnMut(LocaleEnum, T) -> U) -> PerLocale<U> {
+                PerLocale {
+                    #(#locales: f(LocaleEnum::#locales, self.#locales),)*
+                }
+            }
+        }
+
+        impl core::str::FromStr for LocaleEnum {
+            type Err = #core_crate::UnknownLocaleError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Self as #core_crate::LocaleVariant>::from_str(s)
+                    .ok_or_else(|| #core_crate::UnknownLocaleError(core::convert::From::from(s)))
+            }
+        }
+
+        impl core::convert::TryFrom<&str> for LocaleEnum {
+            type Error = #core_crate::UnknownLocaleError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                <Self as core::str::FromStr>::from_str(s)
+            }
+        }
+
+        #serde_impls
+    }
+}
+
+fn create_fallback_keys(
+    cfg_file: &ConfigFile,
+    fallback_keys: &BTreeMap<String, Vec<String>>,
+) -> TokenStream {
+    let match_arms = cfg_file.locales.iter().filter_map(|locale| {
+        let keys = fallback_keys.get(&locale.name)?;
+        let ident = &locale.ident;
+        Some(quote!(LocaleEnum::#ident => &[#(#keys,)*]))
+    });
+
+    quote! {
+        impl LocaleEnum {
+            /// Return the keys missing in this locale, they display the value of the default locale.
+            pub fn fallback_keys(self) -> &'static [&'static str] {
+                match self {
+                    #(#match_arms,)*
+                    _ => &[]
+                }
+            }
         }
     }
 }
 
-fn create_locales_type(_cfg_file: &ConfigFile) -> TokenStream {
+// with the `panic_on_fallback` feature, accessing the keys of a locale with missing keys panics
+// in tests, `cfg!(test)` is expanded in the crate of the user.
+fn fallback_check() -> Option<TokenStream> {
+    cfg!(feature = "panic_on_fallback").then(|| {
+        let core_crate = core_path();
+        quote! {
+            leptos_i18n::testing::check_fallback(cfg!(test), #core_crate::LocaleVariant::as_str(_variant), _variant.fallback_keys());
+        }
+    })
+}
+
+fn create_locales_type(
+    cfg_file: &ConfigFile,
+    tenant_match_arms: Option<Vec<TokenStream>>,
+) -> TokenStream {
+    let core_crate = core_path();
+    let tenants = tenant_match_arms.map(|match_arms| {
+        let tenant_names = cfg_file.tenants.iter().map(|tenant| &tenant.name);
+        quote! {
+            const TENANTS: &'static [&'static str] = &[#(#tenant_names,)*];
+
+            fn get_tenant_keys(locale: LocaleEnum, tenant: &str) -> &'static I18nKeys {
+                match (tenant, locale) {
+                    #(#match_arms,)*
+                    _ => <I18nKeys as #core_crate::LocaleKeys>::from_variant(locale),
+                }
+            }
+        }
+    });
+
     quote! {
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
         pub struct Locales;
 
-        impl leptos_i18n::Locales for Locales {
+        impl #core_crate::Locales for Locales {
             type Variants = LocaleEnum;
             type LocaleKeys = I18nKeys;
+
+            #tenants
+        }
+
+        impl #core_crate::VariantLocales for LocaleEnum {
+            type Locales = Locales;
+        }
+
+        impl LocaleEnum {
+            /// Return the keys for this locale, used by the `td!` macro.
+            #[inline]
+            pub fn get_keys(self) -> &'static I18nKeys {
+                <I18nKeys as #core_crate::LocaleKeys>::from_variant(self)
+            }
+
+            /// Return the keys for this locale in the catalog of `tenant`, the shared keys if the tenant doesn't change this locale.
+            #[inline]
+            pub fn get_tenant_keys(self, tenant: &str) -> &'static I18nKeys {
+                <Locales as #core_crate::Locales>::get_tenant_keys(self, tenant)
+            }
         }
     }
 }
 
 struct Subkeys<'a> {
+    name: &'a Key,
     original_key: &'a syn::Ident,
     key: syn::Ident,
     mod_key: syn::Ident,
@@ -137,10 +575,12 @@ impl<'a> Subkeys<'a> {
         locales: &'a [Rc<RefCell<Locale>>],
         keys: &'a BuildersKeysInner,
     ) -> Self {
+        let name = key;
         let original_key = &key.ident;
         let mod_key = format_ident!("sk_{}", key.ident);
         let key = format_ident!("{}_subkeys", key.ident);
         Subkeys {
+            name,
             original_key,
             key,
             mod_key,
@@ -163,9 +603,25 @@ fn create_locale_type_inner(
         .map(|(key, _)| key)
         .collect::<Vec<_>>();
 
+    // a key is deprecated from the "@key" entry of the default locale, the attribute warns at every use of the field.
+    let deprecated = |key: &Key| {
+        let default_locale = locales.first()?.borrow();
+        let note = match default_locale.deprecations.get(key)? {
+            Some(replacement) => format!(
+                "the key {:?} is deprecated, use {:?} instead",
+                key.name, replacement
+            ),
+            None => format!("the key {:?} is deprecated", key.name),
+        };
+        Some(quote!(#[deprecated(note = #note)]))
+    };
+
     let string_fields = string_keys
         .iter()
-        .map(|key| quote!(pub #key: &'static str))
+        .map(|key| {
+            let deprecated = deprecated(key);
+            quote!(#deprecated pub #key: &'static str)
+        })
         .collect::<Vec<_>>();
 
     let subkeys = keys
@@ -193,7 +649,8 @@ fn create_locale_type_inner(
         let original_key = &sk.original_key;
         let key = &sk.key;
         let mod_ident = &sk.mod_key;
-        quote!(pub #original_key: subkeys::#mod_ident::#key)
+        let deprecated = deprecated(sk.name);
+        quote!(#deprecated pub #original_key: subkeys::#mod_ident::#key)
     });
 
     let subkeys_field_new = subkeys
@@ -231,7 +688,8 @@ fn create_locale_type_inner(
 
     let builder_fields = builders.iter().map(|(key, inter)| {
         let inter_ident = &inter.default_generic_ident;
-        quote!(pub #key: builders::#inter_ident)
+        let deprecated = deprecated(key);
+        quote!(#deprecated pub #key: builders::#inter_ident)
     });
 
     let init_builder_fields: Vec<TokenStream> = builders
@@ -284,16 +742,20 @@ fn create_locale_type_inner(
         }
     });
 
+    let core_crate = core_path();
     let (from_variant, const_values) = if !is_namespace {
         let from_variant_match_arms = top_locales.iter().map(|locale| {
             let ident = &locale.borrow().name.ident;
             quote!(LocaleEnum::#ident => &Self::#ident)
         });
 
+        let fallback_check = fallback_check();
+
         let from_variant = quote! {
-            impl leptos_i18n::LocaleKeys for #type_ident {
+            impl #core_crate::LocaleKeys for #type_ident {
                 type Locales = Locales;
                 fn from_variant(_variant: LocaleEnum) -> &'static Self {
+                    #fallback_check
                     match _variant {
                         #(
                             #from_variant_match_arms,
@@ -329,6 +791,7 @@ fn create_locale_type_inner(
             #(#subkeys_fields,)*
         }
 
+        #[allow(deprecated)]
         impl #type_ident {
 
             #const_values
@@ -403,6 +866,9 @@ fn create_namespaces_types(
         quote!(LocaleEnum::#locale_ident => &Self::#locale_ident)
     });
 
+    let fallback_check = fallback_check();
+    let core_crate = core_path();
+
     quote! {
         pub mod namespaces {
             use super::LocaleEnum;
@@ -434,9 +900,10 @@ fn create_namespaces_types(
             }
         }
 
-        impl leptos_i18n::LocaleKeys for #i18n_keys_ident {
+        impl #core_crate::LocaleKeys for #i18n_keys_ident {
             type Locales = Locales;
             fn from_variant(_variant: LocaleEnum) -> &'static Self {
+                #fallback_check
                 match _variant {
                     #(
                         #from_variant_match_arms,
@@ -1,21 +1,46 @@
-use std::{cell::RefCell, collections::HashSet, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+    rc::Rc,
+};
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use serde::de::{value::MapAccessDeserializer, DeserializeSeed};
 
 use super::{
+    casing::Casing,
     error::{Error, Result},
+    format::Format,
+    gender::Genders,
     key::{Key, KeyPath},
     locale::{Locale, LocaleSeed, LocaleValue},
-    plural::{PluralType, Plurals},
+    plural::{PluralType, Plurals, PluralsOrGenders},
 };
 
+// values are parsed recursively, bound the nesting of subkeys and components
+// to not overflow the stack of the compiler on pathological inputs.
+pub const MAX_NESTING_DEPTH: usize = 32;
+
+// the HTML elements without content, never closed.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+thread_local! {
+    // set from the "bidi-isolation" setting before generating the code, the values don't have access to the config.
+    pub static BIDI_ISOLATION: Cell<bool> = const { Cell::new(false) };
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParsedValue {
     Plural(Plurals),
+    Gender(Genders),
     String(String),
     Variable(Rc<Key>),
+    CasedVariable { key: Rc<Key>, casing: Casing },
+    FormattedVariable { key: Rc<Key>, format: Format },
     Component { key: Rc<Key>, inner: Box<Self> },
     Bloc(Vec<Self>),
     Subkeys(Rc<RefCell<Locale>>),
@@ -24,6 +49,7 @@ pub enum ParsedValue {
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum InterpolateKey {
     Count(PluralType),
+    Gender,
     Variable(Rc<Key>),
     Component(Rc<Key>),
 }
@@ -32,7 +58,9 @@ impl ParsedValue {
     pub fn get_keys_inner(&self, keys: &mut Option<HashSet<InterpolateKey>>) {
         match self {
             ParsedValue::String(_) | ParsedValue::Subkeys(_) => {}
-            ParsedValue::Variable(key) => {
+            ParsedValue::Variable(key)
+            | ParsedValue::CasedVariable { key, .. }
+            | ParsedValue::FormattedVariable { key, .. } => {
                 keys.get_or_insert_with(HashSet::new)
                     .insert(InterpolateKey::Variable(Rc::clone(key)));
             }
@@ -52,15 +80,47 @@ impl ParsedValue {
                 keys.get_or_insert_with(HashSet::new)
                     .insert(InterpolateKey::Count(plural_type));
             }
+            ParsedValue::Gender(genders) => {
+                genders.get_keys_inner(keys);
+                keys.get_or_insert_with(HashSet::new)
+                    .insert(InterpolateKey::Gender);
+            }
         }
     }
 
     pub fn get_keys(&self) -> Option<HashSet<InterpolateKey>> {
         let mut keys = None;
         self.get_keys_inner(&mut keys);
+        if let Some(keys) = &mut keys {
+            Self::unify_count(keys);
+        }
         keys
     }
 
+    // if the set contains InterpolateKey::Count, remove variable keys with name "count"
+    // ("var_count" with the rename), the count of a plural is the only setter for it,
+    // even in locales where it is only displayed. Same for "gender".
+    fn unify_count(keys: &mut HashSet<InterpolateKey>) {
+        if keys
+            .iter()
+            .any(|key| matches!(key, InterpolateKey::Count(_)))
+        {
+            keys.retain(|key| !key.is_count_variable());
+        }
+        if keys.contains(&InterpolateKey::Gender) {
+            keys.retain(|key| !key.is_gender_variable());
+        }
+    }
+
+    // empty or whitespace only, with nothing interpolated.
+    pub fn is_blank(&self) -> bool {
+        match self {
+            ParsedValue::String(value) => value.trim().is_empty(),
+            ParsedValue::Bloc(values) => values.iter().all(Self::is_blank),
+            _ => false,
+        }
+    }
+
     pub fn is_string(&self) -> Option<&str> {
         match self {
             ParsedValue::String(value) => Some(value),
@@ -125,9 +185,7 @@ impl ParsedValue {
             });
         }
 
-        // if the set contains InterpolateKey::Count, remove variable keys with name "count"
-        // ("var_count" with the rename)
-        keys.retain(|key| !matches!(key, InterpolateKey::Variable(key) if key.name == "var_count"));
+        Self::unify_count(keys);
 
         Ok(())
     }
@@ -160,8 +218,11 @@ impl ParsedValue {
                 ParsedValue::Bloc(_)
                 | ParsedValue::Component { .. }
                 | ParsedValue::Plural(_)
+                | ParsedValue::Gender(_)
                 | ParsedValue::String(_)
-                | ParsedValue::Variable(_),
+                | ParsedValue::Variable(_)
+                | ParsedValue::CasedVariable { .. }
+                | ParsedValue::FormattedVariable { .. },
                 LocaleValue::Value(keys),
             ) => self.merge_inner(keys, top_locale, key_path),
             // Value/Subkeys or vice versa-
@@ -169,8 +230,11 @@ impl ParsedValue {
                 ParsedValue::Bloc(_)
                 | ParsedValue::Component { .. }
                 | ParsedValue::Plural(_)
+                | ParsedValue::Gender(_)
                 | ParsedValue::String(_)
-                | ParsedValue::Variable(_),
+                | ParsedValue::Variable(_)
+                | ParsedValue::CasedVariable { .. }
+                | ParsedValue::FormattedVariable { .. },
                 LocaleValue::Subkeys { .. },
             )
             | (ParsedValue::Subkeys(_), LocaleValue::Value(_)) => Err(Error::SubKeyMissmatch {
@@ -180,15 +244,143 @@ impl ParsedValue {
         }
     }
 
+    // only tags that would be parsed as components are checked,
+    // so things like "a < b" or "I <3 you >" are left untouched.
+    // void elements (`<br>`) and self-closing tags (`<hr/>`) are never closed, they are kept as text.
+    fn find_tags(value: &str) -> impl Iterator<Item = (&str, &str)> {
+        value.match_indices('<').filter_map(|(i, _)| {
+            let (ident, _) = value[i + 1..].split_once('>')?;
+            let tag = &value[i..i + ident.len() + 2];
+            let ident = ident.trim();
+            if ident.ends_with('/') {
+                return None;
+            }
+            let name = ident
+                .strip_prefix('/')
+                .map(str::trim_start)
+                .unwrap_or(ident);
+            if VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str()) {
+                return None;
+            }
+            Key::new(&format!("comp_{}", name))?;
+            Some((tag, ident))
+        })
+    }
+
+    pub fn check_components(value: &str, locale: &Rc<Key>, key: &Rc<Key>) -> Result<()> {
+        let mut opened: Vec<(&str, &str)> = vec![];
+        for (tag, ident) in Self::find_tags(value) {
+            let Some(closing) = ident.strip_prefix('/').map(str::trim_start) else {
+                opened.push((tag, ident));
+                if opened.len() > MAX_NESTING_DEPTH {
+                    return Err(Error::TooDeeplyNested(Rc::clone(key)));
+                }
+                continue;
+            };
+            match opened.pop() {
+                Some((_, opening)) if opening == closing => {}
+                Some((opening_tag, _)) => {
+                    return Err(Error::MisnestedComponent {
+                        locale: Rc::clone(locale),
+                        key: Rc::clone(key),
+                        opening: opening_tag.to_string(),
+                        closing: tag.to_string(),
+                    })
+                }
+                None => {
+                    return Err(Error::UnopenedComponent {
+                        locale: Rc::clone(locale),
+                        key: Rc::clone(key),
+                        closing: tag.to_string(),
+                    })
+                }
+            }
+        }
+        if let Some((tag, _)) = opened.pop() {
+            return Err(Error::UnclosedComponent {
+                locale: Rc::clone(locale),
+                key: Rc::clone(key),
+                opening: tag.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn sanitize_variable_name(name: &str) -> Option<String> {
+        let mut sanitized = String::with_capacity(name.len() + 1);
+        for c in name.chars() {
+            if c.is_alphanumeric() {
+                sanitized.push(c);
+            } else if !sanitized.is_empty() && !sanitized.ends_with('_') {
+                sanitized.push('_');
+            }
+        }
+        let sanitized = sanitized.trim_end_matches('_');
+        if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(format!("_{}", sanitized));
+        }
+        (!sanitized.is_empty()).then(|| sanitized.to_string())
+    }
+
+    pub fn check_variables(value: &str, key: &Rc<Key>) -> Result<()> {
+        let mut rest = value;
+        while let Some((_, after_open)) = rest.split_once("{{") {
+            let Some((name, after_close)) = after_open.split_once("}}") else {
+                break;
+            };
+            rest = after_close;
+            let name = match name.split_once(',') {
+                Some((name, formatter)) => {
+                    if Casing::from_name(formatter).is_none()
+                        && Format::from_name(formatter).is_none()
+                    {
+                        return Err(Error::InvalidFormatter {
+                            key: Rc::clone(key),
+                            formatter: formatter.trim().to_string(),
+                        });
+                    }
+                    name.trim()
+                }
+                None => name.trim(),
+            };
+            // the "var_" prefix is added to the identifier, so check the first char separately,
+            // a leading digit would pass but couldn't be used in the `t!` macro.
+            let is_valid = !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && Key::new(&format!("var_{}", name)).is_some();
+            if !is_valid {
+                return Err(Error::InvalidVariableName {
+                    key: Rc::clone(key),
+                    name: name.to_string(),
+                    suggestion: Self::sanitize_variable_name(name),
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn find_variable(value: &str) -> Option<Self> {
         let (before, rest) = value.split_once("{{")?;
         let (ident, after) = rest.split_once("}}")?;
 
-        let ident = Key::new(&format!("var_{}", ident.trim()))?;
-
+        let (ident, formatter) = match ident.split_once(',') {
+            Some((ident, formatter)) => (ident, Some(formatter)),
+            None => (ident, None),
+        };
+        let key = Rc::new(Key::new(&format!("var_{}", ident.trim()))?);
+
+        let this = match formatter {
+            None => ParsedValue::Variable(key),
+            Some(formatter) => match Casing::from_name(formatter) {
+                Some(casing) => ParsedValue::CasedVariable { key, casing },
+                None => ParsedValue::FormattedVariable {
+                    key,
+                    format: Format::from_name(formatter)?,
+                },
+            },
+        };
         let before = Self::new(before);
         let after = Self::new(after);
-        let this = ParsedValue::Variable(Rc::new(ident));
 
         Some(ParsedValue::Bloc(vec![before, this, after]))
     }
@@ -270,8 +462,43 @@ impl ParsedValue {
             ParsedValue::Subkeys(_) => {}
             ParsedValue::String(s) => tokens.push(quote!(leptos::IntoView::into_view(#s))),
             ParsedValue::Plural(plurals) => tokens.push(plurals.to_token_stream()),
-            ParsedValue::Variable(key) => {
-                tokens.push(quote!(leptos::IntoView::into_view(core::clone::Clone::clone(&#key))))
+            ParsedValue::Gender(genders) => tokens.push(genders.to_token_stream()),
+            ParsedValue::Variable(key)
+            | ParsedValue::CasedVariable { key, .. }
+            | ParsedValue::FormattedVariable { key, .. } => {
+                let value = match self {
+                    // the casing needs the text of the value, render it in a closure to keep it reactive.
+                    // `__locale` is the locale field of the builder, in scope where the values are generated.
+                    ParsedValue::CasedVariable { casing, .. } => {
+                        quote!(leptos::IntoView::into_view({
+                            let #key = core::clone::Clone::clone(&#key);
+                            move || #casing.apply(
+                                &leptos_i18n::render::render_to_text(core::clone::Clone::clone(&#key)),
+                                __locale,
+                            )
+                        }))
+                    }
+                    // same for the numbers and the dates, the value is rendered as `f64::to_string` or chrono would and parsed back.
+                    ParsedValue::FormattedVariable { format, .. } => {
+                        quote!(leptos::IntoView::into_view({
+                            let #key = core::clone::Clone::clone(&#key);
+                            move || #format.apply(
+                                &leptos_i18n::render::render_to_text(core::clone::Clone::clone(&#key)),
+                                __locale,
+                            )
+                        }))
+                    }
+                    _ => quote!(leptos::IntoView::into_view(core::clone::Clone::clone(&#key))),
+                };
+                // isolate the interpolated text, so a right-to-left name in a left-to-right sentence
+                // (or the reverse) does not reorder the punctuation around it.
+                if BIDI_ISOLATION.with(Cell::get) {
+                    tokens.push(quote!(leptos::IntoView::into_view("\u{2068}")));
+                    tokens.push(value);
+                    tokens.push(quote!(leptos::IntoView::into_view("\u{2069}")));
+                } else {
+                    tokens.push(value);
+                }
             }
             ParsedValue::Component { key, inner } => {
                 let captured_keys = inner.get_keys().map(|keys| {
@@ -302,20 +529,22 @@ impl InterpolateKey {
         match self {
             InterpolateKey::Variable(key) | InterpolateKey::Component(key) => key.ident.clone(),
             InterpolateKey::Count(_) => format_ident!("var_count"),
+            InterpolateKey::Gender => format_ident!("var_gender"),
         }
     }
 
-    pub fn as_key(&self) -> Option<&Key> {
-        match self {
-            InterpolateKey::Variable(key) | InterpolateKey::Component(key) => Some(key),
-            InterpolateKey::Count(_) => None,
-        }
+    pub fn is_count_variable(&self) -> bool {
+        matches!(self, InterpolateKey::Variable(key) if key.name == "var_count")
+    }
+
+    pub fn is_gender_variable(&self) -> bool {
+        matches!(self, InterpolateKey::Variable(key) if key.name == "var_gender")
     }
 
-    #[cfg(feature = "debug_interpolations")]
     pub fn get_real_name(&self) -> &str {
         match self {
             InterpolateKey::Count(_) => "count",
+            InterpolateKey::Gender => "gender",
             InterpolateKey::Variable(key) => key.name.strip_prefix("var_").unwrap(),
             InterpolateKey::Component(key) => key.name.strip_prefix("comp_").unwrap(),
         }
@@ -329,6 +558,10 @@ impl InterpolateKey {
             InterpolateKey::Count(plural_type) => {
                 quote!(Fn() -> #plural_type + core::clone::Clone + 'static)
             }
+            InterpolateKey::Gender => {
+                let core_crate = super::core_path();
+                quote!(Fn() -> #core_crate::gender::Gender + core::clone::Clone + 'static)
+            }
             InterpolateKey::Component(_) => quote!(
                 Fn(leptos::ChildrenFn) -> leptos::View
                     + core::clone::Clone
@@ -338,6 +571,17 @@ impl InterpolateKey {
     }
 }
 
+impl std::fmt::Display for InterpolateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InterpolateKey::Count(_) => f.write_str("plural count"),
+            InterpolateKey::Gender => f.write_str("gender"),
+            InterpolateKey::Variable(_) => write!(f, "variable {{{{ {} }}}}", self.get_real_name()),
+            InterpolateKey::Component(_) => write!(f, "component <{}>", self.get_real_name()),
+        }
+    }
+}
+
 impl ToTokens for InterpolateKey {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         self.as_ident().to_tokens(tokens)
@@ -364,7 +608,10 @@ impl ToTokens for ParsedValue {
 #[derive(Debug, Clone, Copy)]
 pub struct ParsedValueSeed<'a> {
     pub in_plural: bool,
+    // the locale of the file, for the errors.
+    pub locale: &'a Rc<Key>,
     pub key: &'a Rc<Key>,
+    pub depth: usize,
 }
 
 impl<'de> serde::de::DeserializeSeed<'de> for ParsedValueSeed<'_> {
@@ -385,6 +632,8 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     where
         E: serde::de::Error,
     {
+        ParsedValue::check_components(v, self.locale, self.key).map_err(E::custom)?;
+        ParsedValue::check_variables(v, self.key).map_err(E::custom)?;
         Ok(ParsedValue::new(v))
     }
 
@@ -396,9 +645,15 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             return Err(serde::de::Error::custom(Error::PluralSubkeys));
         }
 
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(serde::de::Error::custom(Error::TooDeeplyNested(Rc::clone(
+                self.key,
+            ))));
+        }
+
         let map_de = MapAccessDeserializer::new(map);
 
-        LocaleSeed(Rc::clone(self.key))
+        LocaleSeed(Rc::clone(self.key), self.depth + 1, Rc::clone(self.locale))
             .deserialize(map_de)
             .map(RefCell::new)
             .map(Rc::new)
@@ -414,7 +669,10 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         if std::mem::replace(&mut self.in_plural, true) {
             return Err(serde::de::Error::custom(Error::NestedPlurals));
         }
-        let plurals = Plurals::from_serde_seq(map, self)?;
+        let plurals = match Plurals::from_serde_seq(map, self)? {
+            PluralsOrGenders::Plurals(plurals) => plurals,
+            PluralsOrGenders::Genders(genders) => return Ok(ParsedValue::Gender(genders)),
+        };
 
         let (invalid_fallback, fallback_count, should_have_fallback) =
             plurals.check_deserialization();
@@ -423,6 +681,8 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             Err(serde::de::Error::custom(Error::InvalidFallback))
         } else if fallback_count > 1 {
             Err(serde::de::Error::custom(Error::MultipleFallbacks))
+        } else if plurals.missing_other_category() {
+            Err(serde::de::Error::custom(Error::MissingOtherCategory))
         } else if fallback_count == 0 && should_have_fallback {
             Err(serde::de::Error::custom(Error::MissingFallback(
                 plurals.get_type(),
@@ -435,7 +695,7 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             formatter,
-            "either a string, a sequence of plurals or a map of subkeys"
+            "either a string, a sequence of plurals or genders or a map of subkeys"
         )
     }
 }
@@ -512,6 +772,121 @@ mod tests {
         )
     }
 
+    #[test]
+    fn check_balanced_components() {
+        let locale = new_key("fr");
+        let key = new_key("key");
+        let check = |value| ParsedValue::check_components(value, &locale, &key);
+
+        assert!(check("<b>bold <i>and italic</i></b> 1 < 2, I <3 you >").is_ok());
+        assert!(check("line<br>break <BR> and <hr/> rule <img src=\"a.png\" /> <b>ok</b>").is_ok());
+        assert!(matches!(
+            check("<b>not closed"),
+            Err(Error::UnclosedComponent { opening, .. }) if opening == "<b>"
+        ));
+        assert_eq!(
+            check("<b>not closed").unwrap_err().to_string(),
+            "component \"<b>\" is never closed in locale \"fr\" at key \"key\""
+        );
+        assert!(matches!(
+            check("not opened</ b>"),
+            Err(Error::UnopenedComponent { closing, .. }) if closing == "</ b>"
+        ));
+        assert!(matches!(
+            check("<b><i>misnested</b></i>"),
+            Err(Error::MisnestedComponent { opening, closing, .. }) if opening == "<i>" && closing == "</b>"
+        ));
+
+        let deep = format!("{}deep{}", "<b>".repeat(100), "</b>".repeat(100));
+        assert!(matches!(check(&deep), Err(Error::TooDeeplyNested(_))));
+    }
+
+    #[test]
+    fn check_variable_names() {
+        let key = new_key("key");
+        let check = |value| ParsedValue::check_variables(value, &key);
+
+        assert!(check("{{ count }} {{ first-name }} {{ _0 }}").is_ok());
+        assert!(matches!(
+            check("{{ first name }}"),
+            Err(Error::InvalidVariableName { suggestion: Some(s), .. }) if s == "first_name"
+        ));
+        assert!(matches!(
+            check("{{ 1st }}"),
+            Err(Error::InvalidVariableName { suggestion: Some(s), .. }) if s == "_1st"
+        ));
+        assert!(matches!(
+            check("{{ }}"),
+            Err(Error::InvalidVariableName {
+                suggestion: None,
+                ..
+            })
+        ));
+        assert!(check("{{ name, upper }} {{ city ,title }}").is_ok());
+        assert!(check("{{ price, currency(usd) }} {{ ratio, percent }}").is_ok());
+        assert!(check("{{ day, date(long) }} {{ sent_at, datetime(short) }}").is_ok());
+        assert!(matches!(
+            check("{{ name, shout }}"),
+            Err(Error::InvalidFormatter { formatter, .. }) if formatter == "shout"
+        ));
+        assert!(matches!(
+            check("{{ price, currency(dollar) }}"),
+            Err(Error::InvalidFormatter { formatter, .. }) if formatter == "currency(dollar)"
+        ));
+    }
+
+    #[test]
+    fn parse_cased_variable() {
+        let value = ParsedValue::new("Hello {{ name, upper }}!");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("Hello ".to_string()),
+                ParsedValue::CasedVariable {
+                    key: new_key("var_name"),
+                    casing: Casing::Upper
+                },
+                ParsedValue::String("!".to_string())
+            ])
+        );
+        assert!(value
+            .get_keys()
+            .unwrap()
+            .contains(&InterpolateKey::Variable(new_key("var_name"))));
+    }
+
+    #[test]
+    fn parse_formatted_variable() {
+        let value = ParsedValue::new("Total: {{ price, currency(eur) }}.");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::String("Total: ".to_string()),
+                ParsedValue::FormattedVariable {
+                    key: new_key("var_price"),
+                    format: Format::Currency("EUR".to_string())
+                },
+                ParsedValue::String(".".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn bidi_isolation() {
+        let value = ParsedValue::new("Hello {{ name }}!");
+
+        assert!(!value.to_token_stream().to_string().contains("\\u{2068}"));
+
+        BIDI_ISOLATION.with(|isolation| isolation.set(true));
+        let tokens = value.to_token_stream().to_string();
+        BIDI_ISOLATION.with(|isolation| isolation.set(false));
+
+        assert!(tokens.contains("\"\\u{2068}\""));
+        assert!(tokens.contains("\"\\u{2069}\""));
+    }
+
     #[test]
     fn parse_skipped_tag() {
         let value = ParsedValue::new("<p>test<h3>this is a h3</h3>not closing p");
@@ -10,14 +10,16 @@ use quote::{quote, ToTokens};
 
 use super::{
     error::{Error, Result},
+    gender::Genders,
     parsed_value::{InterpolateKey, ParsedValue, ParsedValueSeed},
 };
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
 pub enum PluralType {
     I8,
     I16,
     I32,
+    #[default]
     I64,
     U8,
     U16,
@@ -27,12 +29,6 @@ pub enum PluralType {
     F64,
 }
 
-impl Default for PluralType {
-    fn default() -> Self {
-        Self::I64
-    }
-}
-
 impl core::fmt::Display for PluralType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -77,6 +73,12 @@ impl PluralType {
 
 pub type PluralsInner<T> = Vec<(Plural<T>, ParsedValue)>;
 
+// a sequence is a plural, or gender branches when its first element is "gender".
+pub enum PluralsOrGenders {
+    Plurals(Plurals),
+    Genders(Genders),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Plurals {
     I8(PluralsInner<i8>),
@@ -112,6 +114,63 @@ impl Plurals {
         }
     }
 
+    pub fn values(&self) -> Vec<&ParsedValue> {
+        fn inner<T>(v: &PluralsInner<T>) -> Vec<&ParsedValue> {
+            v.iter().map(|(_, value)| value).collect()
+        }
+        match self {
+            Plurals::I8(v) => inner(v),
+            Plurals::I16(v) => inner(v),
+            Plurals::I32(v) => inner(v),
+            Plurals::I64(v) => inner(v),
+            Plurals::U8(v) => inner(v),
+            Plurals::U16(v) => inner(v),
+            Plurals::U32(v) => inner(v),
+            Plurals::U64(v) => inner(v),
+            Plurals::F32(v) => inner(v),
+            Plurals::F64(v) => inner(v),
+        }
+    }
+
+    pub fn values_mut(&mut self) -> Vec<&mut ParsedValue> {
+        fn inner<T>(v: &mut PluralsInner<T>) -> Vec<&mut ParsedValue> {
+            v.iter_mut().map(|(_, value)| value).collect()
+        }
+        match self {
+            Plurals::I8(v) => inner(v),
+            Plurals::I16(v) => inner(v),
+            Plurals::I32(v) => inner(v),
+            Plurals::I64(v) => inner(v),
+            Plurals::U8(v) => inner(v),
+            Plurals::U16(v) => inner(v),
+            Plurals::U32(v) => inner(v),
+            Plurals::U64(v) => inner(v),
+            Plurals::F32(v) => inner(v),
+            Plurals::F64(v) => inner(v),
+        }
+    }
+
+    // the branches with their condition as written in the locale file, and whether it only matches exact numbers.
+    pub fn branches(&self) -> Vec<(String, bool, &ParsedValue)> {
+        fn inner<T: PluralNumber>(v: &PluralsInner<T>) -> Vec<(String, bool, &ParsedValue)> {
+            v.iter()
+                .map(|(plural, value)| (plural.to_string(), plural.is_exact(), value))
+                .collect()
+        }
+        match self {
+            Plurals::I8(v) => inner(v),
+            Plurals::I16(v) => inner(v),
+            Plurals::I32(v) => inner(v),
+            Plurals::I64(v) => inner(v),
+            Plurals::U8(v) => inner(v),
+            Plurals::U16(v) => inner(v),
+            Plurals::U32(v) => inner(v),
+            Plurals::U64(v) => inner(v),
+            Plurals::F32(v) => inner(v),
+            Plurals::F64(v) => inner(v),
+        }
+    }
+
     pub const fn get_type(&self) -> PluralType {
         match self {
             Plurals::I8(_) => PluralType::I8,
@@ -127,28 +186,70 @@ impl Plurals {
         }
     }
 
-    fn to_tokens_integers<T: PluralInteger>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
-        let match_arms = plurals
-            .iter()
-            .map(|(plural, value)| quote!(#plural => #value));
+    // the `if-else` chain of the branches, for the floats and the integer plurals using categories.
+    fn if_chain<T: PluralNumber>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
+        let last = plurals.len().saturating_sub(1);
+        let mut ifs = plurals.iter().enumerate().map(|(i, (plural, value))| {
+            // "other" is what every count not selected before falls into.
+            let condition = match plural {
+                Plural::Category(PluralCategory::Other) if i == last => None,
+                plural => plural.to_condition(),
+            };
+            match condition {
+                None => quote!({ #value }),
+                Some(condition) => quote!(if #condition { #value }),
+            }
+        });
+        let first = ifs.next();
+        quote! {
+            #first
+            #(else #ifs)*
+        }
+    }
 
+    fn captured_values<T>(plurals: &[(Plural<T>, ParsedValue)]) -> Option<TokenStream> {
         let mut captured_values = None;
 
         for (_, value) in plurals {
             value.get_keys_inner(&mut captured_values);
         }
 
-        let captured_values = captured_values.map(|keys| {
+        captured_values.map(|keys| {
             let keys = keys
                 .into_iter()
                 .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
             quote!(#(#keys)*)
-        });
-        let match_statement = quote! {
-            match var_count() {
-                #(
-                    #match_arms,
-                )*
+        })
+    }
+
+    fn to_tokens_integers<T: PluralInteger>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
+        let captured_values = Self::captured_values(plurals);
+
+        // the category depends on the locale, `__locale` is the locale of the arm of the builder the value is rendered in.
+        let match_statement = if plurals.iter().any(|(plural, _)| plural.has_category()) {
+            let ifs = Self::if_chain(plurals);
+            let core_crate = super::core_path();
+            quote! {
+                {
+                    let plural_count = var_count();
+                    #[allow(unused_variables)]
+                    let plural_category = #core_crate::plurals::plural_category(
+                        __locale,
+                        <i64 as core::convert::TryFrom<_>>::try_from(plural_count).unwrap_or(i64::MAX),
+                    );
+                    #ifs
+                }
+            }
+        } else {
+            let match_arms = plurals
+                .iter()
+                .map(|(plural, value)| quote!(#plural => #value));
+            quote! {
+                match var_count() {
+                    #(
+                        #match_arms,
+                    )*
+                }
             }
         };
 
@@ -164,45 +265,8 @@ impl Plurals {
     }
 
     fn to_tokens_floats<T: PluralFloats>(plurals: &[(Plural<T>, ParsedValue)]) -> TokenStream {
-        fn to_condition<T: PluralFloats>(plural: &Plural<T>) -> Option<TokenStream> {
-            match plural {
-                Plural::Exact(exact) => Some(quote!(plural_count == #exact)),
-                Plural::Range { .. } => {
-                    Some(quote!(core::ops::RangeBounds::contains(&(#plural), &plural_count)))
-                }
-                Plural::Multiple(conditions) => {
-                    let mut conditions = conditions.iter().filter_map(to_condition);
-                    let first = conditions.next();
-                    Some(quote!(#first #(|| #conditions)*))
-                }
-                Plural::Fallback => None,
-            }
-        }
-
-        let mut ifs = plurals
-            .iter()
-            .map(|(plural, value)| match to_condition(plural) {
-                None => quote!({ #value }),
-                Some(condition) => quote!(if #condition { #value }),
-            });
-        let first = ifs.next();
-        let ifs = quote! {
-            #first
-            #(else #ifs)*
-        };
-
-        let mut captured_values = None;
-
-        for (_, value) in plurals {
-            value.get_keys_inner(&mut captured_values);
-        }
-
-        let captured_values = captured_values.map(|keys| {
-            let keys = keys
-                .into_iter()
-                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
-            quote!(#(#keys)*)
-        });
+        let ifs = Self::if_chain(plurals);
+        let captured_values = Self::captured_values(plurals);
 
         quote! {
             leptos::IntoView::into_view(
@@ -274,7 +338,7 @@ impl Plurals {
     pub fn from_serde_seq<'de, A>(
         mut seq: A,
         parsed_value_seed: ParsedValueSeed,
-    ) -> Result<Self, A::Error>
+    ) -> Result<PluralsOrGenders, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
     {
@@ -286,10 +350,41 @@ impl Plurals {
         let mut plurals = match type_or_plural {
             TypeOrPlural::Type(plural_type) => Self::from_type(plural_type),
             TypeOrPlural::Plural(plural) => Plurals::I64(vec![plural]),
+            TypeOrPlural::Gender => {
+                return Genders::from_serde_seq(seq, parsed_value_seed)
+                    .map(PluralsOrGenders::Genders)
+            }
         };
 
         plurals.deserialize_inner(seq, parsed_value_seed)?;
-        Ok(plurals)
+        Ok(PluralsOrGenders::Plurals(plurals))
+    }
+
+    // plurals using categories must end with "other" or a fallback, else some counts would select no branch.
+    fn missing_other_inner<T: PluralNumber>(plurals: &[(Plural<T>, ParsedValue)]) -> bool {
+        plurals.iter().any(|(plural, _)| plural.has_category())
+            && !matches!(
+                plurals.last(),
+                Some((
+                    Plural::Fallback | Plural::Category(PluralCategory::Other),
+                    _
+                ))
+            )
+    }
+
+    pub fn missing_other_category(&self) -> bool {
+        match self {
+            Plurals::I8(plurals) => Self::missing_other_inner(plurals),
+            Plurals::I16(plurals) => Self::missing_other_inner(plurals),
+            Plurals::I32(plurals) => Self::missing_other_inner(plurals),
+            Plurals::I64(plurals) => Self::missing_other_inner(plurals),
+            Plurals::U8(plurals) => Self::missing_other_inner(plurals),
+            Plurals::U16(plurals) => Self::missing_other_inner(plurals),
+            Plurals::U32(plurals) => Self::missing_other_inner(plurals),
+            Plurals::U64(plurals) => Self::missing_other_inner(plurals),
+            Plurals::F32(plurals) => Self::missing_other_inner(plurals),
+            Plurals::F64(plurals) => Self::missing_other_inner(plurals),
+        }
     }
 
     fn check_de_inner<T: PluralNumber>(
@@ -359,10 +454,62 @@ pub enum Plural<T> {
     Exact(T),
     Range { start: Option<T>, end: Bound<T> },
     Multiple(Vec<Self>),
+    Category(PluralCategory),
     Fallback,
 }
 
-pub trait PluralNumber: FromStr + ToTokens + PartialOrd + Copy {
+// the CLDR plural categories, selected at runtime with the rules of the locale.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "zero" => Some(PluralCategory::Zero),
+            "one" => Some(PluralCategory::One),
+            "two" => Some(PluralCategory::Two),
+            "few" => Some(PluralCategory::Few),
+            "many" => Some(PluralCategory::Many),
+            "other" => Some(PluralCategory::Other),
+            _ => None,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+impl ToTokens for PluralCategory {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let category = match self {
+            PluralCategory::Zero => quote!(Zero),
+            PluralCategory::One => quote!(One),
+            PluralCategory::Two => quote!(Two),
+            PluralCategory::Few => quote!(Few),
+            PluralCategory::Many => quote!(Many),
+            PluralCategory::Other => quote!(Other),
+        };
+        let core_crate = super::core_path();
+        tokens.extend(quote!(#core_crate::plurals::PluralCategory::#category))
+    }
+}
+
+pub trait PluralNumber: FromStr + ToTokens + PartialOrd + Copy + core::fmt::Display {
     const TYPE: PluralType;
 
     fn range_end_bound(self) -> Option<Bound<Self>>;
@@ -399,6 +546,17 @@ impl<T: PluralNumber> Plural<T> {
             return Ok(Self::Fallback);
         };
 
+        if let Some(category) = PluralCategory::from_name(s) {
+            // the CLDR rules are defined for integers only.
+            if T::TYPE.should_have_fallback() {
+                return Err(Error::PluralCategoryType {
+                    category: s.to_string(),
+                    plural_type: T::TYPE,
+                });
+            }
+            return Ok(Self::Category(category));
+        }
+
         if s.contains('|') {
             return s
                 .split('|')
@@ -445,6 +603,70 @@ impl<T: PluralNumber> Plural<T> {
     }
 }
 
+impl<T: PluralNumber> Plural<T> {
+    // an exact branch such as "0" or "1" can spell the number out instead of using `{{ count }}`.
+    fn is_exact(&self) -> bool {
+        match self {
+            Plural::Exact(_) => true,
+            Plural::Multiple(plurals) => plurals.iter().all(Self::is_exact),
+            Plural::Range { .. } | Plural::Category(_) | Plural::Fallback => false,
+        }
+    }
+
+    fn has_category(&self) -> bool {
+        match self {
+            Plural::Category(_) => true,
+            Plural::Multiple(plurals) => plurals.iter().any(Self::has_category),
+            Plural::Exact(_) | Plural::Range { .. } | Plural::Fallback => false,
+        }
+    }
+
+    fn to_condition(&self) -> Option<TokenStream> {
+        match self {
+            Plural::Exact(exact) => Some(quote!(plural_count == #exact)),
+            Plural::Range { .. } => {
+                Some(quote!(core::ops::RangeBounds::contains(&(#self), &plural_count)))
+            }
+            Plural::Category(category) => Some(quote!(plural_category == #category)),
+            Plural::Multiple(conditions) => {
+                let mut conditions = conditions.iter().filter_map(Self::to_condition);
+                let first = conditions.next();
+                Some(quote!(#first #(|| #conditions)*))
+            }
+            Plural::Fallback => None,
+        }
+    }
+}
+
+impl<T: PluralNumber> core::fmt::Display for Plural<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Plural::Exact(num) => write!(f, "{}", num),
+            Plural::Range { start, end } => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                match end {
+                    Bound::Included(end) => write!(f, "..={}", end),
+                    Bound::Excluded(end) => write!(f, "..{}", end),
+                    Bound::Unbounded => f.write_str(".."),
+                }
+            }
+            Plural::Multiple(plurals) => {
+                for (i, plural) in plurals.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{}", plural)?;
+                }
+                Ok(())
+            }
+            Plural::Category(category) => f.write_str(category.as_str()),
+            Plural::Fallback => f.write_str("_"),
+        }
+    }
+}
+
 impl<T: PluralNumber> ToTokens for Plural<T> {
     fn to_token_stream(&self) -> proc_macro2::TokenStream {
         match self {
@@ -469,6 +691,8 @@ impl<T: PluralNumber> ToTokens for Plural<T> {
                 quote!(#start..#end)
             }
             Plural::Fallback => quote!(_),
+            // the plurals using categories are generated as an `if-else` chain, see `Plurals::if_chain`.
+            Plural::Category(_) => unreachable!("plural categories are not match patterns"),
             Plural::Multiple(matchs) => {
                 let mut matchs = matchs.iter().map(Self::to_token_stream);
                 if let Some(first) = matchs.next() {
@@ -701,6 +925,7 @@ impl<'de> serde::de::Visitor<'de> for PluralFieldVisitor {
 enum TypeOrPlural {
     Type(PluralType),
     Plural((Plural<i64>, ParsedValue)),
+    Gender,
 }
 
 struct TypeOrPluralSeed<'a>(pub ParsedValueSeed<'a>);
@@ -741,6 +966,7 @@ impl<'de> serde::de::Visitor<'de> for TypeOrPluralSeed<'_> {
             "u64" => Ok(TypeOrPlural::Type(PluralType::U64)),
             "f32" => Ok(TypeOrPlural::Type(PluralType::F32)),
             "f64" => Ok(TypeOrPlural::Type(PluralType::F64)),
+            "gender" => Ok(TypeOrPlural::Gender),
             _ => Err(serde::de::Error::custom(Error::InvalidPluralType(
                 v.to_string(),
             ))),
@@ -867,6 +1093,23 @@ mod tests {
 
         assert_eq!(plural, Plural::Fallback);
     }
+
+    #[test]
+    fn test_category() {
+        let plural = Plural::<u32>::new(" few | 0 ").unwrap();
+
+        assert_eq!(
+            plural,
+            Plural::Multiple(vec![
+                Plural::Category(PluralCategory::Few),
+                Plural::Exact(0)
+            ])
+        );
+        assert!(plural.has_category());
+        assert!(!plural.is_exact());
+        assert_eq!(plural.to_string(), "few | 0");
+        assert!(Plural::<f64>::new("one").is_err());
+    }
 }
 
 mod plural_number_impl {
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{cfg_file::ConfigFile, locale::BuildersKeys, snapshot::collect_all_keys};
+
+// a whole missing subkeys group is recorded once as a fallback, so a key falls back if it or one of its parents does.
+fn is_fallback(path: &str, fallback_keys: &[String]) -> bool {
+    fallback_keys.iter().any(|fallback| {
+        path.strip_prefix(fallback.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+// generate the provenance table: every key with the locale that supplies its value in each locale.
+pub fn create_provenance(
+    cfg_file: &ConfigFile,
+    keys: &BuildersKeys,
+    fallback_keys: &BTreeMap<String, Vec<String>>,
+) -> TokenStream {
+    let paths = collect_all_keys(keys)
+        .into_iter()
+        .map(|snapshot_key| snapshot_key.path)
+        .collect::<Vec<_>>();
+
+    let default = &cfg_file.default.ident;
+    let match_arms = cfg_file.locales.iter().filter_map(|locale| {
+        let locale_fallbacks = fallback_keys.get(&locale.name)?;
+        let fallbacks = paths
+            .iter()
+            .filter(|path| is_fallback(path, locale_fallbacks));
+        let ident = &locale.ident;
+        Some(quote!(LocaleEnum::#ident => &[#(#fallbacks,)*]))
+    });
+
+    quote! {
+        impl LocaleEnum {
+            /// The path of every key, sorted.
+            pub const KEY_PATHS: &'static [&'static str] = &[#(#paths,)*];
+
+            /// Return the keys of this locale displaying the value of the default locale, including the keys of missing subkeys.
+            pub fn fallback_key_paths(self) -> &'static [&'static str] {
+                match self {
+                    #(#match_arms,)*
+                    _ => &[]
+                }
+            }
+
+            /// Return the locale supplying the value of the key at `path` in this locale,
+            /// the default locale if the key falls back to it, or `None` for an unknown key.
+            pub fn key_source(self, path: &str) -> Option<LocaleEnum> {
+                Self::KEY_PATHS.binary_search(&path).ok()?;
+                if self.fallback_key_paths().binary_search(&path).is_ok() {
+                    Some(LocaleEnum::#default)
+                } else {
+                    Some(self)
+                }
+            }
+
+            /// Return every key with the locale supplying its value in this locale, sorted by path.
+            pub fn provenance(self) -> Vec<(&'static str, LocaleEnum)> {
+                let fallbacks = self.fallback_key_paths();
+                Self::KEY_PATHS
+                    .iter()
+                    .map(|path| match fallbacks.binary_search(path) {
+                        Ok(_) => (*path, LocaleEnum::#default),
+                        Err(_) => (*path, self),
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+// generate the `completeness` and `is_complete` overrides of `LocaleVariant`, `None` if every locale is complete.
+pub fn create_completeness(
+    cfg_file: &ConfigFile,
+    keys: &BuildersKeys,
+    fallback_keys: &BTreeMap<String, Vec<String>>,
+) -> Option<TokenStream> {
+    if fallback_keys.values().all(Vec::is_empty) {
+        return None;
+    }
+    let paths = collect_all_keys(keys)
+        .into_iter()
+        .map(|snapshot_key| snapshot_key.path)
+        .collect::<Vec<_>>();
+
+    let mut completeness_arms = vec![];
+    let mut incomplete = vec![];
+    for locale in &cfg_file.locales {
+        let Some(locale_fallbacks) = fallback_keys.get(&locale.name) else {
+            continue;
+        };
+        let fallbacks = paths
+            .iter()
+            .filter(|path| is_fallback(path, locale_fallbacks))
+            .count();
+        if fallbacks == 0 {
+            continue;
+        }
+        let completeness = (paths.len() - fallbacks) as f32 / paths.len() as f32;
+        let ident = &locale.ident;
+        completeness_arms.push(quote!(LocaleEnum::#ident => #completeness));
+        incomplete.push(ident);
+    }
+
+    Some(quote! {
+        fn completeness(self) -> f32 {
+            match self {
+                #(#completeness_arms,)*
+                _ => 1.0
+            }
+        }
+        fn is_complete(self) -> bool {
+            !matches!(self, #(LocaleEnum::#incomplete)|*)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_subkeys() {
+        let fallback_keys = ["legal::terms".to_string(), "title".to_string()];
+
+        assert!(is_fallback("title", &fallback_keys));
+        assert!(is_fallback("legal::terms.intro", &fallback_keys));
+        assert!(!is_fallback("legal::terms_short", &fallback_keys));
+        assert!(!is_fallback("subtitle", &fallback_keys));
+    }
+}
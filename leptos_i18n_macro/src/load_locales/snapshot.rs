@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::{
+    gender::Gender,
+    key::Key,
+    locale::{BuildersKeys, BuildersKeysInner, LocaleValue},
+    parsed_value::InterpolateKey,
+};
+
+// the plural branches are rendered for those counts, enough to hit the usual zero/one/few/many cases.
+const SAMPLE_COUNTS: [u8; 4] = [0, 1, 2, 5];
+
+const SAMPLE_GENDERS: [(&str, Gender); 4] = [
+    ("masculine", Gender::Masculine),
+    ("feminine", Gender::Feminine),
+    ("neuter", Gender::Neuter),
+    ("other", Gender::Other),
+];
+
+pub struct SnapshotKey<'a> {
+    pub path: String,
+    pub accessor: Vec<&'a Key>,
+    pub interpolations: Option<&'a HashSet<InterpolateKey>>,
+}
+
+fn collect_keys<'a>(
+    prefix: &str,
+    accessor: &mut Vec<&'a Key>,
+    keys: &'a BuildersKeysInner,
+    snapshot_keys: &mut Vec<SnapshotKey<'a>>,
+) {
+    for (key, value) in &keys.0 {
+        let path = if prefix.is_empty() || prefix.ends_with("::") {
+            format!("{}{}", prefix, key.name)
+        } else {
+            format!("{}.{}", prefix, key.name)
+        };
+        accessor.push(key);
+        match value {
+            LocaleValue::Subkeys { keys, .. } => collect_keys(&path, accessor, keys, snapshot_keys),
+            LocaleValue::Value(interpolations) => snapshot_keys.push(SnapshotKey {
+                path,
+                accessor: accessor.clone(),
+                interpolations: interpolations.as_ref(),
+            }),
+        }
+        accessor.pop();
+    }
+}
+
+fn entry(path: &str, value: TokenStream) -> TokenStream {
+    quote! {
+        leptos_i18n::testing::SnapshotEntry {
+            locale: _locale,
+            key: #path,
+            value: leptos_i18n::render::render_to_text(#value),
+        }
+    }
+}
+
+fn snapshot_entries(snapshot_key: &SnapshotKey) -> Vec<TokenStream> {
+    let accessor = &snapshot_key.accessor;
+    let get_key = quote!(_keys #(.#accessor)*);
+    let Some(interpolations) = snapshot_key.interpolations else {
+        return vec![entry(&snapshot_key.path, get_key)];
+    };
+
+    let mut setters = vec![];
+    let mut plural_type = None;
+    let mut has_gender = false;
+    for interpolation in interpolations {
+        let name = interpolation.get_real_name();
+        let setter = interpolation.as_ident();
+        match interpolation {
+            InterpolateKey::Variable(_) => {
+                let placeholder = format!("{{{}}}", name);
+                setters.push(quote!(.#setter(#placeholder)));
+            }
+            InterpolateKey::Component(_) => {
+                setters.push(quote!(.#setter(leptos_i18n::testing::marker(#name))));
+            }
+            InterpolateKey::Count(count_type) => plural_type = Some(*count_type),
+            InterpolateKey::Gender => has_gender = true,
+        }
+    }
+
+    // each sample is the path suffix and the setter of the sampled value.
+    let mut samples = vec![(String::new(), quote!())];
+    if let Some(plural_type) = plural_type {
+        samples = samples
+            .iter()
+            .flat_map(|(suffix, setter)| {
+                SAMPLE_COUNTS.iter().map(move |count| {
+                    let suffix = format!("{} (count = {})", suffix, count);
+                    let count = Literal::u8_unsuffixed(*count);
+                    (
+                        suffix,
+                        quote!(#setter .var_count(move || #count as #plural_type)),
+                    )
+                })
+            })
+            .collect();
+    }
+    if has_gender {
+        samples = samples
+            .iter()
+            .flat_map(|(suffix, setter)| {
+                SAMPLE_GENDERS.iter().map(move |(name, gender)| {
+                    let suffix = format!("{} (gender = {})", suffix, name);
+                    (suffix, quote!(#setter .var_gender(move || #gender)))
+                })
+            })
+            .collect();
+    }
+
+    samples
+        .into_iter()
+        .map(|(suffix, setter)| {
+            let path = format!("{}{}", snapshot_key.path, suffix);
+            entry(&path, quote!(#get_key #(#setters)* #setter))
+        })
+        .collect()
+}
+
+// every key of the catalog with its path, sorted by path so the generated code is stable.
+pub fn collect_all_keys(keys: &BuildersKeys) -> Vec<SnapshotKey<'_>> {
+    let mut snapshot_keys = vec![];
+    match keys {
+        BuildersKeys::NameSpaces { keys, .. } => {
+            for (namespace, keys) in keys {
+                let prefix = format!("{}::", namespace.name);
+                let mut accessor = vec![&**namespace];
+                collect_keys(&prefix, &mut accessor, keys, &mut snapshot_keys);
+            }
+        }
+        BuildersKeys::Locales { keys, .. } => {
+            collect_keys("", &mut vec![], keys, &mut snapshot_keys)
+        }
+    }
+    snapshot_keys.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+    snapshot_keys
+}
+
+// render every key of every locale with placeholder values, sorted by key so the output is stable.
+pub fn create_snapshot(keys: &BuildersKeys) -> TokenStream {
+    let snapshot_keys = collect_all_keys(keys);
+
+    let entries = snapshot_keys.iter().flat_map(snapshot_entries);
+
+    quote! {
+        /// Render every key of every locale with placeholder values, for snapshot tests of the whole catalog.
+        ///
+        /// Variables are rendered as `{name}`, components as markers, plurals for a few sample counts and genders for each gender.
+        #[allow(deprecated)]
+        pub fn snapshot() -> Vec<leptos_i18n::testing::SnapshotEntry<LocaleEnum>> {
+            let mut _entries = vec![];
+            for _locale in LocaleEnum::iter() {
+                let _keys = _locale.get_keys();
+                _entries.extend([#(#entries,)*]);
+            }
+            _entries
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{
+    cfg_file::ConfigFile,
+    error::{Error, Result},
+};
+
+fn style_value(locale: &str, properties: &toml::Table) -> Result<TokenStream> {
+    let mut class = quote!(None);
+    let mut font_family = quote!(None);
+    let mut line_height = quote!(None);
+    for (property, value) in properties {
+        match (property.as_str(), value) {
+            ("class", toml::Value::String(value)) => class = quote!(Some(#value)),
+            ("font-family", toml::Value::String(value)) => font_family = quote!(Some(#value)),
+            ("line-height", toml::Value::Float(value)) => {
+                let value = *value as f32;
+                line_height = quote!(Some(#value));
+            }
+            ("line-height", toml::Value::Integer(value)) => {
+                let value = *value as f32;
+                line_height = quote!(Some(#value));
+            }
+            _ => {
+                return Err(Error::InvalidLocaleStyle {
+                    locale: locale.to_string(),
+                    property: property.clone(),
+                })
+            }
+        }
+    }
+    let core_crate = super::core_path();
+    Ok(quote! {
+        #core_crate::style::LocaleStyle {
+            class: #class,
+            font_family: #font_family,
+            line_height: #line_height,
+        }
+    })
+}
+
+// generate the `LocaleVariant::style` override from the "locale-styles" table, `None` keeps the default without hints.
+pub fn create_locale_styles(cfg_file: &ConfigFile) -> Result<Option<TokenStream>> {
+    if cfg_file.locale_styles.is_empty() {
+        return Ok(None);
+    }
+
+    let mut match_arms = vec![];
+    let mut errors = vec![];
+    for (locale, properties) in &cfg_file.locale_styles {
+        let Some(key) = cfg_file.locales.iter().find(|key| &key.name == locale) else {
+            errors.push(Error::UnknownStyleLocale(locale.clone()));
+            continue;
+        };
+        match style_value(locale, properties) {
+            Ok(style) => {
+                let variant = &key.ident;
+                match_arms.push(quote!(LocaleEnum::#variant => #style));
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+    Error::collect(errors)?;
+
+    let core_crate = super::core_path();
+    Ok(Some(quote! {
+        #[allow(unreachable_patterns)]
+        fn style(self) -> #core_crate::style::LocaleStyle {
+            match self {
+                #(#match_arms,)*
+                _ => #core_crate::style::LocaleStyle::DEFAULT,
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles_from_manifest() {
+        let manifest = r#"
+[package]
+name = "app"
+
+[package.metadata.leptos-i18n]
+default = "en"
+locales = ["en", "ja"]
+
+[package.metadata.leptos-i18n.locale-styles.ja]
+class = "cjk"
+font-family = "'Noto Sans JP', sans-serif"
+line-height = 1.8
+"#;
+        let cfg = ConfigFile::parse(manifest, None).unwrap();
+        assert_eq!(cfg.locale_styles.len(), 1);
+
+        let styles = create_locale_styles(&cfg).unwrap().unwrap().to_string();
+        let expected = quote! {
+            LocaleEnum::ja => leptos_i18n::__private::leptos_i18n_core::style::LocaleStyle {
+                class: Some("cjk"),
+                font_family: Some("'Noto Sans JP', sans-serif"),
+                line_height: Some(1.8f32),
+            }
+        };
+        assert!(styles.contains(&expected.to_string()), "{}", styles);
+    }
+
+    #[test]
+    fn invalid_style() {
+        let manifest = r#"
+[package.metadata.leptos-i18n]
+default = "en"
+locales = ["en"]
+
+[package.metadata.leptos-i18n.locale-styles.en]
+color = "red"
+
+[package.metadata.leptos-i18n.locale-styles.ja]
+class = "cjk"
+"#;
+        let cfg = ConfigFile::parse(manifest, None).unwrap();
+        let err = create_locale_styles(&cfg).unwrap_err().to_string();
+        assert!(err.contains("color"), "{}", err);
+        assert!(err.contains("\"ja\""), "{}", err);
+    }
+}
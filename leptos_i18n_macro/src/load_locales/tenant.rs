@@ -0,0 +1,199 @@
+use std::rc::Rc;
+
+use leptos_i18n_core::__private::language;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+    cfg_file::{ConfigFile, FileFormat},
+    error::{Error, Result},
+    key::{Key, KeyPath},
+    locale::{BuildersKeys, BuildersKeysInner, KeyPrefix, Locale, LocaleValue, Namespace},
+    parsed_value::ParsedValue,
+    transform::{self, Transformer},
+};
+
+pub fn tenant_dir(locales_dir: &str, tenant: &Key) -> String {
+    format!("{}/tenants/{}", locales_dir, tenant.name)
+}
+
+// a tenant file only lists the keys the tenant changes, a missing file means the tenant uses the shared catalog.
+fn load_tenant_locale(
+    path: String,
+    locale: &Rc<Key>,
+    prefix: Option<&KeyPrefix>,
+    transformers: &[Transformer],
+    format: FileFormat,
+) -> Result<Option<Locale>> {
+    if !std::path::Path::new(&path).is_file() {
+        return Ok(None);
+    }
+    let mut tenant_locale = Locale::new(path, Rc::clone(locale), format)?;
+    let language = language(&locale.name);
+    transform::transform_locale(&mut tenant_locale, transformers, &language);
+    match prefix {
+        Some(prefix) if !tenant_locale.select_subtree(&prefix.path) => Ok(None),
+        _ => Ok(Some(tenant_locale)),
+    }
+}
+
+struct TenantCtx<'a> {
+    tenant: &'a Rc<Key>,
+    locale: &'a Rc<Key>,
+    errors: &'a mut Vec<Error>,
+}
+
+// build `Type { key: "tenant value", subkeys: SubType { .., ..base.subkeys }, ..base }`,
+// the keys are checked against the shared schema so the struct is the same for every tenant.
+fn tenant_struct(
+    ctx: &mut TenantCtx,
+    mod_path: &TokenStream,
+    type_ident: &syn::Ident,
+    base: &TokenStream,
+    tenant_locale: &Locale,
+    keys: &BuildersKeysInner,
+    key_path: &mut KeyPath,
+) -> TokenStream {
+    let mut fields = vec![];
+    for (key, value) in &tenant_locale.keys {
+        key_path.push_key(Rc::clone(key));
+        match (keys.0.get(key), &**value) {
+            (Some(LocaleValue::Value(None)), ParsedValue::String(value)) => {
+                fields.push(quote!(#key: #value));
+            }
+            (Some(LocaleValue::Subkeys { keys, .. }), ParsedValue::Subkeys(locale)) => {
+                let sk_mod = format_ident!("sk_{}", key.ident);
+                let sk_type = format_ident!("{}_subkeys", key.ident);
+                let sk_mod_path = quote!(#mod_path subkeys::#sk_mod::);
+                let sk_base = quote!(#base.#key);
+                let value = tenant_struct(
+                    ctx,
+                    &sk_mod_path,
+                    &sk_type,
+                    &sk_base,
+                    &locale.borrow(),
+                    keys,
+                    key_path,
+                );
+                fields.push(quote!(#key: #value));
+            }
+            _ => ctx.errors.push(Error::InvalidTenantKey {
+                tenant: Rc::clone(ctx.tenant),
+                locale: Rc::clone(ctx.locale),
+                key_path: key_path.clone(),
+            }),
+        }
+        key_path.pop_key();
+    }
+    quote!(#mod_path #type_ident { #(#fields,)* ..#base })
+}
+
+// generate the keys of every tenant, and the `Locales::get_tenant_keys` match arms selecting them.
+pub fn create_tenants(
+    cfg_file: &ConfigFile,
+    keys: &BuildersKeys,
+    prefix: Option<&str>,
+) -> Result<Option<(TokenStream, Vec<TokenStream>)>> {
+    if cfg_file.tenants.is_empty() {
+        return Ok(None);
+    }
+
+    let with_namespaces = matches!(keys, BuildersKeys::NameSpaces { .. });
+    let prefix = match prefix {
+        Some(prefix) => Some(KeyPrefix::new(prefix, with_namespaces)?),
+        None => None,
+    };
+
+    let locales_dir = cfg_file.locales_dir.as_ref();
+    let format = cfg_file.file_format;
+    let mut consts = vec![];
+    let mut match_arms = vec![];
+    let mut errors = vec![];
+    for tenant in &cfg_file.tenants {
+        let tenant_dir = tenant_dir(locales_dir, tenant);
+        for locale in &cfg_file.locales {
+            let locale_ident = &locale.ident;
+            let const_ident = format_ident!("{}__{}", tenant.ident, locale.ident);
+            let base = quote!(super::I18nKeys::#locale_ident);
+            let transformers = transform::transformers_for(cfg_file, &locale.name);
+            let mut ctx = TenantCtx {
+                tenant,
+                locale,
+                errors: &mut errors,
+            };
+            let value = match keys {
+                BuildersKeys::Locales { keys, .. } => {
+                    let path = Locale::file_path(&tenant_dir, locale, format);
+                    match load_tenant_locale(path, locale, prefix.as_ref(), &transformers, format) {
+                        Ok(Some(tenant_locale)) => Some(tenant_struct(
+                            &mut ctx,
+                            &quote!(super::),
+                            &format_ident!("I18nKeys"),
+                            &base,
+                            &tenant_locale,
+                            keys,
+                            &mut KeyPath::new(None),
+                        )),
+                        Ok(None) => None,
+                        Err(err) => {
+                            ctx.errors.push(err);
+                            None
+                        }
+                    }
+                }
+                BuildersKeys::NameSpaces { namespaces, keys } => {
+                    let mut fields = vec![];
+                    for namespace in namespaces {
+                        let path =
+                            Namespace::file_path(&tenant_dir, locale, &namespace.key, format);
+                        let tenant_locale = match load_tenant_locale(
+                            path,
+                            locale,
+                            prefix.as_ref(),
+                            &transformers,
+                            format,
+                        ) {
+                            Ok(Some(tenant_locale)) => tenant_locale,
+                            Ok(None) => continue,
+                            Err(err) => {
+                                ctx.errors.push(err);
+                                continue;
+                            }
+                        };
+                        let namespace_key = &namespace.key;
+                        let ns_mod = format_ident!("ns_{}", namespace_key.ident);
+                        let value = tenant_struct(
+                            &mut ctx,
+                            &quote!(super::namespaces::#ns_mod::),
+                            &namespace_key.ident,
+                            &quote!(#base.#namespace_key),
+                            &tenant_locale,
+                            keys.get(namespace_key).unwrap(),
+                            &mut KeyPath::new(Some(Rc::clone(namespace_key))),
+                        );
+                        fields.push(quote!(#namespace_key: #value));
+                    }
+                    (!fields.is_empty()).then(|| quote!(super::I18nKeys { #(#fields,)* ..#base }))
+                }
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            consts.push(quote!(pub const #const_ident: super::I18nKeys = #value;));
+            let tenant_name = &tenant.name;
+            match_arms
+                .push(quote!((#tenant_name, LocaleEnum::#locale_ident) => &tenants::#const_ident));
+        }
+    }
+    Error::collect(errors)?;
+
+    let tenants = quote! {
+        #[doc(hidden)]
+        #[allow(deprecated, non_upper_case_globals)]
+        pub mod tenants {
+            #(#consts)*
+        }
+    };
+
+    Ok(Some((tenants, match_arms)))
+}
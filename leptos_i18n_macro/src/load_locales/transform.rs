@@ -0,0 +1,311 @@
+use std::rc::Rc;
+
+use leptos_i18n_core::__private::language;
+
+use super::{
+    cfg_file::ConfigFile,
+    error::{Error, Result},
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::ParsedValue,
+};
+
+// the rewrites applied to the text of the values of a locale, set in the "transformers" config.
+// They only see the text between the interpolations, the variables and components are never changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transformer {
+    SmartQuotes,
+    FrenchSpacing,
+    CjkPunctuation,
+}
+
+impl Transformer {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "smart_quotes" => Some(Transformer::SmartQuotes),
+            "french_spacing" => Some(Transformer::FrenchSpacing),
+            "cjk_punctuation" => Some(Transformer::CjkPunctuation),
+            _ => None,
+        }
+    }
+
+    // `previous` is the character displayed before `text`, `Some(_)` after an interpolation.
+    pub fn apply(self, text: &str, language: &str, previous: Option<char>) -> String {
+        match self {
+            Transformer::SmartQuotes => smart_quotes(text, language, previous),
+            Transformer::FrenchSpacing => french_spacing(text, previous),
+            Transformer::CjkPunctuation => cjk_punctuation(text, language, previous),
+        }
+    }
+}
+
+// a quote opens at the start of the value or after a space or an opening bracket, else it closes.
+fn smart_quotes(text: &str, language: &str, mut previous: Option<char>) -> String {
+    let (open, close) = match language {
+        "de" | "cs" | "sk" => ('„', '“'),
+        "fr" | "es" | "it" | "ru" | "uk" => ('«', '»'),
+        "ja" => ('「', '」'),
+        _ => ('“', '”'),
+    };
+    let mut quoted = String::with_capacity(text.len());
+    for c in text.chars() {
+        let opens = previous.is_none_or(|p| p.is_whitespace() || "([{“„«「".contains(p));
+        let replacement = match c {
+            '"' if opens => open,
+            '"' => close,
+            // an apostrophe after a letter (l'été, don't), else an opening single quote.
+            '\'' if opens => '‘',
+            '\'' => '’',
+            c => c,
+        };
+        quoted.push(replacement);
+        previous = Some(c);
+    }
+    quoted
+}
+
+const NARROW_NBSP: char = '\u{202f}';
+
+// french puts a narrow non-breaking space before `!?;:` and `»` and after `«`, replacing the space if any.
+// `:` in times or urls and a punctuation following another one ("?!") are left as is.
+fn french_spacing(text: &str, previous: Option<char>) -> String {
+    let mut spaced = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut skip_spaces = false;
+    while let Some(c) = chars.next() {
+        if skip_spaces && matches!(c, ' ' | '\u{a0}' | NARROW_NBSP) {
+            continue;
+        }
+        skip_spaces = false;
+        let next = chars.peek().copied();
+        let spaced_before = match c {
+            '!' | '?' | ';' | ':' => next.is_none_or(|n| n.is_whitespace() || "!?;:»)".contains(n)),
+            '»' => true,
+            _ => false,
+        };
+        if spaced_before {
+            let had_space = spaced.ends_with([' ', '\u{a0}', NARROW_NBSP]);
+            if had_space {
+                spaced.pop();
+            }
+            let before = spaced
+                .chars()
+                .last()
+                .or(previous.filter(|_| spaced.is_empty()));
+            if had_space || before.is_some_and(|b| !b.is_whitespace() && !"!?;:«".contains(b)) {
+                spaced.push(NARROW_NBSP);
+            }
+        }
+        spaced.push(c);
+        if c == '«' {
+            spaced.push(NARROW_NBSP);
+            skip_spaces = true;
+        }
+    }
+    spaced
+}
+
+// the scripts written with full-width punctuation: kana, ideographs and the full-width forms.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{30ff}' | '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}'
+        | '\u{f900}'..='\u{faff}' | '\u{ff00}'..='\u{ffef}'
+    )
+}
+
+// the ascii punctuation next to CJK text becomes full-width, and the spaces after it are removed
+// as the full-width forms carry their own spacing. Latin text inside a CJK value keeps its punctuation.
+fn cjk_punctuation(text: &str, language: &str, previous: Option<char>) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut previous = previous;
+    let mut skip_spaces = false;
+    while let Some(c) = chars.next() {
+        if skip_spaces && c == ' ' {
+            continue;
+        }
+        skip_spaces = false;
+        let after_cjk = previous.is_some_and(is_cjk);
+        let next = chars.peek().copied();
+        let full_width = match c {
+            '!' if after_cjk => Some('！'),
+            '?' if after_cjk => Some('？'),
+            ':' if after_cjk => Some('：'),
+            ';' if after_cjk => Some('；'),
+            ',' if after_cjk && language == "ja" => Some('、'),
+            ',' if after_cjk => Some('，'),
+            '.' if after_cjk && next.is_none_or(char::is_whitespace) => Some('。'),
+            '(' if next.is_some_and(is_cjk) => Some('（'),
+            ')' if after_cjk => Some('）'),
+            _ => None,
+        };
+        match full_width {
+            Some(full_width) => {
+                normalized.push(full_width);
+                skip_spaces = full_width != '（';
+                previous = Some(full_width);
+            }
+            None => {
+                normalized.push(c);
+                previous = Some(c);
+            }
+        }
+    }
+    normalized
+}
+
+fn transform_value(
+    value: &mut ParsedValue,
+    transformers: &[Transformer],
+    language: &str,
+    previous: &mut Option<char>,
+) {
+    match value {
+        ParsedValue::String(text) => {
+            for transformer in transformers {
+                *text = transformer.apply(text, language, *previous);
+            }
+            if let Some(last) = text.chars().last() {
+                *previous = Some(last);
+            }
+        }
+        // any character other than a space, so a quote following the interpolation closes.
+        ParsedValue::Variable(_)
+        | ParsedValue::CasedVariable { .. }
+        | ParsedValue::FormattedVariable { .. } => *previous = Some('_'),
+        ParsedValue::Component { inner, .. } => {
+            transform_value(inner, transformers, language, previous)
+        }
+        ParsedValue::Bloc(values) => {
+            for value in values {
+                transform_value(value, transformers, language, previous);
+            }
+        }
+        ParsedValue::Plural(plurals) => {
+            for value in plurals.values_mut() {
+                transform_value(value, transformers, language, &mut previous.clone());
+            }
+            *previous = Some('_');
+        }
+        ParsedValue::Gender(genders) => {
+            for (_, value) in &mut genders.0 {
+                transform_value(value, transformers, language, &mut previous.clone());
+            }
+            *previous = Some('_');
+        }
+        ParsedValue::Subkeys(locale) => {
+            transform_locale(&mut locale.borrow_mut(), transformers, language)
+        }
+    }
+}
+
+pub fn transform_locale(locale: &mut Locale, transformers: &[Transformer], language: &str) {
+    for value in locale.keys.values_mut() {
+        transform_value(Rc::make_mut(value), transformers, language, &mut None);
+    }
+}
+
+// the transformers of a locale, the names are checked by `apply_transformers`.
+pub fn transformers_for(cfg_file: &ConfigFile, locale: &str) -> Vec<Transformer> {
+    cfg_file
+        .transformers
+        .get(locale)
+        .into_iter()
+        .flatten()
+        .filter_map(|name| Transformer::from_name(name))
+        .collect()
+}
+
+// apply the transformers configured for each locale to the values of its files.
+pub fn apply_transformers(cfg_file: &ConfigFile, locales: &LocalesOrNamespaces) -> Result<()> {
+    let mut errors = vec![];
+    for (locale_name, names) in &cfg_file.transformers {
+        if !cfg_file
+            .locales
+            .iter()
+            .any(|locale| &locale.name == locale_name)
+        {
+            errors.push(Error::UnknownTransformerLocale(locale_name.clone()));
+            continue;
+        }
+        let mut transformers = vec![];
+        for name in names {
+            match Transformer::from_name(name) {
+                Some(transformer) => transformers.push(transformer),
+                None => errors.push(Error::UnknownTransformer {
+                    locale: locale_name.clone(),
+                    name: name.clone(),
+                }),
+            }
+        }
+        let language = language(locale_name);
+        let mut transform = |locale: &Rc<std::cell::RefCell<Locale>>| {
+            let mut locale = locale.borrow_mut();
+            if &locale.name.name == locale_name {
+                transform_locale(&mut locale, &transformers, &language);
+            }
+        };
+        match locales {
+            LocalesOrNamespaces::NameSpaces(namespaces) => namespaces
+                .iter()
+                .flat_map(|namespace| &namespace.locales)
+                .for_each(&mut transform),
+            LocalesOrNamespaces::Locales(locales) => locales.iter().for_each(&mut transform),
+        }
+    }
+    Error::collect(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smart_quotes() {
+        let quotes = |text, language| smart_quotes(text, language, None);
+
+        assert_eq!(quotes(r#"Say "hello""#, "en"), "Say “hello”");
+        assert_eq!(quotes(r#"Sag "Hallo""#, "de"), "Sag „Hallo“");
+        assert_eq!(quotes(r#"Dites "bonjour""#, "fr"), "Dites «bonjour»");
+        assert_eq!(quotes("l'été, don't 'quote'", "en"), "l’été, don’t ‘quote’");
+        // the quote after an interpolation closes it.
+        assert_eq!(smart_quotes(r#"" now"#, "en", Some('_')), "” now");
+    }
+
+    #[test]
+    fn test_french_spacing() {
+        let spacing = |text| french_spacing(text, None);
+
+        assert_eq!(spacing("Bonjour !"), "Bonjour\u{202f}!");
+        assert_eq!(spacing("Vraiment?!"), "Vraiment\u{202f}?!");
+        assert_eq!(
+            spacing("Note : ceci; cela"),
+            "Note\u{202f}: ceci\u{202f}; cela"
+        );
+        assert_eq!(spacing("« Salut »"), "«\u{202f}Salut\u{202f}»");
+        assert_eq!(spacing("«Salut»"), "«\u{202f}Salut\u{202f}»");
+        assert_eq!(
+            spacing("À 12:30, voir https://example.com"),
+            "À 12:30, voir https://example.com"
+        );
+        // the punctuation right after an interpolation is spaced too.
+        assert_eq!(french_spacing("!", Some('_')), "\u{202f}!");
+        assert_eq!(spacing("!"), "!");
+    }
+
+    #[test]
+    fn test_cjk_punctuation() {
+        assert_eq!(cjk_punctuation("你好, 世界!", "zh", None), "你好，世界！");
+        assert_eq!(
+            cjk_punctuation("こんにちは, 世界. (テスト)", "ja", None),
+            "こんにちは、世界。（テスト）"
+        );
+        assert_eq!(
+            cjk_punctuation("版本 1.2 (beta)", "zh", None),
+            "版本 1.2 (beta)"
+        );
+        assert_eq!(
+            cjk_punctuation("Hello, world!", "zh", None),
+            "Hello, world!"
+        );
+    }
+}
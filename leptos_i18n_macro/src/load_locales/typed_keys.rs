@@ -0,0 +1,165 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::ext::IdentExt;
+
+use super::{
+    key::Key,
+    locale::BuildersKeys,
+    parsed_value::InterpolateKey,
+    snapshot::{collect_all_keys, SnapshotKey},
+};
+
+// the variant of a key is its path with the segments joined by `__`, e.g. `namespace__key__subkey`.
+fn variant_ident(snapshot_key: &SnapshotKey) -> syn::Ident {
+    let name = snapshot_key
+        .accessor
+        .iter()
+        .map(|key| key.ident.unraw().to_string())
+        .collect::<Vec<_>>()
+        .join("__");
+    syn::parse_str::<syn::Ident>(&name)
+        .unwrap_or_else(|_| syn::Ident::new_raw(&name, Span::call_site()))
+}
+
+// the arguments struct of a key with interpolations, and the setters applying its fields to the builder.
+fn key_args(
+    variant: &syn::Ident,
+    interpolations: impl Iterator<Item = InterpolateKey>,
+) -> (TokenStream, Vec<TokenStream>) {
+    let mut interpolations = interpolations.collect::<Vec<_>>();
+    interpolations.sort_unstable_by(|a, b| a.get_real_name().cmp(b.get_real_name()));
+
+    let mut fields = vec![];
+    let mut setters = vec![];
+    for interpolation in &interpolations {
+        let setter = interpolation.as_ident();
+        let Some(field) = Key::new(interpolation.get_real_name()) else {
+            continue;
+        };
+        let field = field.ident;
+        match interpolation {
+            InterpolateKey::Variable(_) => {
+                fields.push(quote!(pub #field: String));
+                setters.push(quote!(.#setter(_args.#field)));
+            }
+            InterpolateKey::Count(plural_type) => {
+                fields.push(quote!(pub #field: #plural_type));
+                setters.push(quote!(.#setter(move || _args.#field)));
+            }
+            InterpolateKey::Gender => {
+                let core_crate = super::core_path();
+                fields.push(quote!(pub #field: #core_crate::gender::Gender));
+                setters.push(quote!(.#setter(move || _args.#field)));
+            }
+            // the text of a component is kept, the component itself has no meaning outside of a view.
+            InterpolateKey::Component(_) => setters.push(quote!(
+                .#setter(|children: leptos::ChildrenFn| leptos::IntoView::into_view(children()))
+            )),
+        }
+    }
+
+    let args_struct = quote! {
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct #variant {
+            #(#fields,)*
+        }
+    };
+    (args_struct, setters)
+}
+
+// generate `KeyEnum`, `KeyArgs` and the `translate` function, for lookups driven by data.
+pub fn create_typed_keys(keys: &BuildersKeys) -> TokenStream {
+    let snapshot_keys = collect_all_keys(keys);
+
+    let mut variants = vec![];
+    let mut paths = vec![];
+    let mut args_structs = vec![];
+    let mut args_variants = vec![];
+    let mut translate_arms = vec![];
+    for snapshot_key in &snapshot_keys {
+        let variant = variant_ident(snapshot_key);
+        let path = &snapshot_key.path;
+        let accessor = &snapshot_key.accessor;
+        let get_key = quote!(_keys #(.#accessor)*);
+        match snapshot_key.interpolations {
+            None => translate_arms.push(quote! {
+                (KeyEnum::#variant, _) => std::borrow::Cow::Borrowed(#get_key)
+            }),
+            Some(interpolations) => {
+                let (args_struct, setters) = key_args(&variant, interpolations.iter().cloned());
+                args_structs.push(args_struct);
+                args_variants.push(quote!(#variant(key_args::#variant)));
+                translate_arms.push(quote! {
+                    (KeyEnum::#variant, KeyArgs::#variant(_args)) => std::borrow::Cow::Owned(
+                        leptos_i18n::render::render_to_text(#get_key #(#setters)*)
+                    )
+                });
+            }
+        }
+        variants.push(variant);
+        paths.push(path);
+    }
+
+    quote! {
+        /// Every key of the catalog, to select a translation from data with `translate`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types)]
+        pub enum KeyEnum {
+            #(#variants,)*
+        }
+
+        impl KeyEnum {
+            /// All the keys, sorted by path.
+            pub const ALL: &'static [KeyEnum] = &[#(KeyEnum::#variants,)*];
+
+            /// Return the path of the key, written as in the macros, e.g. `"namespace::key.subkey"`.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(KeyEnum::#variants => #paths,)*
+                }
+            }
+
+            /// Return the key with the given path, written as in the macros.
+            pub fn from_path(path: &str) -> Option<Self> {
+                match path {
+                    #(#paths => Some(KeyEnum::#variants),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl core::fmt::Display for KeyEnum {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        /// The arguments structs of the keys with interpolations, named like their `KeyEnum` variant.
+        #[allow(non_camel_case_types)]
+        pub mod key_args {
+            #(#args_structs)*
+        }
+
+        /// The arguments of a key given to `translate`, `KeyArgs::None` for the keys without interpolations.
+        #[derive(Debug, Clone, Default, PartialEq)]
+        #[allow(non_camel_case_types)]
+        pub enum KeyArgs {
+            #[default]
+            None,
+            #(#args_variants,)*
+        }
+
+        /// Return the translation of `key` in `locale` as plain text, with the interpolations of `args`.
+        ///
+        /// The components keep their text only. If `args` is not the variant of `key`, the path of the key is returned.
+        /// Rendering a key with interpolations needs a reactive runtime, see `leptos_i18n::standalone::with_runtime` outside of the application.
+        #[allow(deprecated, unreachable_patterns)]
+        pub fn translate(locale: LocaleEnum, key: KeyEnum, args: KeyArgs) -> std::borrow::Cow<'static, str> {
+            let _keys = locale.get_keys();
+            match (key, args) {
+                #(#translate_arms,)*
+                (key, _) => std::borrow::Cow::Borrowed(key.as_str()),
+            }
+        }
+    }
+}
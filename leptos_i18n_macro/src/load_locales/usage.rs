@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+use super::{key::Key, locale::BuildersKeys, snapshot::collect_all_keys};
+
+// the path of a key as written in `t!`, the one counted by the instrumented translations.
+fn macro_path(accessor: &[&Key], with_namespaces: bool) -> String {
+    let mut segments = accessor.iter().map(|key| key.ident.unraw().to_string());
+    let namespace = with_namespaces.then(|| segments.next()).flatten();
+    let path = segments.collect::<Vec<_>>().join(".");
+    match namespace {
+        Some(namespace) => format!("{}::{}", namespace, path),
+        None => path,
+    }
+}
+
+// generate the `usage_report` function, listing every key so the keys never used show up with a count of 0.
+pub fn create_usage_report(keys: &BuildersKeys) -> TokenStream {
+    let with_namespaces = matches!(keys, BuildersKeys::NameSpaces { .. });
+    let paths = collect_all_keys(keys)
+        .iter()
+        .map(|snapshot_key| macro_path(&snapshot_key.accessor, with_namespaces))
+        .collect::<Vec<_>>();
+
+    quote! {
+        /// Return the number of uses of every key since the start of the program or the last `leptos_i18n::usage::reset`.
+        pub fn usage_report() -> leptos_i18n::usage::UsageReport {
+            leptos_i18n::usage::report(&[#(#paths,)*])
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::t_macro::parsed_input::Keys;
+
+use super::{
+    cfg_file::ConfigFile,
+    error::{Error, Result},
+};
+
+fn parse_key(kind: &str, key: &str) -> Result<TokenStream> {
+    let invalid_key = || Error::InvalidValidationKey {
+        kind: kind.to_string(),
+        key: key.to_string(),
+    };
+    let tokens: TokenStream = key.parse().map_err(|_| invalid_key())?;
+    syn::parse2::<Keys>(tokens.clone()).map_err(|_| invalid_key())?;
+    Ok(tokens)
+}
+
+// generate `LocaleEnum::validation_message`, mapping each configured validation error to its key.
+// errors without a key use the english message of their `Display` implementation.
+pub fn create_validation_messages(cfg_file: &ConfigFile) -> Result<Option<TokenStream>> {
+    if cfg_file.validation.is_empty() {
+        return Ok(None);
+    }
+
+    let core_crate = super::core_path();
+    let mut match_arms = vec![];
+    let mut errors = vec![];
+    for (kind, key) in &cfg_file.validation {
+        let key = match parse_key(kind, key) {
+            Ok(key) => key,
+            Err(err) => {
+                errors.push(err);
+                continue;
+            }
+        };
+        let arm = match kind.as_str() {
+            "required" => quote! {
+                #core_crate::validation::ValidationError::Required => leptos_i18n::render::render_to_text(leptos_i18n::td!(self, #key))
+            },
+            "min-length" => quote! {
+                #core_crate::validation::ValidationError::MinLength { min } => {
+                    let min = *min;
+                    leptos_i18n::render::render_to_text(leptos_i18n::td!(self, #key, min))
+                }
+            },
+            "max-length" => quote! {
+                #core_crate::validation::ValidationError::MaxLength { max } => {
+                    let max = *max;
+                    leptos_i18n::render::render_to_text(leptos_i18n::td!(self, #key, max))
+                }
+            },
+            "pattern" => quote! {
+                #core_crate::validation::ValidationError::Pattern { pattern } => {
+                    let pattern = pattern.clone();
+                    leptos_i18n::render::render_to_text(leptos_i18n::td!(self, #key, pattern = pattern.clone()))
+                }
+            },
+            _ => {
+                errors.push(Error::InvalidValidationKind(kind.clone()));
+                continue;
+            }
+        };
+        match_arms.push(arm);
+    }
+    Error::collect(errors)?;
+
+    Ok(Some(quote! {
+        impl LocaleEnum {
+            /// Return the message of a validation error in this locale.
+            pub fn validation_message(self, error: &#core_crate::validation::ValidationError) -> String {
+                #[allow(unreachable_patterns)]
+                match error {
+                    #(#match_arms,)*
+                    _ => error.to_string(),
+                }
+            }
+        }
+    }))
+}
@@ -2,13 +2,51 @@ use proc_macro2::TokenStream;
 #[cfg(not(feature = "nightly"))]
 use quote::{format_ident, quote};
 
-use super::key::{Key, KeyPath};
+use super::{
+    key::{Key, KeyPath},
+    parsed_value::InterpolateKey,
+    plural::PluralType,
+};
 use std::{cell::RefCell, fmt::Display, rc::Rc};
 
 #[derive(Debug)]
 pub enum Warning {
-    MissingKey { locale: Rc<Key>, key_path: KeyPath },
-    SurplusKey { locale: Rc<Key>, key_path: KeyPath },
+    MissingKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    SurplusKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    MissingInterpolation {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        interpolation: InterpolateKey,
+    },
+    SurplusInterpolation {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        interpolation: InterpolateKey,
+    },
+    CountAsVariable {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+        plural_in_default: bool,
+        plural_type: PluralType,
+    },
+    InconsistentPluralTypes {
+        key: String,
+        types: Vec<(Rc<Key>, PluralType)>,
+    },
+    TranslatedPassthrough {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
+    OutdatedKey {
+        locale: Rc<Key>,
+        key_path: KeyPath,
+    },
 }
 
 thread_local! {
@@ -32,6 +70,63 @@ impl Display for Warning {
                 "Key {} is present in locale {:?} but not in default locale, it is ignored",
                 key_path, locale
             ),
+            Warning::MissingInterpolation {
+                locale,
+                key_path,
+                interpolation,
+            } => write!(
+                f,
+                "The {} is used in the default locale but not in locale {:?} at key {}",
+                interpolation, locale, key_path
+            ),
+            Warning::SurplusInterpolation {
+                locale,
+                key_path,
+                interpolation,
+            } => write!(
+                f,
+                "The {} is used in locale {:?} but not in the default locale at key {}, it will still be required when using the key",
+                interpolation, locale, key_path
+            ),
+            Warning::CountAsVariable {
+                locale,
+                key_path,
+                plural_in_default,
+                plural_type,
+            } => {
+                let (plain, plural) = if *plural_in_default {
+                    (format!("locale {:?}", locale), "the default locale".to_string())
+                } else {
+                    ("the default locale".to_string(), format!("locale {:?}", locale))
+                };
+                write!(
+                    f,
+                    "At key {}, {} uses {{{{ count }}}} as a plain variable but {} uses it as a plural count, it will have to be set with a value convertible to {}",
+                    key_path, plain, plural, plural_type
+                )
+            }
+            Warning::InconsistentPluralTypes { key, types } => {
+                write!(
+                    f,
+                    "Key \"{}\" is a plural with different count types across namespaces:",
+                    key
+                )?;
+                for (i, (namespace, plural_type)) in types.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { "," };
+                    write!(f, "{} {} in {:?}", sep, plural_type, namespace)?;
+                }
+                Ok(())
+            }
+            Warning::TranslatedPassthrough { locale, key_path } => write!(
+                f,
+                "Key {} is a passthrough key but locale {:?} translates it, the value of the default locale is used",
+                key_path, locale
+            ),
+            Warning::OutdatedKey { locale, key_path } => write!(
+                f,
+                "Key {} is outdated in locale {:?}, its value in the default locale changed since it was translated",
+                key_path, locale
+            ),
         }
     }
 }
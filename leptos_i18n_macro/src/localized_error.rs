@@ -0,0 +1,178 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, Token};
+
+use crate::t_macro::parsed_input::Keys;
+
+pub fn derive_localized_error(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(tokens as DeriveInput);
+    match derive_localized_error_inner(input) {
+        Ok(ts) => ts.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+enum I18nAttr {
+    Key(TokenStream),
+    Module(syn::Path),
+    Skip,
+}
+
+fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<I18nAttr>> {
+    let mut parsed = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("i18n")) {
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let name: Ident = input.parse()?;
+            match name.to_string().as_str() {
+                "skip" => parsed.push(I18nAttr::Skip),
+                "key" => {
+                    input.parse::<Token![=]>()?;
+                    let key: TokenStream = input.parse()?;
+                    // only check the key is valid, the `td!` macro does the rest.
+                    syn::parse2::<Keys>(key.clone())?;
+                    parsed.push(I18nAttr::Key(key));
+                }
+                "module" => {
+                    input.parse::<Token![=]>()?;
+                    parsed.push(I18nAttr::Module(input.parse()?));
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "expected one of `key = ...`, `module = ...` or `skip`",
+                    ))
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(parsed)
+}
+
+// `TooShort` => `too_short`
+pub(crate) fn to_snake_case(ident: &Ident) -> Ident {
+    let name = ident.to_string();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    format_ident!("{}", snake, span = ident.span())
+}
+
+fn derive_localized_error_inner(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "LocalizedError can only be derived for enums",
+        ));
+    };
+
+    let mut module: syn::Path = syn::parse_quote!(crate::i18n);
+    for attr in parse_attrs(&input.attrs)? {
+        match attr {
+            I18nAttr::Module(path) => module = path,
+            I18nAttr::Key(key) => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "the key must be set on the variants",
+                ))
+            }
+            I18nAttr::Skip => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "`skip` can only be used on fields",
+                ))
+            }
+        }
+    }
+
+    let mut match_arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let mut key = None;
+        for attr in parse_attrs(&variant.attrs)? {
+            match attr {
+                I18nAttr::Key(k) => key = Some(k),
+                _ => {
+                    return Err(syn::Error::new(
+                        variant_ident.span(),
+                        "only `key = ...` can be used on variants",
+                    ))
+                }
+            }
+        }
+        let key = key.unwrap_or_else(|| {
+            let key = to_snake_case(variant_ident);
+            quote!(#key)
+        });
+
+        let arm = match &variant.fields {
+            Fields::Unit => quote! {
+                Self::#variant_ident => leptos_i18n::render::render_to_text(leptos_i18n::td!(locale, #key))
+            },
+            Fields::Unnamed(fields) => {
+                for field in &fields.unnamed {
+                    let skip = parse_attrs(&field.attrs)?
+                        .iter()
+                        .any(|attr| matches!(attr, I18nAttr::Skip));
+                    if !skip {
+                        return Err(syn::Error::new(
+                            field.span(),
+                            "the fields are used as interpolation variables, so they must be named or skipped with `#[i18n(skip)]`",
+                        ));
+                    }
+                }
+                quote! {
+                    Self::#variant_ident(..) => leptos_i18n::render::render_to_text(leptos_i18n::td!(locale, #key))
+                }
+            }
+            Fields::Named(fields) => {
+                let mut variables = vec![];
+                for field in &fields.named {
+                    let skip = parse_attrs(&field.attrs)?
+                        .iter()
+                        .any(|attr| matches!(attr, I18nAttr::Skip));
+                    if !skip {
+                        variables.extend(field.ident.clone());
+                    }
+                }
+                if variables.is_empty() {
+                    quote! {
+                        Self::#variant_ident { .. } => leptos_i18n::render::render_to_text(leptos_i18n::td!(locale, #key))
+                    }
+                } else {
+                    quote! {
+                        Self::#variant_ident { #(#variables,)* .. } => {
+                        #(
+                            let #variables = ::core::clone::Clone::clone(#variables);
+                        )*
+                            leptos_i18n::render::render_to_text(leptos_i18n::td!(locale, #key, #(#variables = #variables.clone()),*))
+                        }
+                    }
+                }
+            }
+        };
+        match_arms.push(arm);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Return the message of this error in the given locale.
+            pub fn localize(&self, locale: #module::LocaleEnum) -> String {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, token::Comma, Token};
+
+use crate::t_macro::parsed_input::Keys;
+
+pub fn use_i18n_scoped(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(tokens as ScopedInput);
+    use_i18n_scoped_inner(input).into()
+}
+
+enum Scope {
+    // `namespace::`, the whole namespace.
+    Namespace(syn::Ident),
+    Keys(Keys),
+}
+
+struct ScopedInput {
+    module: syn::Path,
+    scope: Scope,
+}
+
+impl syn::parse::Parse for ScopedInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut module = syn::parse_quote!(crate::i18n);
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let name: syn::Ident = input.parse()?;
+            if name != "module" {
+                return Err(syn::Error::new(name.span(), "expected `module = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            module = input.parse()?;
+            input.parse::<Comma>()?;
+        }
+        let fork = input.fork();
+        let scope = match (fork.call(syn::Ident::parse_any), fork.parse::<Token![::]>()) {
+            (Ok(namespace), Ok(_)) if fork.is_empty() => {
+                input.parse::<syn::Ident>()?;
+                input.parse::<Token![::]>()?;
+                Scope::Namespace(namespace)
+            }
+            _ => Scope::Keys(input.parse()?),
+        };
+        Ok(ScopedInput { module, scope })
+    }
+}
+
+// the scope selects the subtree with a non capturing closure, coerced to a function pointer,
+// and keeps its path to report the whole path of the keys to the overrides and the usage statistics.
+fn use_i18n_scoped_inner(input: ScopedInput) -> TokenStream {
+    let ScopedInput { module, scope } = input;
+    let (select, prefix) = match &scope {
+        Scope::Namespace(namespace) => (
+            quote!(&_keys.#namespace),
+            format!("{}::", namespace.unraw()),
+        ),
+        Scope::Keys(keys) => {
+            let select = match keys {
+                Keys::SingleKey(key) => quote!(&_keys.#key),
+                Keys::Subkeys(keys) => quote!(&_keys #(.#keys)*),
+                Keys::Namespace(namespace, keys) => quote!(&_keys.#namespace #(.#keys)*),
+            };
+            (select, format!("{}.", keys.path()))
+        }
+    };
+    quote! {
+        leptos_i18n::scope::I18nScope::new(#module::use_i18n(), |_keys| #select, #prefix)
+    }
+}
@@ -1,5 +1,5 @@
 use quote::{format_ident, quote, ToTokens};
-use syn::{Expr, Ident, Token};
+use syn::{ext::IdentExt, Expr, Ident, Token};
 
 pub enum InterpolatedValue {
     // form t!(i18n, key, count)
@@ -18,7 +18,8 @@ impl syn::parse::Parse for InterpolatedValue {
         if is_comp {
             input.parse::<Token![<]>()?;
         }
-        let key = input.parse()?;
+        // variables and components are prefixed, so keywords are valid names: `t!(i18n, key, type = ..)`
+        let key = input.call(Ident::parse_any)?;
         if is_comp {
             input.parse::<Token![>]>()?;
         }
@@ -39,6 +40,22 @@ impl syn::parse::Parse for InterpolatedValue {
     }
 }
 
+impl InterpolatedValue {
+    // `<b>` without a value is rendered as the `<b>...</b>` text by `td_test!`.
+    pub fn with_marker(self) -> Self {
+        match self {
+            InterpolatedValue::Comp(key) => {
+                let name = key.unraw().to_string();
+                InterpolatedValue::AssignedComp {
+                    key,
+                    value: syn::parse_quote!(leptos_i18n::testing::marker(#name)),
+                }
+            }
+            value => value,
+        }
+    }
+}
+
 impl ToTokens for InterpolatedValue {
     fn to_token_stream(&self) -> proc_macro2::TokenStream {
         match self {
@@ -1,29 +1,151 @@
 use quote::quote;
 use syn::parse_macro_input;
 
+use self::interpolate::InterpolatedValue;
 use self::parsed_input::{Keys, ParsedInput};
 
 pub mod interpolate;
 pub mod parsed_input;
 
-pub fn t_macro(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+/// What the translation macros produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputType {
+    /// `t!`: a closure reading the current locale of the context, so the view is updated when it change.
+    View,
+    /// `td!`: the value for the given locale, without any reactivity.
+    Direct,
+    /// `td_string!`: the value for the given locale rendered to an HTML string.
+    String,
+    /// `derive_translation!`: a `Signal<String>` of the value rendered to text, updated when the locale change.
+    Signal,
+    /// `td_test!`: the value for the given locale rendered to text, components without a value rendered as markers.
+    Test,
+    /// `t_aria!`: a closure returning the value rendered to text, for the attributes of an element.
+    Attribute,
+}
+
+pub fn t_macro(
+    tokens: proc_macro::TokenStream,
+    output_type: OutputType,
+) -> proc_macro::TokenStream {
     let input = parse_macro_input!(tokens as ParsedInput);
-    t_macro_inner(input).into()
+    t_macro_inner(input, output_type).into()
 }
 
-pub fn t_macro_inner(input: ParsedInput) -> proc_macro2::TokenStream {
+pub fn t_macro_inner(input: ParsedInput, output_type: OutputType) -> proc_macro2::TokenStream {
     let ParsedInput {
         context,
         keys,
         interpolations,
     } = input;
+    let get_keys = match output_type {
+        // the reactive macros also accept a scope of the context, see `leptos_i18n::scope`.
+        OutputType::View | OutputType::Signal | OutputType::Attribute => {
+            quote!(leptos_i18n::__private::TranslationContext::get_keys(#context))
+        }
+        OutputType::Direct | OutputType::String | OutputType::Test => {
+            quote!((#context).get_keys())
+        }
+    };
+    let path = keys.path();
     let get_key = match keys {
-        Keys::SingleKey(key) => quote!(leptos_i18n::I18nContext::get_keys(#context).#key),
-        Keys::Subkeys(keys) => quote!(leptos_i18n::I18nContext::get_keys(#context)#(.#keys)*),
+        Keys::SingleKey(key) => quote!(#get_keys.#key),
+        Keys::Subkeys(keys) => quote!(#get_keys #(.#keys)*),
         Keys::Namespace(namespace, keys) => {
-            quote!(leptos_i18n::I18nContext::get_keys(#context).#namespace #(.#keys)*)
+            quote!(#get_keys.#namespace #(.#keys)*)
         }
     };
+    let interpolations = match output_type {
+        OutputType::Test => interpolations.map(|interpolations| {
+            interpolations
+                .into_iter()
+                .map(InterpolatedValue::with_marker)
+                .collect()
+        }),
+        _ => interpolations,
+    };
+    let value = translation_closure(get_key, interpolations);
+    let value = match output_type {
+        OutputType::View | OutputType::Signal | OutputType::Attribute
+            if cfg!(feature = "usage_stats") =>
+        {
+            quote! {
+                {
+                    let _translation = #value;
+                    let _path = leptos_i18n::__private::TranslationContext::key_path(#context, #path);
+                    move || {
+                        leptos_i18n::usage::record(&_path);
+                        _translation()
+                    }
+                }
+            }
+        }
+        _ if cfg!(feature = "usage_stats") => quote! {
+            {
+                let _translation = #value;
+                move || {
+                    leptos_i18n::usage::record(#path);
+                    _translation()
+                }
+            }
+        },
+        _ => value,
+    };
+    let value = match output_type {
+        OutputType::View | OutputType::Signal | OutputType::Attribute
+            if cfg!(feature = "overrides") =>
+        {
+            quote! {
+                {
+                    let _translation = #value;
+                    leptos_i18n::overrides::wrap(
+                        leptos_i18n::__private::TranslationContext::context(#context),
+                        leptos_i18n::__private::TranslationContext::key_path(#context, #path),
+                        _translation,
+                    )
+                }
+            }
+        }
+        _ => value,
+    };
+    // only what ends up in the UI is marked, `td!` values are used outside of it.
+    let value = match output_type {
+        OutputType::View | OutputType::Signal | OutputType::Attribute
+            if cfg!(feature = "pseudo_locale") =>
+        {
+            quote! {
+                {
+                    let _translation = #value;
+                    move || leptos_i18n::pseudo::wrap(_translation())
+                }
+            }
+        }
+        _ => value,
+    };
+    match output_type {
+        OutputType::View => value,
+        OutputType::Direct => quote!((#value)()),
+        OutputType::String => quote!(leptos_i18n::render::render_to_html((#value)())),
+        OutputType::Signal => quote! {
+            {
+                let _translation = #value;
+                leptos::Signal::derive(move || leptos_i18n::render::render_to_text(_translation()))
+            }
+        },
+        OutputType::Test => quote!(leptos_i18n::render::render_to_text((#value)())),
+        OutputType::Attribute => quote! {
+            {
+                let _translation = #value;
+                move || leptos_i18n::render::render_to_text(_translation())
+            }
+        },
+    }
+}
+
+fn translation_closure(
+    get_key: proc_macro2::TokenStream,
+    interpolations: Option<Vec<InterpolatedValue>>,
+) -> proc_macro2::TokenStream {
     if let Some(interpolations) = interpolations {
         if cfg!(feature = "debug_interpolations") {
             quote! {
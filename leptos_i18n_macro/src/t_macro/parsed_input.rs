@@ -1,4 +1,5 @@
 use proc_macro2::Ident;
+use syn::ext::IdentExt;
 use syn::token::Comma;
 use syn::Token;
 
@@ -11,7 +12,7 @@ pub enum Keys {
 }
 
 pub struct ParsedInput {
-    pub context: Ident,
+    pub context: syn::Expr,
     pub keys: Keys,
     pub interpolations: Option<Vec<InterpolatedValue>>,
 }
@@ -41,18 +42,64 @@ impl syn::parse::Parse for ParsedInput {
     }
 }
 
+// keys are allowed to be keywords (`t!(i18n, type)`), they are generated as raw identifiers.
+// A message context is given in parentheses, `open(verb)` is the key "open (verb)" generated as `open__verb`.
+fn parse_key(input: syn::parse::ParseStream) -> syn::Result<Ident> {
+    let key = input.call(Ident::parse_any)?;
+    if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        let context = content.call(Ident::parse_any)?;
+        return Ok(quote::format_ident!(
+            "{}__{}",
+            key,
+            context,
+            span = key.span()
+        ));
+    }
+    let key_repr = key.to_string();
+    if syn::parse_str::<Ident>(&key_repr).is_ok() {
+        Ok(key)
+    } else {
+        match key_repr.as_str() {
+            "self" | "super" | "crate" | "Self" | "_" => Err(syn::Error::new(
+                key.span(),
+                format!("`{}` can't be used as a key", key_repr),
+            )),
+            _ => Ok(Ident::new_raw(&key_repr, key.span())),
+        }
+    }
+}
+
 fn parse_subkeys(input: syn::parse::ParseStream, keys: &mut Vec<Ident>) -> syn::Result<()> {
-    keys.push(input.parse()?);
+    keys.push(parse_key(input)?);
     while input.peek(Token![.]) {
         input.parse::<Token![.]>()?;
-        keys.push(input.parse()?);
+        keys.push(parse_key(input)?);
     }
     Ok(())
 }
 
+impl Keys {
+    // the path as written by users, e.g. `namespace::key.subkey`, without the `r#` of raw identifiers.
+    pub fn path(&self) -> String {
+        let join = |keys: &[Ident]| {
+            keys.iter()
+                .map(|key| key.unraw().to_string())
+                .collect::<Vec<_>>()
+                .join(".")
+        };
+        match self {
+            Keys::SingleKey(key) => key.unraw().to_string(),
+            Keys::Subkeys(keys) => join(keys),
+            Keys::Namespace(namespace, keys) => format!("{}::{}", namespace.unraw(), join(keys)),
+        }
+    }
+}
+
 impl syn::parse::Parse for Keys {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let first_key = input.parse()?;
+        let first_key = parse_key(input)?;
         if input.peek(Token![::]) {
             input.parse::<Token![::]>()?;
             let mut keys = vec![];
@@ -68,3 +115,18 @@ impl syn::parse::Parse for Keys {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyword_keys() {
+        let keys = syn::parse_str::<Keys>("type.r#loop").unwrap();
+        assert_eq!(keys.path(), "type.loop");
+
+        let err = syn::parse_str::<Keys>("_").err().unwrap();
+        assert_eq!(err.to_string(), "`_` can't be used as a key");
+        assert!(syn::parse_str::<Keys>("a._").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+#![no_std]
+
+leptos_i18n_macro::load_catalogs!();
+
+#[cfg(test)]
+mod tests {
+    use super::i18n::*;
+    use leptos_i18n_core::{
+        catalog::{self, LocaleCatalogs},
+        LocaleVariant, UnknownLocaleError,
+    };
+
+    #[test]
+    fn locale_enum() {
+        assert_eq!(LocaleEnum::default(), LocaleEnum::en);
+        assert_eq!(LocaleEnum::ALL, &[LocaleEnum::en, LocaleEnum::fr]);
+        assert_eq!(LocaleEnum::fr.as_str(), locale_codes::fr);
+        assert_eq!("fr".parse::<LocaleEnum>(), Ok(LocaleEnum::fr));
+        assert!(matches!(
+            "de".parse::<LocaleEnum>(),
+            Err(UnknownLocaleError(locale)) if locale == "de"
+        ));
+    }
+
+    #[test]
+    fn find_locale() {
+        assert_eq!(LocaleEnum::find_locale(&["fr-CA", "en"]), LocaleEnum::fr);
+        assert_eq!(LocaleEnum::find_locale(&["de"]), LocaleEnum::en);
+    }
+
+    #[test]
+    fn style() {
+        assert_eq!(LocaleEnum::fr.style().class, Some("fr"));
+        assert_eq!(LocaleEnum::en.style().class, None);
+        assert!(LocaleEnum::fr.is_complete());
+    }
+
+    #[test]
+    fn catalogs() {
+        let fr = LocaleEnum::fr.get_catalog(None).unwrap();
+        assert_eq!(fr.locale, "fr");
+        assert!(fr.json.contains("Vous avez cliqué {{ count }} fois"));
+        assert!(LocaleEnum::fr.get_catalog(Some("common")).is_none());
+
+        let response = catalog::respond::<LocaleEnum>("fr", None, None);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, fr.json);
+        let etag = fr.etag();
+        let response = catalog::respond::<LocaleEnum>("fr", None, Some(&etag));
+        assert_eq!(response.status, 304);
+        assert_eq!(catalog::respond::<LocaleEnum>("de", None, None).status, 404);
+
+        let key = LocaleEnum::SCHEMA.get_key(None, "click_count").unwrap();
+        assert_eq!(key.variables, &["count"]);
+        assert!(LocaleEnum::SCHEMA
+            .get_key(None, "subkeys.subkey_2")
+            .is_some());
+    }
+}